@@ -4,7 +4,7 @@ fn main() {
     protoc_rust_grpc::run(protoc_rust_grpc::Args {
         out_dir: "src/common/grpc",
         includes: &[],
-        input: &["proto/wcd.proto"],
+        input: &["proto/wcd.proto", "proto/health.proto"],
         rust_protobuf: true,
     }).expect("protoc-rust-grpc");
 }
@@ -0,0 +1,18 @@
+-- SQLite cannot drop columns before 3.35, so the statistics table is rebuilt without the legacy counters
+ALTER TABLE image_statistics RENAME TO image_statistics_with_legacy;
+
+CREATE TABLE image_statistics (
+  filename TEXT NOT NULL PRIMARY KEY,
+  total_displays BIGINT NOT NULL DEFAULT 0,
+  total_skips BIGINT NOT NULL DEFAULT 0,
+  total_display_seconds BIGINT NOT NULL DEFAULT 0
+);
+
+INSERT INTO image_statistics (filename, total_displays, total_skips, total_display_seconds)
+  SELECT filename, total_displays, total_skips, total_display_seconds FROM image_statistics_with_legacy;
+
+DROP TABLE image_statistics_with_legacy;
+
+DROP INDEX display_events_started_at;
+DROP INDEX display_events_filename;
+DROP TABLE display_events;
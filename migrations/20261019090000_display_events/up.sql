@@ -0,0 +1,24 @@
+CREATE TABLE display_events (
+  id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+  filename TEXT NOT NULL,
+  channel TEXT NOT NULL,
+  playlist TEXT NOT NULL,
+  started_at BIGINT NOT NULL,
+  ended_at BIGINT NOT NULL,
+  reason TEXT NOT NULL,
+  skipped BOOLEAN NOT NULL DEFAULT 0
+);
+
+CREATE INDEX display_events_filename ON display_events (filename);
+CREATE INDEX display_events_started_at ON display_events (started_at);
+
+-- counters which are not backed by display events, like the ones recorded before display events existed,
+-- are kept when the statistics are rebuilt from display events
+ALTER TABLE image_statistics ADD COLUMN legacy_displays BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE image_statistics ADD COLUMN legacy_skips BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE image_statistics ADD COLUMN legacy_display_seconds BIGINT NOT NULL DEFAULT 0;
+
+UPDATE image_statistics SET
+  legacy_displays = total_displays,
+  legacy_skips = total_skips,
+  legacy_display_seconds = total_display_seconds;
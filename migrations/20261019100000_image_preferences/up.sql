@@ -0,0 +1,5 @@
+CREATE TABLE image_preferences (
+  filename TEXT NOT NULL PRIMARY KEY,
+  rating INTEGER,
+  favorite BOOLEAN NOT NULL DEFAULT 0
+);
@@ -0,0 +1,4 @@
+CREATE TABLE banned_images (
+  filename TEXT NOT NULL PRIMARY KEY,
+  banned_at BIGINT NOT NULL
+);
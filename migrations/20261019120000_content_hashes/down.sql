@@ -0,0 +1,51 @@
+-- SQLite cannot drop columns before 3.35, so the display events table is rebuilt without content hashes
+DROP INDEX display_events_hash;
+DROP INDEX display_events_started_at;
+DROP INDEX display_events_filename;
+
+ALTER TABLE display_events RENAME TO display_events_with_hashes;
+
+CREATE TABLE display_events (
+  id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+  filename TEXT NOT NULL,
+  channel TEXT NOT NULL,
+  playlist TEXT NOT NULL,
+  started_at BIGINT NOT NULL,
+  ended_at BIGINT NOT NULL,
+  reason TEXT NOT NULL,
+  skipped BOOLEAN NOT NULL DEFAULT 0
+);
+
+INSERT INTO display_events (id, filename, channel, playlist, started_at, ended_at, reason, skipped)
+  SELECT id, filename, channel, playlist, started_at, ended_at, reason, skipped FROM display_events_with_hashes;
+
+DROP TABLE display_events_with_hashes;
+
+CREATE INDEX display_events_filename ON display_events (filename);
+CREATE INDEX display_events_started_at ON display_events (started_at);
+
+ALTER TABLE image_statistics RENAME TO image_statistics_by_hash;
+
+CREATE TABLE image_statistics (
+  filename TEXT NOT NULL PRIMARY KEY,
+  total_displays BIGINT NOT NULL DEFAULT 0,
+  total_skips BIGINT NOT NULL DEFAULT 0,
+  total_display_seconds BIGINT NOT NULL DEFAULT 0,
+  legacy_displays BIGINT NOT NULL DEFAULT 0,
+  legacy_skips BIGINT NOT NULL DEFAULT 0,
+  legacy_display_seconds BIGINT NOT NULL DEFAULT 0
+);
+
+-- statistics go to the most recently seen path of each image
+INSERT OR REPLACE INTO image_statistics
+  (filename, total_displays, total_skips, total_display_seconds, legacy_displays, legacy_skips, legacy_display_seconds)
+  SELECT p.path, s.total_displays, s.total_skips, s.total_display_seconds,
+    s.legacy_displays, s.legacy_skips, s.legacy_display_seconds
+  FROM image_statistics_by_hash s JOIN image_paths p ON p.hash = s.hash
+  WHERE p.last_seen = (SELECT MAX(last_seen) FROM image_paths WHERE hash = s.hash);
+
+DROP TABLE image_statistics_by_hash;
+
+DROP INDEX image_paths_hash;
+
+DROP TABLE image_paths;
@@ -0,0 +1,37 @@
+CREATE TABLE image_paths (
+  path TEXT NOT NULL PRIMARY KEY,
+  hash TEXT NOT NULL,
+  last_seen BIGINT NOT NULL
+);
+
+CREATE INDEX image_paths_hash ON image_paths (hash);
+
+-- existing statistics are identified by their paths until they are relinked
+ALTER TABLE image_statistics RENAME TO image_statistics_by_path;
+
+CREATE TABLE image_statistics (
+  hash TEXT NOT NULL PRIMARY KEY,
+  total_displays BIGINT NOT NULL DEFAULT 0,
+  total_skips BIGINT NOT NULL DEFAULT 0,
+  total_display_seconds BIGINT NOT NULL DEFAULT 0,
+  legacy_displays BIGINT NOT NULL DEFAULT 0,
+  legacy_skips BIGINT NOT NULL DEFAULT 0,
+  legacy_display_seconds BIGINT NOT NULL DEFAULT 0
+);
+
+INSERT INTO image_statistics
+  (hash, total_displays, total_skips, total_display_seconds, legacy_displays, legacy_skips, legacy_display_seconds)
+  SELECT 'path:' || filename, total_displays, total_skips, total_display_seconds,
+    legacy_displays, legacy_skips, legacy_display_seconds
+  FROM image_statistics_by_path;
+
+INSERT INTO image_paths (path, hash, last_seen)
+  SELECT filename, 'path:' || filename, 0 FROM image_statistics_by_path;
+
+DROP TABLE image_statistics_by_path;
+
+ALTER TABLE display_events ADD COLUMN hash TEXT NOT NULL DEFAULT '';
+
+UPDATE display_events SET hash = 'path:' || filename;
+
+CREATE INDEX display_events_hash ON display_events (hash);
@@ -0,0 +1 @@
+DROP INDEX display_events_identity;
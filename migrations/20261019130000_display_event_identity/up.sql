@@ -0,0 +1,2 @@
+-- display events are identified by their image, channel and start time when statistics are merged
+CREATE INDEX display_events_identity ON display_events (hash, channel, started_at);
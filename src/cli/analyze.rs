@@ -0,0 +1,466 @@
+use std::io;
+use std::path::Path;
+
+use chrono::Utc;
+use crossterm::event::{self, Event, KeyCode, KeyEvent};
+use crossterm::execute;
+use crossterm::terminal::{self, EnterAlternateScreen, LeaveAlternateScreen};
+use tui::Terminal;
+use tui::backend::{Backend, CrosstermBackend};
+use tui::layout::{Constraint, Direction, Layout};
+use tui::style::{Modifier, Style};
+use tui::text::{Span, Spans};
+use tui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap};
+use tui::Frame;
+
+use crate::common::config::StatsStorage;
+use crate::common::grpc::wcd;
+use crate::common::proto::{ControlRequest, ControlResponse, ImageStats, StatsQuery, StatsSort, StatsInfo};
+use crate::common::util;
+use crate::daemon::stats;
+use super::client::Client;
+
+const HELP: &str = "q quit  / filter  s sort  r reload  Enter show  0-5 rate  f favorite  b ban";
+
+pub fn main(storage: Option<&StatsStorage>, client: &Client, channel: Option<String>) {
+    // the database is only opened directly when the daemon cannot be reached, so the daemon does not have
+    // to compete for it
+    let source = match client.send(all_statistics()) {
+        Ok(ControlResponse::StatsInfoFailed(failure)) => super::fail("Failed to retrieve statistics", failure),
+        Ok(_) => Source::Daemon(client),
+        Err(e) => match storage {
+            Some(storage) => match stats::State::open(storage) {
+                Ok(state) => Source::Database(state),
+                Err(e) => abort!(1, "Cannot open statistics: {}", e),
+            },
+            None => abort!(1, "Cannot reach the daemon and statistics collection is disabled: {}", e),
+        },
+    };
+
+    let mut app = App {
+        source,
+        channel,
+        images: Vec::new(),
+        visible: Vec::new(),
+        table: TableState::default(),
+        page: 1,
+        sort: StatsSort::Displays,
+        filter: String::new(),
+        editing_filter: false,
+        pending_ban: false,
+        message: None,
+    };
+    app.reload();
+
+    if let Err(e) = run(&mut app) {
+        abort!(1, "Terminal error: {}", e);
+    }
+}
+
+fn all_statistics() -> ControlRequest {
+    ControlRequest::GetStatistics(StatsQuery {
+        playlist: None,
+        sort: StatsSort::Filename,
+        top: None,
+        missing_only: false,
+    })
+}
+
+fn run(app: &mut App) -> io::Result<()> {
+    // messages logged by the statistics code would be printed over the screen
+    log::set_max_level(log::LevelFilter::Off);
+
+    let _guard = TerminalGuard::enter()?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+
+    loop {
+        terminal.draw(|f| draw(f, app))?;
+
+        if let Event::Key(key) = event::read()? {
+            if !app.handle_key(key) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+// restores the terminal even if drawing fails
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> io::Result<TerminalGuard> {
+        terminal::enable_raw_mode()?;
+        if let Err(e) = execute!(io::stdout(), EnterAlternateScreen) {
+            let _ = terminal::disable_raw_mode();
+            return Err(e);
+        }
+        Ok(TerminalGuard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+// where statistics are taken from and where actions are sent to
+enum Source<'a> {
+    Daemon(&'a Client),
+    // the daemon is not running
+    Database(stats::State),
+}
+
+impl<'a> Source<'a> {
+    fn describe(&self) -> &'static str {
+        match *self {
+            Source::Daemon(_) => "daemon",
+            Source::Database(_) => "database, the daemon is not running",
+        }
+    }
+
+    fn load(&self) -> Result<Vec<ImageStats>, String> {
+        match *self {
+            Source::Daemon(client) => match send(client, all_statistics())? {
+                ControlResponse::StatsInfoOk(StatsInfo { image_stats, .. }) => Ok(image_stats),
+                ControlResponse::StatsInfoFailed(failure) => Err(failure.message),
+                _ => Err("Unexpected server response".into()),
+            },
+            Source::Database(ref state) => state.load()
+                .map(|images| images.into_iter()
+                    .map(|image| {
+                        let proto: wcd::ImageStatsInfo = image.into();
+                        proto.into()
+                    })
+                    .collect())
+                .map_err(|e| e.to_string()),
+        }
+    }
+
+    fn show(&self, channel: Option<String>, image: &str) -> Result<(), String> {
+        match *self {
+            Source::Daemon(client) => expect_ok(
+                send(client, ControlRequest::ShowImage { channel, image: image.to_owned(), })?,
+                ControlResponse::ShowImageOk,
+            ),
+            Source::Database(_) => Err("Showing images requires the running daemon".into()),
+        }
+    }
+
+    fn rate(&self, image: &str, rating: u8) -> Result<(), String> {
+        match *self {
+            Source::Daemon(client) => expect_ok(
+                send(client, ControlRequest::RateImage { channel: None, image: Some(image.to_owned()), rating, })?,
+                ControlResponse::RateImageOk,
+            ),
+            Source::Database(ref state) => state.set_rating(image, if rating == 0 { None } else { Some(rating) })
+                .map_err(|e| e.to_string()),
+        }
+    }
+
+    fn set_favorite(&self, image: &str, favorite: bool) -> Result<(), String> {
+        match *self {
+            Source::Daemon(client) => expect_ok(
+                send(client, ControlRequest::SetFavorite { channel: None, image: Some(image.to_owned()), favorite, })?,
+                ControlResponse::SetFavoriteOk,
+            ),
+            Source::Database(ref state) => state.set_favorite(image, favorite).map_err(|e| e.to_string()),
+        }
+    }
+
+    fn ban(&self, image: &str) -> Result<(), String> {
+        match *self {
+            Source::Daemon(client) => expect_ok(
+                send(client, ControlRequest::BanImage { channel: None, image: Some(image.to_owned()), })?,
+                ControlResponse::BanImageOk,
+            ),
+            Source::Database(ref state) => state.ban(image, Utc::now()).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+fn send(client: &Client, req: ControlRequest) -> Result<ControlResponse, String> {
+    client.send(req).map_err(|e| format!("Error sending request: {}", e))
+}
+
+fn expect_ok(resp: ControlResponse, ok: ControlResponse) -> Result<(), String> {
+    match resp {
+        ControlResponse::ShowImageFailed(failure) | ControlResponse::RateImageFailed(failure) |
+        ControlResponse::SetFavoriteFailed(failure) | ControlResponse::BanImageFailed(failure) => Err(failure.message),
+        resp if resp == ok => Ok(()),
+        _ => Err("Unexpected server response".into()),
+    }
+}
+
+struct App<'a> {
+    source: Source<'a>,
+    // the channel images are shown in
+    channel: Option<String>,
+    // all images in file name order
+    images: Vec<ImageStats>,
+    // indices of images matching the filter, in the display order
+    visible: Vec<usize>,
+    table: TableState,
+    // the number of rows visible in the table
+    page: usize,
+    sort: StatsSort,
+    filter: String,
+    editing_filter: bool,
+    // the selected image is banned if `b` is pressed again
+    pending_ban: bool,
+    message: Option<String>,
+}
+
+impl<'a> App<'a> {
+    fn selected(&self) -> Option<&ImageStats> {
+        self.table.selected().and_then(|idx| self.visible.get(idx)).map(|&idx| &self.images[idx])
+    }
+
+    fn reload(&mut self) {
+        let selected = self.selected().map(|image| image.filename.clone());
+        match self.source.load() {
+            Ok(images) => {
+                self.images = images;
+                self.update_visible(selected);
+            }
+            Err(e) => self.message = Some(format!("Failed to load statistics: {}", e)),
+        }
+    }
+
+    // applies the filter and the sort order, keeping the given image selected if it is still visible
+    fn update_visible(&mut self, selected: Option<String>) {
+        let filter = self.filter.to_lowercase();
+        let images = &self.images;
+        self.visible = (0..images.len())
+            .filter(|&idx| filter.is_empty() || images[idx].filename.to_lowercase().contains(&filter))
+            .collect();
+
+        // sorting is stable, so ties stay ordered by file name
+        match self.sort {
+            StatsSort::Filename => {}
+            StatsSort::Displays => self.visible.sort_by_key(|&idx| -images[idx].total_displays),
+            StatsSort::Skips => self.visible.sort_by_key(|&idx| -images[idx].total_skips),
+            StatsSort::DisplayTime => self.visible.sort_by_key(|&idx| -images[idx].total_display_time),
+        }
+
+        let position = selected
+            .and_then(|filename| self.visible.iter().position(|&idx| images[idx].filename == filename));
+        self.table.select(match position {
+            Some(position) => Some(position),
+            None if self.visible.is_empty() => None,
+            None => Some(0),
+        });
+    }
+
+    fn move_selection(&mut self, offset: isize) {
+        if self.visible.is_empty() {
+            return;
+        }
+        let current = self.table.selected().unwrap_or(0) as isize;
+        let last = self.visible.len() as isize - 1;
+        self.table.select(Some(current.saturating_add(offset).max(0).min(last) as usize));
+    }
+
+    // returns false when the analyzer should quit
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if self.editing_filter {
+            let selected = self.selected().map(|image| image.filename.clone());
+            match key.code {
+                KeyCode::Enter => self.editing_filter = false,
+                KeyCode::Esc => {
+                    self.editing_filter = false;
+                    self.filter.clear();
+                }
+                KeyCode::Backspace => { self.filter.pop(); }
+                KeyCode::Char(c) => self.filter.push(c),
+                _ => return true,
+            }
+            self.update_visible(selected);
+            return true;
+        }
+
+        let pending_ban = self.pending_ban;
+        self.pending_ban = false;
+        self.message = None;
+
+        let page = self.page as isize;
+        match key.code {
+            KeyCode::Char('q') => return false,
+            // cancels a pending ban, otherwise quits
+            KeyCode::Esc => return pending_ban,
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(1),
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(-1),
+            KeyCode::PageDown => self.move_selection(page),
+            KeyCode::PageUp => self.move_selection(-page),
+            KeyCode::Home | KeyCode::Char('g') => self.move_selection(isize::MIN),
+            KeyCode::End | KeyCode::Char('G') => self.move_selection(isize::MAX),
+            KeyCode::Char('/') => self.editing_filter = true,
+            KeyCode::Char('s') => {
+                self.sort = match self.sort {
+                    StatsSort::Displays => StatsSort::Skips,
+                    StatsSort::Skips => StatsSort::DisplayTime,
+                    StatsSort::DisplayTime => StatsSort::Filename,
+                    StatsSort::Filename => StatsSort::Displays,
+                };
+                let selected = self.selected().map(|image| image.filename.clone());
+                self.update_visible(selected);
+            }
+            KeyCode::Char('r') => self.reload(),
+            KeyCode::Enter => {
+                let channel = self.channel.clone();
+                self.act(|source, image| source.show(channel, image), "Showing");
+            }
+            KeyCode::Char(c @ '0'..='5') => {
+                let rating = c as u8 - b'0';
+                self.act(|source, image| source.rate(image, rating), "Rated");
+            }
+            KeyCode::Char('f') => {
+                let favorite = !self.selected().map(|image| image.favorite).unwrap_or(true);
+                self.act(
+                    |source, image| source.set_favorite(image, favorite),
+                    if favorite { "Added to favorites" } else { "Removed from favorites" },
+                );
+            }
+            KeyCode::Char('b') => {
+                if pending_ban {
+                    self.act(|source, image| source.ban(image), "Banned");
+                } else if let Some(image) = self.selected() {
+                    self.message = Some(format!("Press b again to ban {}", image.filename));
+                    self.pending_ban = true;
+                }
+            }
+            _ => {}
+        }
+        true
+    }
+
+    // applies the action to the selected image and reloads statistics, which the action may change
+    fn act<F>(&mut self, f: F, done: &str)
+        where F: FnOnce(&Source, &str) -> Result<(), String>
+    {
+        let filename = match self.selected() {
+            Some(image) => image.filename.clone(),
+            None => return,
+        };
+
+        match f(&self.source, &filename) {
+            Ok(()) => {
+                self.reload();
+                if self.message.is_none() {
+                    self.message = Some(format!("{} {}", done, filename));
+                }
+            }
+            Err(e) => self.message = Some(e),
+        }
+    }
+}
+
+fn draw<B: Backend>(f: &mut Frame<B>, app: &mut App) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(3), Constraint::Length(1)].as_ref())
+        .split(f.size());
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)].as_ref())
+        .split(rows[1]);
+
+    let header = format!(
+        "wcd statistics ({}): {} of {} images",
+        app.source.describe(), app.visible.len(), app.images.len()
+    );
+    f.render_widget(Paragraph::new(header), rows[0]);
+
+    // borders and the header row take three lines
+    app.page = (columns[0].height as usize).saturating_sub(3).max(1);
+    draw_table(f, app, columns[0]);
+    draw_details(f, app, columns[1]);
+
+    let footer = if app.editing_filter {
+        Spans::from(vec![Span::raw("Filter: "), Span::raw(app.filter.as_str()), Span::raw("_")])
+    } else if let Some(ref message) = app.message {
+        Spans::from(message.as_str())
+    } else if !app.filter.is_empty() {
+        Spans::from(format!("Filter: {}  |  {}", app.filter, HELP))
+    } else {
+        Spans::from(HELP)
+    };
+    f.render_widget(Paragraph::new(footer), rows[2]);
+}
+
+fn draw_table<B: Backend>(f: &mut Frame<B>, app: &mut App, area: tui::layout::Rect) {
+    let sorted = Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+    let current_sort = app.sort;
+    let header_cell = |title: &'static str, sort: StatsSort| {
+        if current_sort == sort { Cell::from(title).style(sorted) } else { Cell::from(title) }
+    };
+    let header = Row::new(vec![
+        header_cell("Displays", StatsSort::Displays),
+        header_cell("Skips", StatsSort::Skips),
+        header_cell("Time", StatsSort::DisplayTime),
+        Cell::from("Rating"),
+        Cell::from("Fav"),
+        header_cell("File", StatsSort::Filename),
+    ]).style(Style::default().add_modifier(Modifier::BOLD));
+
+    let images = &app.images;
+    let rows: Vec<_> = app.visible.iter()
+        .map(|&idx| {
+            let image = &images[idx];
+            Row::new(vec![
+                Cell::from(image.total_displays.to_string()),
+                Cell::from(image.total_skips.to_string()),
+                Cell::from(util::format_display_time(image.total_display_time)),
+                Cell::from(image.rating.map(|r| r.to_string()).unwrap_or_else(|| "-".into())),
+                Cell::from(if image.favorite { "*" } else { "" }),
+                Cell::from(image.filename.as_str()),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(rows)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title("Images"))
+        .widths(&[
+            Constraint::Length(8), Constraint::Length(6), Constraint::Length(11),
+            Constraint::Length(6), Constraint::Length(3), Constraint::Min(10),
+        ])
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    f.render_stateful_widget(table, area, &mut app.table);
+}
+
+fn draw_details<B: Backend>(f: &mut Frame<B>, app: &App, area: tui::layout::Rect) {
+    let lines = match app.selected() {
+        Some(image) => {
+            let skip_rate = if image.total_displays > 0 {
+                format!("{:.1}%", image.total_skips as f64 * 100.0 / image.total_displays as f64)
+            } else {
+                "-".into()
+            };
+            let average_time = if image.total_displays > 0 {
+                util::format_display_time(image.total_display_time / image.total_displays)
+            } else {
+                "-".into()
+            };
+            vec![
+                Spans::from(image.filename.as_str()),
+                Spans::from(""),
+                Spans::from(format!("File exists:     {}", if Path::new(&image.filename).exists() { "yes" } else { "no" })),
+                Spans::from(format!("Displays:        {}", image.total_displays)),
+                Spans::from(format!("Skips:           {}", image.total_skips)),
+                Spans::from(format!("Skip rate:       {}", skip_rate)),
+                Spans::from(format!("Time on screen:  {}", util::format_display_time(image.total_display_time))),
+                Spans::from(format!("Average display: {}", average_time)),
+                Spans::from(format!("Rating:          {}", image.rating.map(|r| r.to_string()).unwrap_or_else(|| "none".into()))),
+                Spans::from(format!("Favorite:        {}", if image.favorite { "yes" } else { "no" })),
+            ]
+        }
+        None => vec![Spans::from("No image selected")],
+    };
+
+    let details = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title("Details"))
+        .wrap(Wrap { trim: false });
+    f.render_widget(details, area);
+}
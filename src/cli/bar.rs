@@ -0,0 +1,145 @@
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+use serde_derive::Serialize;
+
+use crate::common::proto::{ControlRequest, ControlResponse, StatusInfo, EventKind, Failure, FailureKind};
+use super::client::Client;
+use super::output::{OutputFormat, Template};
+
+pub const DEFAULT_TEMPLATE: &str = "{image_name}";
+
+// how long to wait before connecting again to a daemon which is not running
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(5);
+
+// the output of custom modules of Waybar with `"return-type": "json"`
+#[derive(Serialize)]
+struct WaybarLine {
+    text: String,
+    tooltip: String,
+    class: &'static str,
+    alt: String,
+}
+
+// the line is printed again after every event of the channel; while the daemon is not running, an empty
+// line is shown and the connection is retried
+pub fn main(client: &Client, channel: Option<String>, format: OutputFormat, template: Template) {
+    if format != OutputFormat::Plain && format != OutputFormat::Json {
+        abort!(1, "Status bar output only supports the plain and json formats");
+    }
+
+    let mut last_line = None;
+    loop {
+        // subscribing before requesting the status makes sure that no change is missed in between
+        let events = client.subscribe();
+
+        let mut status = get_status(client, &channel);
+        print_line(&format_line(status.as_ref(), format, &template), &mut last_line);
+
+        for event in events {
+            let event = match event {
+                Ok(event) => event,
+                Err(::grpc::Error::GrpcMessage(e)) => {
+                    let failure = Failure::from(e);
+                    if is_configuration_error(&failure) {
+                        super::fail("Failed to receive events", failure);
+                    }
+                    warn!("Failed to receive events, reconnecting: {}", failure);
+                    break;
+                }
+                Err(_) => break,
+            };
+            if event.kind == EventKind::ShuttingDown {
+                break;
+            }
+
+            // events without a channel, like playlist refreshes, apply to all channels
+            let followed = status.as_ref().map(|s| &s.channel);
+            if event.channel.is_some() && followed.is_some() && event.channel.as_ref() != followed {
+                continue;
+            }
+
+            status = get_status(client, &channel);
+            print_line(&format_line(status.as_ref(), format, &template), &mut last_line);
+        }
+
+        print_line(&format_line(None, format, &template), &mut last_line);
+        thread::sleep(RECONNECT_INTERVAL);
+    }
+}
+
+// `None` if the daemon cannot be reached or fails to answer
+fn get_status(client: &Client, channel: &Option<String>) -> Option<StatusInfo> {
+    match client.send(ControlRequest::GetStatus(channel.clone())) {
+        Ok(ControlResponse::StatusInfoOk(status_info)) => Some(status_info),
+        Ok(ControlResponse::StatusInfoFailed(failure)) => {
+            if is_configuration_error(&failure) {
+                super::fail("Failed to retrieve status info", failure);
+            }
+            warn!("Failed to retrieve status info: {}", failure);
+            None
+        }
+        Ok(_) => abort!(1, "Unexpected server response when getting status info"),
+        Err(_) => None,
+    }
+}
+
+// failures which do not go away by reconnecting, like an unknown channel or a missing token
+fn is_configuration_error(failure: &Failure) -> bool {
+    match failure.kind {
+        FailureKind::InvalidArgument | FailureKind::NotFound | FailureKind::Unauthenticated |
+        FailureKind::PermissionDenied | FailureKind::Unimplemented => true,
+        FailureKind::FailedPrecondition | FailureKind::Unavailable | FailureKind::Internal => false,
+    }
+}
+
+fn format_line(status_info: Option<&StatusInfo>, format: OutputFormat, template: &Template) -> String {
+    let status_info = match status_info {
+        Some(status_info) => status_info,
+        None if format == OutputFormat::Json => return to_json(&WaybarLine {
+            text: String::new(),
+            tooltip: "The wcd daemon is not running".into(),
+            class: "disconnected",
+            alt: String::new(),
+        }),
+        None => return String::new(),
+    };
+
+    let text = super::render_status(template, status_info, None);
+    if format != OutputFormat::Json {
+        return text;
+    }
+
+    let playlist = status_info.playlists.get(&status_info.current_playlist);
+    let mut tooltip: Vec<String> = playlist
+        .map(|p| p.current_images.iter().map(|oi| oi.image.clone()).collect())
+        .unwrap_or_default();
+    if status_info.paused {
+        tooltip.push("Scheduled changes are paused".into());
+    } else if let Some(playlist) = playlist {
+        tooltip.push(format!("Next change: {}", super::TimestampDisplay(playlist.next_update)));
+    }
+
+    to_json(&WaybarLine {
+        text,
+        tooltip: tooltip.join("\n"),
+        class: if status_info.paused { "paused" } else { "" },
+        alt: status_info.current_playlist.clone(),
+    })
+}
+
+fn to_json(line: &WaybarLine) -> String {
+    serde_json::to_string(line).unwrap_or_else(|e| abort!(1, "Failed to serialize status: {}", e))
+}
+
+// bars only need to redraw when the line changes; the bar closing the pipe ends the process
+fn print_line(line: &str, last_line: &mut Option<String>) {
+    if last_line.as_deref() == Some(line) {
+        return;
+    }
+    if writeln!(io::stdout(), "{}", line).is_err() {
+        abort!(1);
+    }
+    *last_line = Some(line.to_owned());
+}
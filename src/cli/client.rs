@@ -1,21 +1,33 @@
 use std::error::Error;
+use std::net::ToSocketAddrs;
 use std::result;
+use std::sync::Arc;
 
-use grpc::ClientStubExt;
+use bytes::Bytes;
+use grpc::{ClientStub, GrpcStatus, MetadataKey, RequestOptions, SingleResponse};
+use httpbis::ClientTlsOption;
 
+use crate::common::auth;
+use crate::common::config::ValidatedTls;
 use crate::common::proto::GrpcResponseExt;
-use crate::common::grpc::wcd;
+use crate::common::grpc::{health, wcd};
+use crate::common::grpc::health_grpc::{HealthClient, Health};
 use crate::common::grpc::wcd_grpc::{WcdClient, Wcd};
-use crate::common::proto::{ControlRequest, ControlResponse};
+use crate::common::proto::{ControlRequest, ControlResponse, Event, PROTOCOL_VERSION};
+use crate::common::tls::TlsConnector;
 
 type Result<T> = result::Result<T, Box<dyn Error>>;
 
 pub struct Client {
     grpc: WcdClient,
+    // the standard health checking service, served on the same connection
+    health: HealthClient,
+    // sent with every request if the daemon requires authentication
+    auth_token: Option<String>,
 }
 
 impl Client {
-    pub fn new(endpoint: &str) -> Result<Client> {
+    pub fn new(endpoint: &str, auth_token: Option<String>, tls: Option<ValidatedTls>) -> Result<Client> {
         let mut parts = endpoint.split(":");
         let host = parts.next();
         let port = parts.next();
@@ -25,35 +37,213 @@ impl Client {
             _ => unimplemented!()
         };
 
-        let grpc = WcdClient::new_plain(host, port.parse().expect("Endpoint port is invalid"), Default::default())?;
+        let port = port.parse().expect("Endpoint port is invalid");
+        let addr = (host, port).to_socket_addrs()?.next()
+            .ok_or_else(|| format!("Endpoint host {} cannot be resolved", host))?;
+        let tls = match tls {
+            Some(tls) => {
+                let server_name = tls.server_name.clone().unwrap_or_else(|| host.to_owned());
+                ClientTlsOption::Tls(server_name, Arc::new(TlsConnector::from_config(&tls)?))
+            }
+            None => ClientTlsOption::Plain,
+        };
+        let client = Arc::new(::grpc::Client::new_expl(&addr, host, tls, Default::default())?);
+
+        Ok(Client {
+            grpc: WcdClient::with_client(client.clone()),
+            health: HealthClient::with_client(client),
+            auth_token,
+        })
+    }
+
+    fn options(&self) -> RequestOptions {
+        let mut options = RequestOptions::new();
+        if let Some(ref token) = self.auth_token {
+            options.metadata.add(MetadataKey::from(auth::METADATA_KEY), Bytes::from(auth::metadata_value(token)));
+        }
+        options
+    }
 
-        Ok(Client { grpc, })
+    // blocks until the next event arrives; the iterator ends when the daemon shuts down, and failures
+    // reported by the daemon are `grpc::Error::GrpcMessage` errors
+    pub fn subscribe(&self) -> impl Iterator<Item=result::Result<Event, ::grpc::Error>> {
+        self.grpc.subscribe(self.options(), wcd::Empty::new()).wait_drop_metadata()
+            .map(|event| event.map(Into::into))
     }
 
     pub fn send(&self, msg: ControlRequest) -> Result<ControlResponse> {
         match msg {
-            ControlRequest::TriggerChange =>
-                Ok(self.grpc.trigger_change(Default::default(), wcd::Empty::new()).wait_drop_metadata()
-                    .map(|_| ControlResponse::TriggerChangeOk)?),
-            ControlRequest::TriggerUpdate =>
-                Ok(self.grpc.trigger_update(Default::default(), wcd::Empty::new()).wait_drop_metadata()
-                    .map(|_| ControlResponse::TriggerUpdateOk)?),
+            ControlRequest::TriggerChange(channel) =>
+                Ok(self.wait(self.grpc.trigger_change(self.options(), channel_name(channel)))
+                    .fold(|_: wcd::Empty| ControlResponse::TriggerChangeOk, ControlResponse::TriggerChangeFailed)?),
+            ControlRequest::TriggerUpdate(channel) =>
+                Ok(self.wait(self.grpc.trigger_update(self.options(), channel_name(channel)))
+                    .fold(|_: wcd::Empty| ControlResponse::TriggerUpdateOk, ControlResponse::TriggerUpdateFailed)?),
             ControlRequest::RefreshPlaylists =>
-                Ok(self.grpc.refresh_playlists(Default::default(), wcd::Empty::new()).wait_drop_metadata()
-                    .map(|_| ControlResponse::RefreshPlaylistsOk)?),
+                Ok(self.wait(self.grpc.refresh_playlists(self.options(), wcd::Empty::new()))
+                    .fold(|_: wcd::Empty| ControlResponse::RefreshPlaylistsOk, ControlResponse::RefreshPlaylistsFailed)?),
             ControlRequest::Terminate =>
-                Ok(self.grpc.terminate(Default::default(), wcd::Empty::new()).wait_drop_metadata()
-                    .map(|_| ControlResponse::TerminateOk)?),
-            ControlRequest::GetStatus => {
-                Ok(self.grpc.get_status(Default::default(), wcd::Empty::new()).wait()?
-                    .fold(ControlResponse::StatusInfoOk, ControlResponse::StatusInfoFailed))
+                Ok(self.wait(self.grpc.terminate(self.options(), wcd::Empty::new()))
+                    .fold(|_: wcd::Empty| ControlResponse::TerminateOk, ControlResponse::TerminateFailed)?),
+            ControlRequest::GetStatus(channel) => {
+                Ok(self.wait(self.grpc.get_status(self.options(), channel_name(channel)))
+                    .fold(ControlResponse::StatusInfoOk, ControlResponse::StatusInfoFailed)?)
             }
-            ControlRequest::ChangePlaylist(playlist) => {
+            ControlRequest::ChangePlaylist(channel, playlist) => {
                 let mut playlist_name = wcd::PlaylistName::new();
                 playlist_name.set_name(playlist);
-                Ok(self.grpc.change_playlist(Default::default(), playlist_name).wait()?
-                    .fold(|_: wcd::Empty| ControlResponse::ChangePlaylistOk, ControlResponse::ChangePlaylistFailed))
+                playlist_name.set_channel(channel.unwrap_or_default());
+                Ok(self.wait(self.grpc.change_playlist(self.options(), playlist_name))
+                    .fold(|_: wcd::Empty| ControlResponse::ChangePlaylistOk, ControlResponse::ChangePlaylistFailed)?)
+            }
+            ControlRequest::ShowImage { channel, image, } => {
+                Ok(self.wait(self.grpc.show_image(self.options(), image_name(channel, Some(image))))
+                    .fold(|_: wcd::Empty| ControlResponse::ShowImageOk, ControlResponse::ShowImageFailed)?)
+            }
+            ControlRequest::ShowPrevious(channel) => {
+                Ok(self.wait(self.grpc.show_previous(self.options(), channel_name(channel)))
+                    .fold(|_: wcd::Empty| ControlResponse::ShowPreviousOk, ControlResponse::ShowPreviousFailed)?)
+            }
+            ControlRequest::Pause(channel) => {
+                Ok(self.wait(self.grpc.pause(self.options(), channel_name(channel)))
+                    .fold(|_: wcd::Empty| ControlResponse::PauseOk, ControlResponse::PauseFailed)?)
+            }
+            ControlRequest::Resume(channel) => {
+                Ok(self.wait(self.grpc.resume(self.options(), channel_name(channel)))
+                    .fold(|_: wcd::Empty| ControlResponse::ResumeOk, ControlResponse::ResumeFailed)?)
+            }
+            ControlRequest::GetStatistics(query) => {
+                Ok(self.wait(self.grpc.get_statistics(self.options(), query.into()))
+                    .fold(ControlResponse::StatsInfoOk, ControlResponse::StatsInfoFailed)?)
+            }
+            ControlRequest::RebuildStatistics => {
+                Ok(self.wait(self.grpc.rebuild_statistics(self.options(), wcd::Empty::new()))
+                    .fold(|_: wcd::Empty| ControlResponse::RebuildStatisticsOk, ControlResponse::RebuildStatisticsFailed)?)
+            }
+            ControlRequest::RelinkStatistics => {
+                Ok(self.wait(self.grpc.relink_statistics(self.options(), wcd::Empty::new()))
+                    .fold(ControlResponse::RelinkStatisticsOk, ControlResponse::RelinkStatisticsFailed)?)
+            }
+            ControlRequest::CollectStatisticsGarbage { dry_run, } => {
+                let mut query = wcd::GcQuery::new();
+                query.set_dry_run(dry_run);
+                Ok(self.wait(self.grpc.collect_statistics_garbage(self.options(), query))
+                    .fold(ControlResponse::GcStatisticsOk, ControlResponse::GcStatisticsFailed)?)
+            }
+            ControlRequest::RenameStatisticsPrefix { old_prefix, new_prefix, } => {
+                let mut rename = wcd::RenamePrefix::new();
+                rename.set_old_prefix(old_prefix);
+                rename.set_new_prefix(new_prefix);
+                Ok(self.wait(self.grpc.rename_statistics_prefix(self.options(), rename))
+                    .fold(ControlResponse::RenameStatisticsPrefixOk, ControlResponse::RenameStatisticsPrefixFailed)?)
+            }
+            ControlRequest::ExportStatistics => {
+                Ok(self.wait(self.grpc.export_statistics(self.options(), wcd::Empty::new()))
+                    .fold(|e: wcd::StatsExport| ControlResponse::ExportStatisticsOk(e.data),
+                          ControlResponse::ExportStatisticsFailed)?)
+            }
+            ControlRequest::ImportStatistics { data, merge, } => {
+                let mut import = wcd::StatsImport::new();
+                import.set_data(data);
+                import.set_merge(merge);
+                Ok(self.wait(self.grpc.import_statistics(self.options(), import))
+                    .fold(ControlResponse::ImportStatisticsOk, ControlResponse::ImportStatisticsFailed)?)
+            }
+            ControlRequest::GetReport(query) => {
+                Ok(self.wait(self.grpc.get_report(self.options(), query.into()))
+                    .fold(ControlResponse::ReportOk, ControlResponse::ReportFailed)?)
+            }
+            ControlRequest::RateImage { channel, image, rating, } => {
+                let mut image_rating = wcd::ImageRating::new();
+                image_rating.set_channel(channel.unwrap_or_default());
+                image_rating.set_image(image.unwrap_or_default());
+                image_rating.set_rating(rating.into());
+                Ok(self.wait(self.grpc.rate_image(self.options(), image_rating))
+                    .fold(|_: wcd::Empty| ControlResponse::RateImageOk, ControlResponse::RateImageFailed)?)
+            }
+            ControlRequest::SetFavorite { channel, image, favorite, } => {
+                let mut image_favorite = wcd::ImageFavorite::new();
+                image_favorite.set_channel(channel.unwrap_or_default());
+                image_favorite.set_image(image.unwrap_or_default());
+                image_favorite.set_favorite(favorite);
+                Ok(self.wait(self.grpc.set_favorite(self.options(), image_favorite))
+                    .fold(|_: wcd::Empty| ControlResponse::SetFavoriteOk, ControlResponse::SetFavoriteFailed)?)
+            }
+            ControlRequest::BanImage { channel, image, } => {
+                Ok(self.wait(self.grpc.ban_image(self.options(), image_name(channel, image)))
+                    .fold(|_: wcd::Empty| ControlResponse::BanImageOk, ControlResponse::BanImageFailed)?)
+            }
+            ControlRequest::UnbanImage(image) => {
+                Ok(self.wait(self.grpc.unban_image(self.options(), image_name(None, Some(image))))
+                    .fold(|_: wcd::Empty| ControlResponse::UnbanImageOk, ControlResponse::UnbanImageFailed)?)
+            }
+            ControlRequest::ListImages(query) => {
+                Ok(self.wait(self.grpc.list_images(self.options(), query.into()))
+                    .fold(ControlResponse::ImageListOk, ControlResponse::ImageListFailed)?)
+            }
+            ControlRequest::ListBans => {
+                Ok(self.wait(self.grpc.list_bans(self.options(), wcd::Empty::new()))
+                    .fold(ControlResponse::BanListOk, ControlResponse::BanListFailed)?)
+            }
+            ControlRequest::GetVersion => {
+                Ok(self.wait(self.grpc.get_version(self.options(), wcd::Empty::new()))
+                    .fold(ControlResponse::VersionOk, ControlResponse::VersionFailed)?)
+            }
+            // the empty service name asks about the daemon as a whole
+            ControlRequest::CheckHealth => {
+                Ok(self.wait(self.health.check(self.options(), health::HealthCheckRequest::new()))
+                    .fold(|r: health::HealthCheckResponse| ControlResponse::HealthOk(r.get_status().into()),
+                          ControlResponse::HealthFailed)?)
             }
         }
     }
+
+    // requests unknown to the daemon are explained by comparing its version with the one of the client;
+    // requests of services unknown to the daemon are answered with HTTP 404 instead of a gRPC status
+    fn wait<T: Send + 'static>(&self, response: SingleResponse<T>) -> ::grpc::Result<T> {
+        let result = response.wait_drop_metadata();
+        let unimplemented = match result {
+            Err(::grpc::Error::GrpcMessage(ref e)) => e.grpc_status == GrpcStatus::Unimplemented as i32,
+            Err(::grpc::Error::Other("not 200")) => true,
+            _ => false,
+        };
+        if !unimplemented {
+            return result;
+        }
+        Err(::grpc::Error::GrpcMessage(::grpc::GrpcMessageError {
+            grpc_status: GrpcStatus::Unimplemented as i32,
+            grpc_message: self.unimplemented_message(),
+        }))
+    }
+
+    fn unimplemented_message(&self) -> String {
+        let client = format!("the client is wcd {} with protocol {}", env!("CARGO_PKG_VERSION"), PROTOCOL_VERSION);
+        match self.grpc.get_version(self.options(), wcd::Empty::new()).wait_drop_metadata() {
+            Ok(ref daemon) if daemon.protocol_version < PROTOCOL_VERSION => format!(
+                "The daemon is older than the client (the daemon is wcd {} with protocol {}, {}) and does not support \
+                 this request, restart the daemon to upgrade it",
+                daemon.version, daemon.protocol_version, client
+            ),
+            // daemons without `GetVersion` predate protocol versions
+            Err(::grpc::Error::GrpcMessage(ref e)) if e.grpc_status == GrpcStatus::Unimplemented as i32 => format!(
+                "The daemon is older than the client ({}) and does not support this request, restart the daemon \
+                 to upgrade it",
+                client
+            ),
+            _ => "The daemon does not support this request".into(),
+        }
+    }
+}
+
+fn channel_name(channel: Option<String>) -> wcd::ChannelName {
+    let mut channel_name = wcd::ChannelName::new();
+    channel_name.set_channel(channel.unwrap_or_default());
+    channel_name
+}
+
+fn image_name(channel: Option<String>, image: Option<String>) -> wcd::ImageName {
+    let mut image_name = wcd::ImageName::new();
+    image_name.set_channel(channel.unwrap_or_default());
+    image_name.set_image(image.unwrap_or_default());
+    image_name
 }
@@ -1,76 +1,625 @@
+use std::env;
 use std::fmt;
+use std::fs;
 use std::path::Path;
 
-use chrono::{Local, TimeZone};
+use chrono::{Local, TimeZone, Utc};
+use serde_derive::Serialize;
 
 use crate::common::config;
-use crate::common::proto::{ControlRequest, ControlResponse, StatusInfo, PlaylistInfo, ChangeMode};
-use crate::Command;
+use crate::common::proto::{
+    ControlRequest, ControlResponse, StatusInfo, PlaylistInfo, ChangeMode, StatsQuery, StatsSort, StatsInfo, BanList,
+    ListQuery, ImageList, RelinkResult, GcResult, RenameResult, ImportResult, ReportQuery, ReportGrouping, Report,
+    Event, EventKind, DisplayReason, Failure, FailureKind, VersionInfo, HealthStatus, PROTOCOL_VERSION,
+};
+use crate::common::util;
+use crate::{Command, StatsCommand, BansCommand, OutputOptions};
 use self::client::Client;
+use self::output::{OutputFormat, Template};
 
 mod client;
+mod analyze;
+mod bar;
+mod output;
+
+// exit codes are part of the command line interface, see the Readme
+const EXIT_CONNECTION_FAILED: i32 = 2;
+const EXIT_DAEMON_ERROR: i32 = 3;
+const EXIT_INVALID_ARGUMENT: i32 = 4;
+const EXIT_NOT_FOUND: i32 = 5;
+const EXIT_FAILED_PRECONDITION: i32 = 6;
+const EXIT_UNAVAILABLE: i32 = 7;
+const EXIT_UNAUTHENTICATED: i32 = 8;
+const EXIT_PERMISSION_DENIED: i32 = 9;
+const EXIT_UNIMPLEMENTED: i32 = 10;
+const EXIT_NOT_SERVING: i32 = 11;
+
+const STATUS_TEMPLATE_FIELDS: &[&str] = &[
+    "channel", "playlist", "image", "image_name", "images", "mode", "total_files", "paused", "last_update",
+    "next_update",
+];
+
+// how responses are printed
+struct Output {
+    format: OutputFormat,
+    template: Option<Template>,
+    // the only playlist shown in the status
+    playlist: Option<String>,
+}
+
+pub(crate) fn main(config_path: &Path, command: Command, options: OutputOptions) {
+    let format = options.format.as_ref().map(|f| OutputFormat::parse(f)).unwrap_or(OutputFormat::Plain);
+
+    // the version of the client is known without the configuration
+    if let Command::Version { daemon: false } = command {
+        print_version(None, format);
+        return;
+    }
 
-pub(crate) fn main(config_path: &Path, command: Command) {
     let config = config::load(config_path)
         .unwrap_or_else(|e| abort!(1, "Cannot load configuration file {}: {}", config_path.display(), e));
 
     let endpoint = config.common.endpoint;
 
-    let client = client::Client::new(&endpoint)
+    let client = client::Client::new(&endpoint, config.common.auth_token, config.common.tls)
         .unwrap_or_else(|e| abort!(1, "Error creating a gRPC client: {}", e));
 
+    let mut output = Output {
+        format,
+        template: None,
+        playlist: None,
+    };
+    if let Some(template) = options.template {
+        match command {
+            Command::Status { .. } | Command::Bar { .. } =>
+                output.template = Some(Template::parse(&template, STATUS_TEMPLATE_FIELDS)
+                    .unwrap_or_else(|e| abort!(1, "Invalid template: {}", e))),
+            _ => abort!(1, "Templates are only supported by `wcd status` and `wcd bar`"),
+        }
+    }
+
+    if let Command::Analyze { channel } = command {
+        if output.format != OutputFormat::Plain {
+            abort!(1, "The analyzer is interactive and only supports the plain format");
+        }
+        analyze::main(config.server.stats.as_ref(), &client, channel);
+        return;
+    }
+
+    if let Command::Bar { channel } = command {
+        // always unwraps
+        let template = output.template
+            .unwrap_or_else(|| Template::parse(bar::DEFAULT_TEMPLATE, STATUS_TEMPLATE_FIELDS).unwrap());
+        bar::main(&client, channel, output.format, template);
+        return;
+    }
+
+    if let Command::Watch { json } = command {
+        watch(&client, if json { OutputFormat::Json } else { output.format });
+        return;
+    }
+
+    let mut export_path = None;
+
     let req = match command {
-        Command::Trigger { keep: false, } => ControlRequest::TriggerChange,
-        Command::Trigger { keep: true, } => ControlRequest::TriggerUpdate,
+        Command::Trigger { keep: false, channel, } => ControlRequest::TriggerChange(channel),
+        Command::Trigger { keep: true, channel, } => ControlRequest::TriggerUpdate(channel),
+        Command::Next { channel, } => ControlRequest::TriggerChange(channel),
+        Command::Prev { channel, } => ControlRequest::ShowPrevious(channel),
         Command::Refresh => ControlRequest::RefreshPlaylists,
         Command::Terminate => ControlRequest::Terminate,
-        Command::Status => ControlRequest::GetStatus,
-        Command::SetPlaylist { name: playlist_name, or_trigger, } => {
+        Command::Version { .. } => ControlRequest::GetVersion,
+        Command::Ping => ControlRequest::CheckHealth,
+        Command::Status { playlist, channel, } => {
+            output.playlist = playlist;
+            ControlRequest::GetStatus(channel)
+        }
+        Command::Stats { action: Some(StatsCommand::Rebuild), .. } => ControlRequest::RebuildStatistics,
+        Command::Stats { action: Some(StatsCommand::Relink), .. } => ControlRequest::RelinkStatistics,
+        Command::Stats { action: Some(StatsCommand::Gc { dry_run }), .. } =>
+            ControlRequest::CollectStatisticsGarbage { dry_run, },
+        Command::Stats { action: Some(StatsCommand::RenamePrefix { old_prefix, new_prefix }), .. } =>
+            // always unwraps
+            ControlRequest::RenameStatisticsPrefix {
+                old_prefix: absolute_image_path(Some(old_prefix)).unwrap(),
+                new_prefix: absolute_image_path(Some(new_prefix)).unwrap(),
+            },
+        Command::Stats { action: Some(StatsCommand::Export { output }), .. } => {
+            export_path = output;
+            ControlRequest::ExportStatistics
+        }
+        Command::Stats { action: Some(StatsCommand::Import { file, merge }), .. } => {
+            let data = fs::read_to_string(&file)
+                .unwrap_or_else(|e| abort!(1, "Cannot read statistics from {}: {}", file, e));
+            ControlRequest::ImportStatistics { data, merge, }
+        }
+        Command::Stats { action: Some(StatsCommand::Report { since, by }), .. } => {
+            let since = since.map(|since| match util::parse_duration(&since) {
+                Some(period) => (Utc::now() - period).timestamp(),
+                None => abort!(1, "Invalid period: {}", since),
+            });
+            ControlRequest::GetReport(ReportQuery {
+                since,
+                group_by: ReportGrouping::from_name(&by).unwrap_or(ReportGrouping::Playlist),
+            })
+        }
+        Command::Stats { options, action: None, } => ControlRequest::GetStatistics(StatsQuery {
+            playlist: options.playlist,
+            sort: StatsSort::from_name(&options.sort).unwrap_or(StatsSort::Displays),
+            top: options.top,
+            missing_only: options.missing_only,
+        }),
+        Command::Pause { channel, } => ControlRequest::Pause(channel),
+        Command::Resume { channel, } => ControlRequest::Resume(channel),
+        Command::Show { image, channel, } =>
+            // always unwraps
+            ControlRequest::ShowImage { channel, image: absolute_image_path(Some(image)).unwrap(), },
+        Command::Rate { rating, image, channel, } =>
+            ControlRequest::RateImage { channel, image: absolute_image_path(image), rating, },
+        Command::Fav { image, channel, } =>
+            ControlRequest::SetFavorite { channel, image: absolute_image_path(image), favorite: true, },
+        Command::Unfav { image, channel, } =>
+            ControlRequest::SetFavorite { channel, image: absolute_image_path(image), favorite: false, },
+        Command::List { upcoming, playlist, channel, } =>
+            ControlRequest::ListImages(ListQuery { channel, playlist, upcoming, }),
+        Command::Ban { image, channel, } =>
+            ControlRequest::BanImage { channel, image: absolute_image_path(image), },
+        Command::Bans { action: BansCommand::List } => ControlRequest::ListBans,
+        Command::Bans { action: BansCommand::Remove { image } } =>
+            // always unwraps
+            ControlRequest::UnbanImage(absolute_image_path(Some(image)).unwrap()),
+        Command::SetPlaylist { name: playlist_name, or_trigger, channel, } => {
             if or_trigger {
-                match make_request(&client, ControlRequest::GetStatus) {
+                match make_request(&client, ControlRequest::GetStatus(channel.clone())) {
                     ControlResponse::StatusInfoOk(StatusInfo { current_playlist, .. }) => {
                         if playlist_name == current_playlist {
-                            ControlRequest::TriggerChange
+                            ControlRequest::TriggerChange(channel)
                         } else {
-                            ControlRequest::ChangePlaylist(playlist_name.clone())
+                            ControlRequest::ChangePlaylist(channel, playlist_name.clone())
                         }
                     }
+                    ControlResponse::StatusInfoFailed(failure) => fail("Failed to retrieve status info", failure),
                     _ => abort!(1, "Unexpected server response when getting current playlist")
                 }
             } else {
-                ControlRequest::ChangePlaylist(playlist_name.clone())
+                ControlRequest::ChangePlaylist(channel, playlist_name.clone())
+            }
+        }
+        Command::Cycle { reverse, channel, } => {
+            match make_request(&client, ControlRequest::GetStatus(channel.clone())) {
+                ControlResponse::StatusInfoOk(StatusInfo { playlists, current_playlist, .. }) => {
+                    let mut names: Vec<_> = playlists.into_keys().collect();
+                    names.sort();
+                    if reverse {
+                        names.reverse();
+                    }
+                    let idx = names.iter().position(|name| *name == current_playlist).unwrap_or(0);
+                    ControlRequest::ChangePlaylist(channel, names[(idx + 1) % names.len()].clone())
+                }
+                ControlResponse::StatusInfoFailed(failure) => fail("Failed to retrieve status info", failure),
+                _ => abort!(1, "Unexpected server response when getting current playlist")
             }
         }
         _ => unreachable!()
     };
 
-    display_response(make_request(&client, req));
+    match (make_request(&client, req), export_path) {
+        (ControlResponse::ExportStatisticsOk(data), Some(path)) =>
+            fs::write(&path, data).unwrap_or_else(|e| abort!(1, "Cannot write statistics to {}: {}", path, e)),
+        (resp, _) => display_response(resp, &output),
+    }
 }
 
 fn make_request(client: &Client, req: ControlRequest) -> ControlResponse {
     client.send(req)
-        .unwrap_or_else(|e| abort!(1, "Error sending request: {}", e))
+        .unwrap_or_else(|e| abort!(EXIT_CONNECTION_FAILED, "Error sending request: {}", e))
 }
 
-fn display_response(resp: ControlResponse) {
+// every kind of failure reported by the daemon exits with its own code
+fn fail(action: &str, failure: Failure) -> ! {
+    let code = match failure.kind {
+        FailureKind::InvalidArgument => EXIT_INVALID_ARGUMENT,
+        FailureKind::NotFound => EXIT_NOT_FOUND,
+        FailureKind::FailedPrecondition => EXIT_FAILED_PRECONDITION,
+        FailureKind::Unavailable => EXIT_UNAVAILABLE,
+        FailureKind::Unauthenticated => EXIT_UNAUTHENTICATED,
+        FailureKind::PermissionDenied => EXIT_PERMISSION_DENIED,
+        FailureKind::Unimplemented => EXIT_UNIMPLEMENTED,
+        FailureKind::Internal => EXIT_DAEMON_ERROR,
+    };
+    abort!(code, "{}: {}", action, failure)
+}
+
+// responses without data print nothing in any format
+fn display_response(resp: ControlResponse, output: &Output) {
+    let format = output.format;
     match resp {
         ControlResponse::TriggerChangeOk | ControlResponse::TriggerUpdateOk | ControlResponse::RefreshPlaylistsOk |
-        ControlResponse::TerminateOk | ControlResponse::ChangePlaylistOk => {}
-        ControlResponse::ChangePlaylistFailed(msg) => abort!(1, "Failed to change playlist: {}", msg),
-        ControlResponse::StatusInfoOk(StatusInfo { playlists, current_playlist, last_update }) => {
-            println!("Last change time: {}", TimestampDisplay(last_update));
-            println!("Current playlist: {}", current_playlist);
-
-            if !playlists.is_empty() {
-                println!("Playlists:");
-                for (name, playlist) in playlists {
-                    print_playlist(name, playlist);
+        ControlResponse::TerminateOk | ControlResponse::ChangePlaylistOk | ControlResponse::ShowImageOk |
+        ControlResponse::ShowPreviousOk |
+        ControlResponse::PauseOk | ControlResponse::ResumeOk | ControlResponse::RebuildStatisticsOk => {}
+        ControlResponse::TriggerChangeFailed(failure) => fail("Failed to change image", failure),
+        ControlResponse::TriggerUpdateFailed(failure) => fail("Failed to update image", failure),
+        ControlResponse::RefreshPlaylistsFailed(failure) => fail("Failed to refresh playlists", failure),
+        ControlResponse::TerminateFailed(failure) => fail("Failed to terminate the daemon", failure),
+        ControlResponse::ChangePlaylistFailed(failure) => fail("Failed to change playlist", failure),
+        ControlResponse::ShowImageFailed(failure) => fail("Failed to show image", failure),
+        ControlResponse::ShowPreviousFailed(failure) => fail("Failed to show previous image", failure),
+        ControlResponse::PauseFailed(failure) => fail("Failed to pause", failure),
+        ControlResponse::ResumeFailed(failure) => fail("Failed to resume", failure),
+        ControlResponse::RebuildStatisticsFailed(failure) => fail("Failed to rebuild statistics", failure),
+        ControlResponse::RelinkStatisticsOk(result) => if !output::print_serialized(&result, format, "result") {
+            let RelinkResult { merged, relinked } = result;
+            println!("Identified {} images by their content, relinked {} files", merged, relinked);
+        }
+        ControlResponse::RelinkStatisticsFailed(failure) => fail("Failed to relink statistics", failure),
+        ControlResponse::GcStatisticsOk(result) => if !output::print_serialized(&result, format, "result") {
+            let GcResult { images, paths, preferences, dry_run } = result;
+            for image in &images {
+                println!("{}", image);
+            }
+            println!(
+                "{} statistics of {} images, {} paths and {} preferences of missing files",
+                if dry_run { "Would remove" } else { "Removed" }, images.len(), paths, preferences
+            );
+        }
+        ControlResponse::GcStatisticsFailed(failure) => fail("Failed to collect garbage", failure),
+        ControlResponse::RenameStatisticsPrefixOk(result) => if !output::print_serialized(&result, format, "result") {
+            let RenameResult { paths, preferences, merged } = result;
+            println!(
+                "Renamed {} paths and {} preferences, merged {} records with existing ones", paths, preferences, merged
+            );
+        }
+        ControlResponse::RenameStatisticsPrefixFailed(failure) => fail("Failed to rename paths", failure),
+        // the export is always JSON, as it is read by `wcd stats import`
+        ControlResponse::ExportStatisticsOk(data) => println!("{}", data),
+        ControlResponse::ExportStatisticsFailed(failure) => fail("Failed to export statistics", failure),
+        ControlResponse::ImportStatisticsOk(result) => if !output::print_serialized(&result, format, "result") {
+            let ImportResult { images, events, duplicate_events } = result;
+            println!(
+                "Imported statistics of {} images and {} display events, skipped {} known display events",
+                images, events, duplicate_events
+            );
+        }
+        ControlResponse::ImportStatisticsFailed(failure) => fail("Failed to import statistics", failure),
+        ControlResponse::ReportOk(report) => print_report(report, format),
+        ControlResponse::ReportFailed(failure) => fail("Failed to build statistics report", failure),
+        ControlResponse::StatusInfoOk(status_info) => print_status(status_info, output),
+        ControlResponse::StatusInfoFailed(failure) => fail("Failed to retrieve status info", failure),
+        ControlResponse::StatsInfoOk(stats_info) => print_stats(stats_info, format),
+        ControlResponse::StatsInfoFailed(failure) => fail("Failed to retrieve statistics", failure),
+        ControlResponse::RateImageOk | ControlResponse::SetFavoriteOk => {}
+        ControlResponse::RateImageFailed(failure) => fail("Failed to rate image", failure),
+        ControlResponse::SetFavoriteFailed(failure) => fail("Failed to update favorites", failure),
+        ControlResponse::BanImageOk | ControlResponse::UnbanImageOk => {}
+        ControlResponse::BanImageFailed(failure) => fail("Failed to ban image", failure),
+        ControlResponse::UnbanImageFailed(failure) => fail("Failed to unban image", failure),
+        ControlResponse::BanListOk(ban_list) => if !output::print_serialized(&ban_list, format, "banned images") {
+            let BanList { bans } = ban_list;
+            if bans.is_empty() {
+                println!("No banned images");
+            }
+            for ban in bans {
+                println!("{}  {}", TimestampDisplay(ban.banned_at), ban.filename);
+            }
+        }
+        ControlResponse::BanListFailed(failure) => fail("Failed to list banned images", failure),
+        ControlResponse::ImageListOk(image_list) => if !output::print_serialized(&image_list, format, "images") {
+            let ImageList { playlist, mode, images } = image_list;
+            println!("Playlist: {} ({})", playlist, ChangeModeDisplay(mode));
+            if images.is_empty() {
+                println!("No images");
+            }
+            for image in images {
+                match image.weight {
+                    Some(weight) => println!("{:>8.4}  {}{}", weight, image.path, OverrideDisplay(image.override_name)),
+                    None => println!("{}{}", image.path, OverrideDisplay(image.override_name)),
                 }
-            } else {
-                println!("No playlists available");
             }
         }
-        ControlResponse::StatusInfoFailed(msg) => abort!(1, "Failed to retrieve status info: {}", msg)
+        ControlResponse::ImageListFailed(failure) => fail("Failed to list images", failure),
+        ControlResponse::VersionOk(version_info) => print_version(Some(version_info), format),
+        ControlResponse::VersionFailed(failure) => fail("Failed to retrieve the daemon version", failure),
+        ControlResponse::HealthOk(status) => print_health(status, format),
+        ControlResponse::HealthFailed(failure) => fail("Failed to check the daemon health", failure),
+    }
+}
+
+#[derive(Serialize)]
+struct Health {
+    status: HealthStatus,
+}
+
+// the exit code tells whether the daemon is serving, so scripts do not need to parse the output
+fn print_health(status: HealthStatus, format: OutputFormat) {
+    if !output::print_serialized(&Health { status }, format, "health") {
+        match status {
+            HealthStatus::Serving => println!("The daemon is serving"),
+            HealthStatus::NotServing =>
+                println!("The daemon is not serving: a current playlist is empty or the statistics storage failed to open"),
+            HealthStatus::Unknown | HealthStatus::ServiceUnknown => println!("The daemon health is unknown"),
+        }
+    }
+    if status != HealthStatus::Serving {
+        abort!(EXIT_NOT_SERVING);
+    }
+}
+
+#[derive(Serialize)]
+struct Versions {
+    client: ClientVersion,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    daemon: Option<VersionInfo>,
+}
+
+#[derive(Serialize)]
+struct ClientVersion {
+    version: &'static str,
+    protocol_version: u32,
+}
+
+fn print_version(daemon: Option<VersionInfo>, format: OutputFormat) {
+    let versions = Versions {
+        client: ClientVersion { version: env!("CARGO_PKG_VERSION"), protocol_version: PROTOCOL_VERSION, },
+        daemon,
+    };
+    if output::print_serialized(&versions, format, "versions") {
+        return;
+    }
+
+    let Versions { client, daemon } = versions;
+    println!("Client: wcd {} (protocol {})", client.version, client.protocol_version);
+    let daemon = match daemon {
+        Some(daemon) => daemon,
+        None => return,
+    };
+    println!("Daemon: wcd {} (protocol {})", daemon.version, daemon.protocol_version);
+    if daemon.protocol_version < client.protocol_version {
+        println!("The daemon is older than the client, restart the daemon to upgrade it");
+    } else if daemon.protocol_version > client.protocol_version {
+        println!("The client is older than the daemon, some requests of the daemon are not available");
+    }
+    println!("Statistics: {}", if daemon.stats_enabled { "enabled" } else { "disabled" });
+    match daemon.watch_interval {
+        Some(interval) => println!("Playlist rescans: every {}", util::format_display_time(interval)),
+        None => println!("Playlist rescans: disabled"),
+    }
+    println!("RPCs: {}", daemon.rpcs.join(", "));
+}
+
+// events are printed one per line in JSON and as separate documents in YAML
+fn watch(client: &Client, format: OutputFormat) {
+    if format == OutputFormat::Csv {
+        abort!(1, "CSV output is only supported by statistics commands");
+    }
+
+    for event in client.subscribe() {
+        let event = match event {
+            Ok(event) => event,
+            Err(::grpc::Error::GrpcMessage(e)) => fail("Failed to receive events", e.into()),
+            Err(e) => abort!(EXIT_CONNECTION_FAILED, "Error receiving events: {}", e),
+        };
+        match format {
+            OutputFormat::Json => match serde_json::to_string(&event) {
+                Ok(line) => println!("{}", line),
+                Err(e) => abort!(1, "Failed to serialize event: {}", e),
+            },
+            OutputFormat::Yaml => match serde_yaml::to_string(&event) {
+                Ok(yaml) => print!("---\n{}", yaml),
+                Err(e) => abort!(1, "Failed to serialize event: {}", e),
+            },
+            _ => print_event(event),
+        }
+    }
+}
+
+fn print_event(event: Event) {
+    let channel = match event.channel {
+        Some(ref channel) => format!(" [{}]", channel),
+        None => String::new(),
+    };
+    let description = match event.kind {
+        EventKind::ImageChanged { playlist, old_images, new_images, reason } => format!(
+            "Image changed in playlist {} ({}): {} -> {}",
+            playlist, DisplayReasonDisplay(reason), ImagesDisplay(&old_images), ImagesDisplay(&new_images)
+        ),
+        EventKind::PlaylistChanged { old_playlist, new_playlist } =>
+            format!("Playlist changed: {} -> {}", old_playlist, new_playlist),
+        EventKind::PlaylistsRefreshed { added, removed } =>
+            format!("Playlists refreshed: {} images added, {} removed", added, removed),
+        EventKind::Paused => "Scheduled changes paused".into(),
+        EventKind::Resumed => "Scheduled changes resumed".into(),
+        EventKind::CommandFailed { playlist, images } =>
+            format!("Change command failed in playlist {} for {}", playlist, ImagesDisplay(&images)),
+        EventKind::ShuttingDown => "Daemon is shutting down".into(),
+        EventKind::Unknown => "Unknown event".into(),
+    };
+    println!("{}{} {}", TimestampDisplay(event.timestamp), channel, description);
+}
+
+fn print_status(mut status_info: StatusInfo, output: &Output) {
+    if let Some(ref name) = output.playlist {
+        match status_info.playlists.remove(name) {
+            Some(playlist) => status_info.playlists = Some((name.clone(), playlist)).into_iter().collect(),
+            None => fail("Failed to retrieve status info", Failure {
+                kind: FailureKind::NotFound,
+                message: format!("Playlist {} does not exist", name),
+            }),
+        }
+    }
+
+    if let Some(ref template) = output.template {
+        println!("{}", render_status(template, &status_info, output.playlist.as_ref()));
+        return;
+    }
+    if output::print_serialized(&status_info, output.format, "status info") {
+        return;
+    }
+
+    let StatusInfo { playlists, current_playlist, last_update, channel, channels, paused } = status_info;
+    if channels.len() > 1 {
+        println!("Channel: {} (available: {})", channel, channels.join(", "));
+    }
+    println!("Last change time: {}", TimestampDisplay(last_update));
+    println!("Current playlist: {}", current_playlist);
+    if paused {
+        println!("Scheduled changes are paused");
+    }
+
+    if !playlists.is_empty() {
+        println!("Playlists:");
+        for (name, playlist) in playlists {
+            print_playlist(name, playlist);
+        }
+    } else {
+        println!("No playlists available");
+    }
+}
+
+// playlist fields describe the requested playlist or the current one
+fn render_status(template: &Template, status_info: &StatusInfo, playlist_name: Option<&String>) -> String {
+    let playlist_name = playlist_name.unwrap_or(&status_info.current_playlist);
+    let playlist = status_info.playlists.get(playlist_name);
+    let images: Vec<&str> = playlist
+        .map(|p| p.current_images.iter().map(|oi| &*oi.image).collect())
+        .unwrap_or_default();
+
+    template.render(|field| match field {
+        "channel" => status_info.channel.clone(),
+        "playlist" => playlist_name.clone(),
+        "image" => images.first().map(|&image| image.to_owned()).unwrap_or_default(),
+        "image_name" => images.first()
+            .and_then(|image| Path::new(image).file_name())
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        "images" => images.join(" "),
+        "mode" => playlist.map(|p| ChangeModeDisplay(p.mode.clone()).to_string()).unwrap_or_default(),
+        "total_files" => playlist.map(|p| p.total_files.to_string()).unwrap_or_default(),
+        "paused" => status_info.paused.to_string(),
+        "last_update" => TimestampDisplay(status_info.last_update).to_string(),
+        "next_update" => playlist.map(|p| TimestampDisplay(p.next_update).to_string()).unwrap_or_default(),
+        // fields are checked when the template is parsed
+        _ => String::new(),
+    })
+}
+
+// image paths are resolved by the daemon, which may run in a different directory
+fn absolute_image_path(image: Option<String>) -> Option<String> {
+    image.map(|image| {
+        let path = Path::new(&image);
+        if path.is_absolute() {
+            image
+        } else {
+            match env::current_dir() {
+                Ok(dir) => dir.join(path).to_string_lossy().into_owned(),
+                Err(e) => abort!(1, "Cannot determine the current directory: {}", e),
+            }
+        }
+    })
+}
+
+fn print_stats(stats_info: StatsInfo, format: OutputFormat) {
+    // printed separately, so it does not break the machine readable formats
+    if stats_info.dropped_writes > 0 {
+        eprintln!(
+            "Warning: {} statistics updates were dropped because the statistics database was too slow",
+            stats_info.dropped_writes
+        );
+    }
+
+    match format {
+        OutputFormat::Plain => {
+            if stats_info.image_stats.is_empty() {
+                println!("No statistics available");
+                return;
+            }
+
+            let rows: Vec<_> = stats_info.image_stats.iter()
+                .map(|s| (
+                    s.total_displays.to_string(),
+                    s.total_skips.to_string(),
+                    util::format_display_time(s.total_display_time),
+                    s.rating.map(|r| r.to_string()).unwrap_or_else(|| "-".into()),
+                    if s.favorite { "*" } else { "" },
+                    &s.filename,
+                ))
+                .collect();
+
+            let displays_width = rows.iter().map(|r| r.0.len()).chain(Some("Displays".len())).max().unwrap();
+            let skips_width = rows.iter().map(|r| r.1.len()).chain(Some("Skips".len())).max().unwrap();
+            let time_width = rows.iter().map(|r| r.2.len()).chain(Some("Time".len())).max().unwrap();
+
+            println!(
+                "{:>dw$}  {:>sw$}  {:>tw$}  Rating  Fav  File",
+                "Displays", "Skips", "Time", dw = displays_width, sw = skips_width, tw = time_width
+            );
+            for (displays, skips, time, rating, favorite, filename) in rows {
+                println!(
+                    "{:>dw$}  {:>sw$}  {:>tw$}  {:>6}  {:>3}  {}",
+                    displays, skips, time, rating, favorite, filename,
+                    dw = displays_width, sw = skips_width, tw = time_width
+                );
+            }
+        }
+        OutputFormat::Csv => {
+            println!("filename,total_displays,total_skips,total_display_time,rating,favorite");
+            for s in stats_info.image_stats {
+                println!(
+                    "{},{},{},{},{},{}",
+                    CsvDisplay(&s.filename), s.total_displays, s.total_skips, s.total_display_time,
+                    s.rating.map(|r| r.to_string()).unwrap_or_default(), s.favorite
+                );
+            }
+        }
+        _ => {
+            output::print_serialized(&stats_info.image_stats, format, "statistics");
+        }
+    }
+}
+
+fn print_report(report: Report, format: OutputFormat) {
+    match format {
+        OutputFormat::Csv => {
+            println!("group,display_time,displays,skips,skip_rate");
+            for g in report.groups {
+                println!("{},{},{},{},{}", CsvDisplay(&g.name), g.display_time, g.displays, g.skips, g.skip_rate);
+            }
+        }
+        OutputFormat::Plain => {
+            if report.groups.is_empty() {
+                println!("No displays recorded in the period");
+                return;
+            }
+
+            let rows: Vec<_> = report.groups.iter()
+                .map(|g| (
+                    util::format_display_time(g.display_time),
+                    g.displays.to_string(),
+                    g.skips.to_string(),
+                    format!("{:.1}%", g.skip_rate * 100.0),
+                    &g.name,
+                ))
+                .collect();
+
+            let time_width = rows.iter().map(|r| r.0.len()).chain(Some("Time".len())).max().unwrap();
+            let displays_width = rows.iter().map(|r| r.1.len()).chain(Some("Displays".len())).max().unwrap();
+            let skips_width = rows.iter().map(|r| r.2.len()).chain(Some("Skips".len())).max().unwrap();
+
+            println!(
+                "{:>tw$}  {:>dw$}  {:>sw$}  Skip rate  Group",
+                "Time", "Displays", "Skips", tw = time_width, dw = displays_width, sw = skips_width
+            );
+            for (time, displays, skips, skip_rate, name) in rows {
+                println!(
+                    "{:>tw$}  {:>dw$}  {:>sw$}  {:>9}  {}",
+                    time, displays, skips, skip_rate, name,
+                    tw = time_width, dw = displays_width, sw = skips_width
+                );
+            }
+        }
+        _ => {
+            output::print_serialized(&report.groups, format, "statistics report");
+        }
     }
 }
 
@@ -89,11 +638,16 @@ fn print_playlist(name: String, playlist: PlaylistInfo) {
         }
     }
     println!("    Total files number: {}", playlist.total_files);
-    println!("    Change mode: {}", match playlist.mode {
-        ChangeMode::Sequential => "sequential",
-        ChangeMode::Random => "random",
-    });
-    println!("    Current image: {}", OptionDisplay(playlist.current_image));
+    println!("    Change mode: {}", ChangeModeDisplay(playlist.mode));
+    if playlist.current_images.len() > 1 {
+        println!("    Current images:");
+        for output_image in playlist.current_images {
+            println!("      {}: {}{}", output_image.output, output_image.image, OverrideDisplay(output_image.override_name));
+        }
+    } else {
+        let override_name = playlist.current_images.into_iter().next().and_then(|oi| oi.override_name);
+        println!("    Current image: {}{}", OptionDisplay(playlist.current_image), OverrideDisplay(override_name));
+    }
     println!("    Trigger wallpaper change on select: {}", BoolDisplay(playlist.trigger_on_select));
     if playlist.trigger_on_select {
         println!("    Use last used wallpaper on select: {}", BoolDisplay(playlist.use_last_on_select));
@@ -121,6 +675,66 @@ impl<T: fmt::Display> fmt::Display for OptionDisplay<T> {
     }
 }
 
+struct ChangeModeDisplay(ChangeMode);
+
+impl fmt::Display for ChangeModeDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self.0 {
+            ChangeMode::Sequential => "sequential",
+            ChangeMode::Random => "random",
+            ChangeMode::Weighted => "weighted",
+        })
+    }
+}
+
+struct DisplayReasonDisplay(DisplayReason);
+
+impl fmt::Display for DisplayReasonDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self.0 {
+            DisplayReason::Scheduled => "scheduled",
+            DisplayReason::Manual => "manual",
+            DisplayReason::Select => "playlist selected",
+            DisplayReason::Restore => "restored",
+        })
+    }
+}
+
+struct ImagesDisplay<'a>(&'a [String]);
+
+impl<'a> fmt::Display for ImagesDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.is_empty() {
+            f.write_str("none")
+        } else {
+            f.write_str(&self.0.join(", "))
+        }
+    }
+}
+
+struct OverrideDisplay(Option<String>);
+
+impl fmt::Display for OverrideDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(ref name) => write!(f, " (override {})", name),
+            None => Ok(()),
+        }
+    }
+}
+
+struct CsvDisplay<'a>(&'a str);
+
+impl<'a> fmt::Display for CsvDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.contains(&[',', '"', '\n'][..]) {
+            write!(f, "\"{}\"", self.0.replace('"', "\"\""))
+        } else {
+            f.write_str(self.0)
+        }
+    }
+}
+
 struct BoolDisplay(bool);
 
 impl fmt::Display for BoolDisplay {
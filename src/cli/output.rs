@@ -0,0 +1,145 @@
+use serde::Serialize;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OutputFormat {
+    Plain,
+    Csv,
+    Json,
+    Yaml,
+}
+
+impl OutputFormat {
+    // the possible values are restricted by the command line parser; `table` is the name of the plain
+    // format used by older versions of statistics commands
+    pub fn parse(s: &str) -> OutputFormat {
+        match s {
+            "csv" => OutputFormat::Csv,
+            "json" => OutputFormat::Json,
+            "yaml" => OutputFormat::Yaml,
+            _ => OutputFormat::Plain,
+        }
+    }
+}
+
+// prints the value if one of the serialization formats is selected, otherwise returns false so the
+// caller can print it for humans
+pub fn print_serialized<T: Serialize>(value: &T, format: OutputFormat, what: &str) -> bool {
+    match format {
+        OutputFormat::Plain => false,
+        OutputFormat::Csv => abort!(1, "CSV output is only supported by statistics commands"),
+        OutputFormat::Json => {
+            match serde_json::to_string_pretty(value) {
+                Ok(json) => println!("{}", json),
+                Err(e) => abort!(1, "Failed to serialize {}: {}", what, e),
+            }
+            true
+        }
+        OutputFormat::Yaml => {
+            match serde_yaml::to_string(value) {
+                Ok(yaml) => print!("{}", yaml),
+                Err(e) => abort!(1, "Failed to serialize {}: {}", what, e),
+            }
+            true
+        }
+    }
+}
+
+enum Segment {
+    Text(String),
+    Field(String),
+}
+
+// a line with `{field}` placeholders; braces are escaped by doubling them
+pub struct Template {
+    segments: Vec<Segment>,
+}
+
+impl Template {
+    pub fn parse(template: &str, fields: &[&str]) -> Result<Template, String> {
+        let mut segments = Vec::new();
+        let mut text = String::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    text.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    text.push('}');
+                }
+                '{' => {
+                    let mut field = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => field.push(c),
+                            None => return Err("unclosed `{`".into()),
+                        }
+                    }
+                    if !fields.contains(&&*field) {
+                        return Err(format!("unknown field `{}`, available fields: {}", field, fields.join(", ")));
+                    }
+                    if !text.is_empty() {
+                        segments.push(Segment::Text(text.split_off(0)));
+                    }
+                    segments.push(Segment::Field(field));
+                }
+                '}' => return Err("unmatched `}`, use `}}` to print it".into()),
+                c => text.push(c),
+            }
+        }
+        if !text.is_empty() {
+            segments.push(Segment::Text(text));
+        }
+
+        Ok(Template { segments })
+    }
+
+    pub fn render<F: Fn(&str) -> String>(&self, value: F) -> String {
+        self.segments.iter()
+            .map(|segment| match segment {
+                Segment::Text(text) => text.clone(),
+                Segment::Field(field) => value(field),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIELDS: &[&str] = &["playlist", "image"];
+
+    fn render(template: &str) -> Result<String, String> {
+        Template::parse(template, FIELDS).map(|t| t.render(|field| format!("<{}>", field)))
+    }
+
+    #[test]
+    fn fields_are_replaced() {
+        assert_eq!(render("{playlist}: {image}").unwrap(), "<playlist>: <image>");
+        assert_eq!(render("{image}{image}").unwrap(), "<image><image>");
+        assert_eq!(render("no fields").unwrap(), "no fields");
+        assert_eq!(render("").unwrap(), "");
+    }
+
+    #[test]
+    fn doubled_braces_are_printed() {
+        assert_eq!(render("{{playlist}}").unwrap(), "{playlist}");
+        assert_eq!(render("{{{image}}}").unwrap(), "{<image>}");
+        assert_eq!(render("}}{{").unwrap(), "}{");
+    }
+
+    #[test]
+    fn invalid_templates_are_rejected() {
+        let unknown = render("{nope}").unwrap_err();
+        assert!(unknown.contains("unknown field `nope`") && unknown.contains("playlist, image"), "{}", unknown);
+        assert!(render("{}").unwrap_err().contains("unknown field"));
+        assert!(render("{image").unwrap_err().contains("unclosed"));
+        assert!(render("image {").unwrap_err().contains("unclosed"));
+        assert!(render("image}").unwrap_err().contains("unmatched"));
+    }
+}
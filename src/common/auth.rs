@@ -0,0 +1,22 @@
+// the token is sent in the standard header for credentials, as a bearer token
+pub const METADATA_KEY: &str = "authorization";
+const SCHEME: &str = "Bearer ";
+
+pub fn metadata_value(token: &str) -> String {
+    format!("{}{}", SCHEME, token)
+}
+
+// `None` if the value does not carry a bearer token
+pub fn token_from_metadata(value: &[u8]) -> Option<&[u8]> {
+    if value.starts_with(SCHEME.as_bytes()) {
+        Some(&value[SCHEME.len()..])
+    } else {
+        None
+    }
+}
+
+// the comparison takes the same time wherever the tokens differ, so the token cannot be guessed
+// byte by byte from response times
+pub fn tokens_equal(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
@@ -1,16 +1,18 @@
 use std::io::{self, Read};
-use std::fs::File;
+use std::fs::{self, File};
 use std::path::{Path, PathBuf};
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::fmt;
 
 use toml;
 use serde::de::Error;
 use serde::Deserializer;
 use serde::Deserialize;
-use serde_derive::Deserialize;
 use chrono::Duration;
 use shellexpand;
+use glob::Pattern;
 
 use crate::common::util;
 
@@ -57,10 +59,61 @@ impl<'a> Deserialize<'a> for WatchMode {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StatsBackend {
+    Sqlite,
+    JsonLines,
+    Memory,
+}
+
+impl<'a> Deserialize<'a> for StatsBackend {
+    fn deserialize<D>(deserializer: D) -> Result<StatsBackend, D::Error> where D: Deserializer<'a> {
+        match String::deserialize(deserializer)?.as_str() {
+            "sqlite" => Ok(StatsBackend::Sqlite),
+            "jsonl" => Ok(StatsBackend::JsonLines),
+            "memory" => Ok(StatsBackend::Memory),
+            other => Err(D::Error::custom(format!("invalid stats_backend value: {}", other))),
+        }
+    }
+}
+
+// what a client may do; each scope includes the ones before it
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Scope {
+    // querying the status, images and statistics
+    Read,
+    // changing images and playlists, rating and banning images
+    Control,
+    // terminating the daemon and maintaining the statistics database
+    Admin,
+}
+
+impl<'a> Deserialize<'a> for Scope {
+    fn deserialize<D>(deserializer: D) -> Result<Scope, D::Error> where D: Deserializer<'a> {
+        match String::deserialize(deserializer)?.as_str() {
+            "read" => Ok(Scope::Read),
+            "control" => Ok(Scope::Control),
+            "admin" => Ok(Scope::Admin),
+            other => Err(D::Error::custom(format!("invalid scope value: {}", other))),
+        }
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Scope::Read => "read",
+            Scope::Control => "control",
+            Scope::Admin => "admin",
+        })
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ChangeMode {
     Sequential,
     Random,
+    Weighted,
 }
 
 impl<'a> Deserialize<'a> for ChangeMode {
@@ -68,11 +121,121 @@ impl<'a> Deserialize<'a> for ChangeMode {
         match String::deserialize(deserializer)?.as_str() {
             "sequential" => Ok(ChangeMode::Sequential),
             "random" => Ok(ChangeMode::Random),
+            "weighted" => Ok(ChangeMode::Weighted),
             other => Err(D::Error::custom(format!("invalid mode value: {}", other))),
         }
     }
 }
 
+// width to height ratio condition of an image
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AspectRatio {
+    Portrait,
+    Landscape,
+    Square,
+    Less(f64),
+    LessOrEqual(f64),
+    Greater(f64),
+    GreaterOrEqual(f64),
+}
+
+impl AspectRatio {
+    pub fn matches(&self, width: usize, height: usize) -> bool {
+        let ratio = width as f64 / height as f64;
+        match *self {
+            AspectRatio::Portrait => width < height,
+            AspectRatio::Landscape => width > height,
+            AspectRatio::Square => width == height,
+            AspectRatio::Less(r) => ratio < r,
+            AspectRatio::LessOrEqual(r) => ratio <= r,
+            AspectRatio::Greater(r) => ratio > r,
+            AspectRatio::GreaterOrEqual(r) => ratio >= r,
+        }
+    }
+}
+
+impl<'a> Deserialize<'a> for AspectRatio {
+    fn deserialize<D>(deserializer: D) -> Result<AspectRatio, D::Error> where D: Deserializer<'a> {
+        let s = String::deserialize(deserializer)?;
+        let s = s.trim();
+
+        let (make_ratio, value): (fn(f64) -> AspectRatio, &str) = match s {
+            "portrait" => return Ok(AspectRatio::Portrait),
+            "landscape" => return Ok(AspectRatio::Landscape),
+            "square" => return Ok(AspectRatio::Square),
+            _ if s.starts_with("<=") => (AspectRatio::LessOrEqual, &s[2..]),
+            _ if s.starts_with(">=") => (AspectRatio::GreaterOrEqual, &s[2..]),
+            _ if s.starts_with('<') => (AspectRatio::Less, &s[1..]),
+            _ if s.starts_with('>') => (AspectRatio::Greater, &s[1..]),
+            _ => return Err(D::Error::custom(format!("invalid aspect ratio value: {}", s))),
+        };
+
+        match value.trim().parse() {
+            Ok(r) => Ok(make_ratio(r)),
+            Err(_) => Err(D::Error::custom(format!("invalid aspect ratio value: {}", s))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outputs {
+    Count(usize),
+    Named(Vec<String>),
+}
+
+impl Outputs {
+    pub fn len(&self) -> usize {
+        match *self {
+            Outputs::Count(n) => n,
+            Outputs::Named(ref names) => names.len(),
+        }
+    }
+
+    pub fn is_named(&self) -> bool {
+        matches!(*self, Outputs::Named(_))
+    }
+
+    // returns the configured output name or its index if outputs are not named
+    pub fn name(&self, idx: usize) -> String {
+        match *self {
+            Outputs::Count(_) => idx.to_string(),
+            Outputs::Named(ref names) => names[idx].clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandArg {
+    Literal(String),
+    // `{}`, the image for the current output, or the first image if the command is run once
+    Path,
+    // `{path:N}`, the image for the N-th output
+    IndexedPath(usize),
+    // `{output}`, the name of the current output
+    Output,
+    // `{fit}`, the configured fit mode
+    Fit,
+}
+
+impl CommandArg {
+    fn parse(arg: &str) -> CommandArg {
+        match arg {
+            "{}" => CommandArg::Path,
+            "{output}" => CommandArg::Output,
+            "{fit}" => CommandArg::Fit,
+            _ if arg.starts_with("{path:") && arg.ends_with('}') => match arg[6..arg.len()-1].parse() {
+                Ok(idx) => CommandArg::IndexedPath(idx),
+                Err(_) => CommandArg::Literal(arg.to_owned()),
+            },
+            _ => CommandArg::Literal(arg.to_owned()),
+        }
+    }
+
+    fn is_path(&self) -> bool {
+        matches!(*self, CommandArg::Path | CommandArg::IndexedPath(_))
+    }
+}
+
 // Configuration directly corresponding to the one stored in file
 
 #[derive(Deserialize, Debug, Clone)]
@@ -83,16 +246,66 @@ pub struct Config {
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct CommonConfig {
-    pub endpoint: String
+    pub endpoint: String,
+    // a file with the token which clients must send with every request
+    pub auth_token_file: Option<String>,
+    // `None` if connections are not encrypted
+    pub tls: Option<TlsConfig>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TlsConfig {
+    // the daemon's certificate chain and private key in PEM files, only read by the daemon
+    pub cert_file: Option<String>,
+    pub key_file: Option<String>,
+    // the authority which signed the daemon's certificate, the system ones are trusted by default
+    pub ca_file: Option<String>,
+    // the name the daemon's certificate is checked against, the host of the endpoint by default
+    pub server_name: Option<String>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct ServerConfig {
-    pub default_playlist: String,
+    pub default_playlist: Option<String>,
+    pub default_channel: Option<String>,
     pub watch: Option<WatchMode>,
     pub defaults: Option<Defaults>,
     pub playlists: HashMap<String, Playlist>,
+    #[serde(default)]
+    pub channels: HashMap<String, Channel>,
     pub stats_db: Option<String>,
+    pub stats_backend: Option<StatsBackend>,
+    pub http: Option<HttpConfig>,
+    pub dbus: Option<DbusConfig>,
+    #[serde(default)]
+    pub tokens: Vec<TokenConfig>,
+    pub anonymous_scope: Option<Scope>,
+}
+
+// an additional token accepted by the daemon, usually with a narrower scope than the common one
+#[derive(Deserialize, Debug, Clone)]
+pub struct TokenConfig {
+    pub file: String,
+    pub scope: Scope,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct HttpConfig {
+    pub listen: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct DbusConfig {
+    pub name: Option<String>,
+    pub address: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Channel {
+    pub default_playlist: Option<String>,
+    pub playlists: Option<Vec<String>>,
+    pub command: Option<Vec<String>>,
+    pub change_every: Option<ParsedDuration>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -106,6 +319,24 @@ pub struct Playlist {
     pub change_every: Option<ParsedDuration>,
     pub trigger_on_select: Option<bool>,
     pub use_last_on_select: Option<bool>,
+    pub outputs: Option<Vec<String>>,
+    pub count: Option<usize>,
+    pub fit: Option<String>,
+    #[serde(default)]
+    pub overrides: Vec<Override>,
+    pub min_rating: Option<u8>,
+    pub favorites_only: Option<bool>,
+    pub weights: Option<Weights>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Override {
+    pub name: Option<String>,
+    pub glob: Option<String>,
+    pub extension: Option<String>,
+    pub aspect: Option<AspectRatio>,
+    pub command: Option<Vec<String>>,
+    pub fit: Option<String>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -115,6 +346,20 @@ pub struct Defaults {
     pub change_every: Option<ParsedDuration>,
     pub trigger_on_select: Option<bool>,
     pub use_last_on_select: Option<bool>,
+    pub outputs: Option<Vec<String>>,
+    pub count: Option<usize>,
+    pub fit: Option<String>,
+    pub weights: Option<Weights>,
+}
+
+// coefficients of the weight formula used by the weighted mode
+#[derive(Deserialize, Debug, Clone)]
+pub struct Weights {
+    pub displays: Option<f64>,
+    pub skips: Option<f64>,
+    pub favorite: Option<f64>,
+    pub recent: Option<f64>,
+    pub recent_period: Option<ParsedDuration>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -134,16 +379,92 @@ impl<'a> Deserialize<'a> for ParsedDuration {
 
 #[derive(Debug, Clone)]
 pub struct ValidatedConfig {
-    pub common: CommonConfig,
+    pub common: ValidatedCommonConfig,
     pub server: ValidatedServerConfig
 }
 
+#[derive(Clone)]
+pub struct ValidatedCommonConfig {
+    pub endpoint: String,
+    // `None` if requests are not authenticated
+    pub auth_token: Option<String>,
+    // `None` if connections are not encrypted
+    pub tls: Option<ValidatedTls>,
+}
+
+// the configuration is logged in the verbose mode, which must not reveal the token
+impl fmt::Debug for ValidatedCommonConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ValidatedCommonConfig")
+            .field("endpoint", &self.endpoint)
+            .field("auth_token", &self.auth_token.as_ref().map(|_| "<redacted>"))
+            .field("tls", &self.tls)
+            .finish()
+    }
+}
+
+// files are only read by the side which needs them, so the same configuration serves the daemon and the client
+#[derive(Debug, Clone)]
+pub struct ValidatedTls {
+    pub cert_file: PathBuf,
+    pub key_file: PathBuf,
+    pub ca_file: Option<PathBuf>,
+    pub server_name: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct ValidatedServerConfig {
-    pub default_playlist: String,
+    pub default_channel: String,
     pub watch: WatchMode,
+    pub channels: HashMap<String, ValidatedChannel>,
+    // `None` if statistics collection is disabled
+    pub stats: Option<StatsStorage>,
+    // address of the HTTP API and the web control panel, `None` if they are disabled
+    pub http: Option<SocketAddr>,
+    // `None` if the D-Bus interface is disabled
+    pub dbus: Option<ValidatedDbus>,
+    pub access: ValidatedAccess,
+}
+
+#[derive(Clone)]
+pub struct ValidatedAccess {
+    // tokens accepted by the control server with their scopes, including the common token
+    pub tokens: Vec<(String, Scope)>,
+    // scope of requests without a token, `None` if they are rejected
+    pub anonymous_scope: Option<Scope>,
+}
+
+impl fmt::Debug for ValidatedAccess {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ValidatedAccess")
+            .field("tokens", &self.tokens.iter().map(|&(_, scope)| ("<redacted>", scope)).collect::<Vec<_>>())
+            .field("anonymous_scope", &self.anonymous_scope)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "dbus"), allow(dead_code))]
+pub struct ValidatedDbus {
+    // the well-known name owned by the daemon on the bus
+    pub name: String,
+    // `None` means the session bus
+    pub address: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatsStorage {
+    Sqlite(PathBuf),
+    JsonLines(PathBuf),
+    // statistics are lost when the daemon stops
+    Memory,
+}
+
+#[derive(Debug, Clone)]
+pub struct ValidatedChannel {
+    pub default_playlist: String,
+    // playlists available in this channel, with channel overrides already applied
     pub playlists: HashMap<String, ValidatedPlaylist>,
-    pub stats_db: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone)]
@@ -151,46 +472,235 @@ pub struct ValidatedPlaylist {
     pub files: Vec<PathBuf>,
     pub directories: Vec<PathBuf>,
     pub command: String,
-    pub command_args: Vec<String>,
+    pub command_args: Vec<CommandArg>,
     pub mode: ChangeMode,
     pub change_every: Duration,
     pub trigger_on_select: bool,
     pub use_last_on_select: bool,
+    pub outputs: Outputs,
+    pub fit: Option<String>,
+    pub overrides: Vec<ValidatedOverride>,
+    // rated images below this rating are excluded, unrated images are always included
+    pub min_rating: Option<u8>,
+    pub favorites_only: bool,
+    pub weights: WeightFormula,
+}
+
+#[derive(Debug, Clone)]
+pub struct WeightFormula {
+    // penalty for each display
+    pub displays: f64,
+    // penalty for the ratio of skips to displays
+    pub skips: f64,
+    // multiplier for favorite images
+    pub favorite: f64,
+    // multiplier for images modified within `recent_period`
+    pub recent: f64,
+    pub recent_period: Duration,
+}
+
+impl Default for WeightFormula {
+    fn default() -> WeightFormula {
+        WeightFormula {
+            displays: 1.0,
+            skips: 2.0,
+            favorite: 3.0,
+            recent: 2.0,
+            recent_period: Duration::days(7),
+        }
+    }
+}
+
+impl WeightFormula {
+    pub fn weight(&self, displays: i64, skips: i64, favorite: bool, recent: bool) -> f64 {
+        let skip_ratio = if displays > 0 { skips as f64 / displays as f64 } else { 0.0 };
+
+        let mut weight = 1.0 / ((1.0 + self.displays * displays as f64) * (1.0 + self.skips * skip_ratio));
+        if favorite {
+            weight *= self.favorite;
+        }
+        if recent {
+            weight *= self.recent;
+        }
+        weight
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ValidatedOverride {
+    pub name: String,
+    pub matcher: ImageMatcher,
+    pub command: Option<(String, Vec<CommandArg>)>,
+    pub fit: Option<String>,
+}
+
+// all present conditions must hold for an image to match
+#[derive(Debug, Clone)]
+pub struct ImageMatcher {
+    pub glob: Option<Pattern>,
+    // always lowercase
+    pub extension: Option<String>,
+    pub aspect: Option<AspectRatio>,
 }
 
+// the name of the channel which is created when no channels are configured explicitly
+pub const DEFAULT_CHANNEL_NAME: &str = "default";
+pub const DEFAULT_DBUS_NAME: &str = "io.github.netvl.Wcd";
+
 pub fn load(path: &Path) -> Result<ValidatedConfig, ConfigError> {
     let mut file = File::open(path)?;
     let mut data = String::new();
     file.read_to_string(&mut data)?;
 
-    validate(toml::from_str::<Config>(&data)?)
+    parse(&data)
+}
+
+pub fn parse(data: &str) -> Result<ValidatedConfig, ConfigError> {
+    validate(toml::from_str::<Config>(data)?)
+}
+
+// `place` describes where the command is configured, e.g. "playlist default"
+fn check_command(cmd: &[String], place: Option<&str>) -> Result<(), ConfigError> {
+    if cmd.is_empty() {
+        Err(if let Some(place) = place {
+            format!("empty command is configured in {}", place).into()
+        } else {
+            "empty default command is configured".into()
+        })
+    } else if !cmd.iter().any(|p| CommandArg::parse(p).is_path()) {
+        Err(if let Some(place) = place {
+            format!("configured command in {} has no file placeholder in it", place).into()
+        } else {
+            "configured default command has no file placeholder in it".into()
+        })
+    } else {
+        Ok(())
+    }
+}
+
+fn check_command_args(args: &[CommandArg], outputs: &Outputs, place: &str) -> Result<(), ConfigError> {
+    let per_output = args.contains(&CommandArg::Output);
+    if per_output && !outputs.is_named() {
+        return Err(format!("configured command in {} uses output placeholder, but outputs are not named", place).into());
+    }
+    for arg in args {
+        if let CommandArg::IndexedPath(idx) = *arg {
+            if per_output {
+                return Err(format!(
+                    "configured command in {} mixes output and indexed file placeholders", place
+                ).into());
+            }
+            if idx >= outputs.len() {
+                return Err(format!(
+                    "configured command in {} refers to file {}, but only {} outputs are configured",
+                    place, idx, outputs.len()
+                ).into());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn parse_command(mut full_command: Vec<String>, outputs: &Outputs, place: &str) -> Result<(String, Vec<CommandArg>), ConfigError> {
+    check_command(&full_command, Some(place))?;
+    let command = full_command.remove(0);  // full_command is checked to be non-empty
+    // full_command now only contains args
+    let command_args: Vec<_> = full_command.iter().map(|a| CommandArg::parse(a)).collect();
+    check_command_args(&command_args, outputs, place)?;
+    Ok((command, command_args))
+}
+
+fn resolve_outputs(outputs: Option<Vec<String>>, count: Option<usize>, playlist: &str) -> Result<Outputs, ConfigError> {
+    match (outputs, count) {
+        (Some(_), Some(_)) =>
+            Err(format!("playlist {} has both outputs and output count configured", playlist).into()),
+        (Some(ref names), None) if names.is_empty() =>
+            Err(format!("playlist {} has an empty list of outputs configured", playlist).into()),
+        (Some(names), None) => Ok(Outputs::Named(names)),
+        (None, Some(0)) =>
+            Err(format!("playlist {} has zero output count configured", playlist).into()),
+        (None, Some(count)) => Ok(Outputs::Count(count)),
+        (None, None) => Ok(Outputs::Count(1)),
+    }
 }
 
 fn validate(config: Config) -> Result<ValidatedConfig, ConfigError> {
     let Config { 
-        server: ServerConfig { default_playlist, watch, defaults, playlists, stats_db },
-        common
+        server: ServerConfig {
+            default_playlist, default_channel, watch, defaults, playlists, channels, stats_db, stats_backend, http, dbus,
+            tokens, anonymous_scope,
+        },
+        common: CommonConfig { endpoint, auth_token_file, tls },
     } = config;
 
-    if !playlists.contains_key(&default_playlist) {
-        return Err(format!("unknown playlist name {} configured as a default playlist", default_playlist).into());
-    }
+    let auth_token = match auth_token_file {
+        Some(path) => Some(read_auth_token(Path::new(&*shellexpand::tilde(&path)))?),
+        None => None,
+    };
 
-    fn check_command(cmd: &[String], playlist: Option<&str>) -> Result<(), ConfigError> {
-        if cmd.is_empty() {
-            Err(if let Some(playlist) = playlist {
-                format!("empty command is configured in playlist {}", playlist).into()
-            } else {
-                "empty default command is configured".into()
+    let expand = |path: String| Path::new(&*shellexpand::tilde(&path)).to_owned();
+    let tls = match tls {
+        Some(TlsConfig { cert_file, key_file, ca_file, server_name }) => {
+            // a configuration without them would only fail when the daemon starts listening
+            let (cert_file, key_file) = match (cert_file, key_file) {
+                (Some(cert_file), Some(key_file)) => (cert_file, key_file),
+                _ => return Err("cert_file and key_file must be set in the TLS configuration".into()),
+            };
+            Some(ValidatedTls {
+                cert_file: expand(cert_file),
+                key_file: expand(key_file),
+                ca_file: ca_file.map(expand),
+                server_name,
             })
-        } else if cmd.iter().all(|p| p != "{}") {
-            Err(if let Some(playlist) = playlist {
-                format!("configured command in playlist {} has no file placeholder in it", playlist).into()
-            } else {
-                "configured default command has no file placeholder in it".into()
-            })
-        } else {
-            Ok(())
+        }
+        None => None,
+    };
+
+    // the common token is the one used by the command line client, so it may do everything
+    let mut scoped_tokens: Vec<(String, Scope)> = auth_token.iter().map(|t| (t.clone(), Scope::Admin)).collect();
+    for TokenConfig { file, scope } in tokens {
+        scoped_tokens.push((read_auth_token(Path::new(&*shellexpand::tilde(&file)))?, scope));
+    }
+    // without any tokens the daemon stays open to everyone who can connect, as before scopes existed
+    let anonymous_scope = match anonymous_scope {
+        Some(scope) => Some(scope),
+        None if scoped_tokens.is_empty() => Some(Scope::Admin),
+        None => None,
+    };
+    let access = ValidatedAccess { tokens: scoped_tokens, anonymous_scope, };
+
+    let stats_db = stats_db.map(|s| Path::new(&*shellexpand::tilde(&s)).to_owned());
+    let stats = match (stats_backend.unwrap_or(StatsBackend::Sqlite), stats_db) {
+        (StatsBackend::Memory, _) => Some(StatsStorage::Memory),
+        (StatsBackend::Sqlite, Some(path)) => Some(StatsStorage::Sqlite(path)),
+        (StatsBackend::JsonLines, Some(path)) => Some(StatsStorage::JsonLines(path)),
+        (StatsBackend::Sqlite, None) => None,
+        (StatsBackend::JsonLines, None) =>
+            return Err("stats_backend is jsonl, but no statistics file is configured in stats_db".into()),
+    };
+
+    let http = match http {
+        Some(HttpConfig { listen }) => match listen.parse() {
+            Ok(addr) => Some(addr),
+            Err(_) => return Err(format!("invalid HTTP listen address {}", listen).into()),
+        },
+        None => None,
+    };
+
+    let dbus = match dbus {
+        Some(DbusConfig { name, address }) => {
+            let name = name.unwrap_or_else(|| DEFAULT_DBUS_NAME.to_owned());
+            if !is_valid_bus_name(&name) {
+                return Err(format!("invalid D-Bus name {}", name).into());
+            }
+            Some(ValidatedDbus { name, address, })
+        }
+        None => None,
+    };
+
+    if let Some(ref default_playlist) = default_playlist {
+        if !playlists.contains_key(default_playlist) {
+            return Err(format!("unknown playlist name {} configured as a default playlist", default_playlist).into());
         }
     }
 
@@ -212,15 +722,79 @@ fn validate(config: Config) -> Result<ValidatedConfig, ConfigError> {
             .map(|p| p.into_owned())
             .collect();
 
+        // either both outputs and count come from the playlist, or both come from the defaults
+        let outputs = if playlist.outputs.is_some() || playlist.count.is_some() {
+            resolve_outputs(playlist.outputs, playlist.count, &name)?
+        } else {
+            resolve_outputs(
+                defaults.and_then(|d| d.outputs.clone()), defaults.and_then(|d| d.count), &name
+            )?
+        };
+
         let (command, command_args) = match playlist.command.or_else(|| defaults.and_then(|d| d.command.clone())) {
-            Some(mut full_command) => {
-                check_command(&full_command, Some(&name))?;
-                let command = full_command.remove(0);  // full_command is checked to be non-empty
-                (command, full_command)  // full_command now only contains args
-            }
+            Some(full_command) => parse_command(full_command, &outputs, &format!("playlist {}", name))?,
             None => return Err(format!("playlist {} has no command configured and no default is set", name).into())
         };
 
+        let fit = playlist.fit.or_else(|| defaults.and_then(|d| d.fit.clone()));
+        if command_args.contains(&CommandArg::Fit) && fit.is_none() {
+            return Err(format!("configured command in playlist {} uses fit placeholder, but no fit mode is set", name).into());
+        }
+
+        let mut overrides = Vec::new();
+        for (idx, ovr) in playlist.overrides.into_iter().enumerate() {
+            let ovr_name = ovr.name.unwrap_or_else(|| format!("#{}", idx + 1));
+            let place = format!("override {} of playlist {}", ovr_name, name);
+
+            if ovr.glob.is_none() && ovr.extension.is_none() && ovr.aspect.is_none() {
+                return Err(format!("{} has no conditions configured", place).into());
+            }
+            if ovr.command.is_none() && ovr.fit.is_none() {
+                return Err(format!("{} changes neither command nor fit mode", place).into());
+            }
+
+            let glob = match ovr.glob {
+                Some(glob) => match Pattern::new(&shellexpand::tilde(&glob)) {
+                    Ok(pattern) => Some(pattern),
+                    Err(e) => return Err(format!("{} has invalid glob pattern: {}", place, e).into())
+                },
+                None => None,
+            };
+
+            let ovr_command = match ovr.command {
+                Some(full_command) => {
+                    let ovr_command = parse_command(full_command, &outputs, &place)?;
+                    if ovr_command.1.contains(&CommandArg::Output) != command_args.contains(&CommandArg::Output) {
+                        return Err(format!(
+                            "command in {} must use the output placeholder if and only if the playlist command uses it",
+                            place
+                        ).into());
+                    }
+                    Some(ovr_command)
+                }
+                None => None,
+            };
+
+            let effective_args = ovr_command.as_ref().map(|c| &c.1).unwrap_or(&command_args);
+            if effective_args.contains(&CommandArg::Fit) && ovr.fit.is_none() && fit.is_none() {
+                return Err(format!("command in {} uses fit placeholder, but no fit mode is set", place).into());
+            }
+            if ovr.fit.is_some() && !effective_args.contains(&CommandArg::Fit) {
+                return Err(format!("{} sets fit mode, but its command has no fit placeholder", place).into());
+            }
+
+            overrides.push(ValidatedOverride {
+                name: ovr_name,
+                matcher: ImageMatcher {
+                    glob,
+                    extension: ovr.extension.map(|e| e.trim_start_matches('.').to_lowercase()),
+                    aspect: ovr.aspect,
+                },
+                command: ovr_command,
+                fit: ovr.fit,
+            });
+        }
+
         let mode = match playlist.mode.or_else(|| defaults.and_then(|d| d.mode)) {
             Some(mode) => mode,
             None => return Err(format!("playlist {} has no change mode configured and no default is set", name).into())
@@ -239,6 +813,49 @@ fn validate(config: Config) -> Result<ValidatedConfig, ConfigError> {
             .or_else(|| defaults.and_then(|d| d.use_last_on_select))
             .unwrap_or(true);
 
+        if let Some(min_rating) = playlist.min_rating {
+            if !(1..=5).contains(&min_rating) {
+                return Err(format!("playlist {} has minimum rating {} outside of the 1-5 range", name, min_rating).into());
+            }
+        }
+
+        let favorites_only = playlist.favorites_only.unwrap_or(false);
+        if (playlist.min_rating.is_some() || favorites_only) && stats.is_none() {
+            return Err(format!(
+                "playlist {} filters images by ratings or favorites, but no statistics database is configured", name
+            ).into());
+        }
+
+        // each coefficient is taken from the playlist, then from the defaults
+        let playlist_weights = playlist.weights.as_ref();
+        let default_weights = defaults.and_then(|d| d.weights.as_ref());
+        macro_rules! weight {
+            ($field:ident) => {
+                playlist_weights.and_then(|w| w.$field.clone())
+                    .or_else(|| default_weights.and_then(|w| w.$field.clone()))
+            }
+        }
+        let mut weights = WeightFormula::default();
+        weights.displays = weight!(displays).unwrap_or(weights.displays);
+        weights.skips = weight!(skips).unwrap_or(weights.skips);
+        weights.favorite = weight!(favorite).unwrap_or(weights.favorite);
+        weights.recent = weight!(recent).unwrap_or(weights.recent);
+        weights.recent_period = weight!(recent_period).map(|p| p.0).unwrap_or(weights.recent_period);
+
+        let coefficients = [weights.displays, weights.skips, weights.favorite, weights.recent];
+        if coefficients.iter().any(|c| !c.is_finite() || *c < 0.0) {
+            return Err(format!("playlist {} has negative or invalid weight coefficients", name).into());
+        }
+        if weights.favorite == 0.0 || weights.recent == 0.0 {
+            return Err(format!("playlist {} has zero favorite or recent weight multiplier", name).into());
+        }
+
+        if mode == ChangeMode::Weighted && stats.is_none() {
+            return Err(format!(
+                "playlist {} uses weighted mode, but no statistics database is configured", name
+            ).into());
+        }
+
         validated_playlists.insert(name, ValidatedPlaylist {
             files: files,
             directories: directories,
@@ -247,20 +864,285 @@ fn validate(config: Config) -> Result<ValidatedConfig, ConfigError> {
             mode: mode,
             change_every: change_every.0,
             trigger_on_select: trigger_on_select,
-            use_last_on_select: use_last_on_select
+            use_last_on_select: use_last_on_select,
+            outputs: outputs,
+            fit: fit,
+            overrides: overrides,
+            min_rating: playlist.min_rating,
+            favorites_only,
+            weights,
         });
     }
 
-    let stats_db = stats_db.map(|s| Path::new(&*shellexpand::tilde(&s)).to_owned());
+    let mut validated_channels = HashMap::new();
+    if channels.is_empty() {
+        // without explicit channels all playlists belong to a single implicit channel
+        let default_playlist = match default_playlist {
+            Some(default_playlist) => default_playlist,
+            None => return Err("no default playlist is configured".into())
+        };
+        validated_channels.insert(DEFAULT_CHANNEL_NAME.to_owned(), ValidatedChannel {
+            default_playlist: default_playlist,
+            playlists: validated_playlists,
+        });
+    } else {
+        for (name, channel) in channels {
+            let channel_default_playlist = match channel.default_playlist.or_else(|| default_playlist.clone()) {
+                Some(default_playlist) => default_playlist,
+                None => return Err(format!("channel {} has no default playlist configured and no default is set", name).into())
+            };
+
+            let playlist_names = channel.playlists
+                .unwrap_or_else(|| validated_playlists.keys().cloned().collect());
+
+            let mut channel_playlists = HashMap::new();
+            for playlist_name in playlist_names {
+                let mut playlist = match validated_playlists.get(&playlist_name) {
+                    Some(playlist) => playlist.clone(),
+                    None => return Err(format!("channel {} refers to unknown playlist {}", name, playlist_name).into())
+                };
+
+                if let Some(ref full_command) = channel.command {
+                    let place = format!("channel {} for playlist {}", name, playlist_name);
+                    let (command, command_args) = parse_command(full_command.clone(), &playlist.outputs, &place)?;
+                    if command_args.contains(&CommandArg::Fit) && playlist.fit.is_none() {
+                        return Err(format!("configured command in {} uses fit placeholder, but no fit mode is set", place).into());
+                    }
+                    playlist.command = command;
+                    playlist.command_args = command_args;
+                }
+                if let Some(ref change_every) = channel.change_every {
+                    playlist.change_every = change_every.0;
+                }
+
+                channel_playlists.insert(playlist_name, playlist);
+            }
+
+            if !channel_playlists.contains_key(&channel_default_playlist) {
+                return Err(format!(
+                    "default playlist {} of channel {} is not available in this channel", channel_default_playlist, name
+                ).into());
+            }
+
+            validated_channels.insert(name, ValidatedChannel {
+                default_playlist: channel_default_playlist,
+                playlists: channel_playlists,
+            });
+        }
+    }
+
+    let default_channel = match default_channel {
+        Some(default_channel) => {
+            if !validated_channels.contains_key(&default_channel) {
+                return Err(format!("unknown channel name {} configured as a default channel", default_channel).into());
+            }
+            default_channel
+        }
+        None if validated_channels.len() == 1 => validated_channels.keys().next().unwrap().clone(),
+        None if validated_channels.contains_key(DEFAULT_CHANNEL_NAME) => DEFAULT_CHANNEL_NAME.to_owned(),
+        None => return Err("multiple channels are configured, but no default channel is set".into())
+    };
 
     Ok(ValidatedConfig {
-        common: common,
+        common: ValidatedCommonConfig { endpoint, auth_token, tls, },
         server: ValidatedServerConfig {
-            default_playlist: default_playlist,
+            default_channel: default_channel,
             watch: watch.unwrap_or_else(|| WatchMode::Poll(Duration::seconds(30))),
-            playlists: validated_playlists,
-            stats_db: stats_db,
+            channels: validated_channels,
+            stats: stats,
+            http: http,
+            dbus: dbus,
+            access: access,
         }
     })
 }
 
+// well-known bus names are dot-separated elements of letters, digits, `_` and `-`, not starting with a digit
+fn is_valid_bus_name(name: &str) -> bool {
+    let elements: Vec<_> = name.split('.').collect();
+    name.len() <= 255 && elements.len() >= 2 && elements.iter().all(|e| {
+        !e.is_empty() && !e.starts_with(|c: char| c.is_ascii_digit()) &&
+            e.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    })
+}
+
+// surrounding whitespace, like the final newline, is not a part of the token
+fn read_auth_token(path: &Path) -> Result<String, ConfigError> {
+    let token = fs::read_to_string(path)
+        .map_err(|e| format!("cannot read auth token file {}: {}", path.display(), e))?;
+    let token = token.trim();
+    if token.is_empty() {
+        return Err(format!("auth token file {} is empty", path.display()).into());
+    }
+    // the token is sent in request metadata, which only allows printable characters
+    if !token.bytes().all(|b| b.is_ascii_graphic()) {
+        return Err(format!("auth token in {} must only contain printable ASCII characters without spaces", path.display()).into());
+    }
+    Ok(token.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<CommandArg> {
+        args.iter().map(|a| CommandArg::parse(a)).collect()
+    }
+
+    fn named(names: &[&str]) -> Outputs {
+        Outputs::Named(names.iter().map(|n| n.to_string()).collect())
+    }
+
+    #[test]
+    fn command_args_are_parsed() {
+        assert_eq!(CommandArg::parse("{}"), CommandArg::Path);
+        assert_eq!(CommandArg::parse("{output}"), CommandArg::Output);
+        assert_eq!(CommandArg::parse("{fit}"), CommandArg::Fit);
+        assert_eq!(CommandArg::parse("{path:0}"), CommandArg::IndexedPath(0));
+        assert_eq!(CommandArg::parse("{path:12}"), CommandArg::IndexedPath(12));
+        // anything else is passed to the command as is
+        for arg in &["--bg-fill", "{path:}", "{path:-1}", "{path:x}", "{path:1", "x{}", "{ }"] {
+            assert_eq!(CommandArg::parse(arg), CommandArg::Literal(arg.to_string()));
+        }
+    }
+
+    #[test]
+    fn outputs_are_resolved() {
+        assert_eq!(resolve_outputs(None, None, "p").unwrap(), Outputs::Count(1));
+        assert_eq!(resolve_outputs(None, Some(3), "p").unwrap(), Outputs::Count(3));
+        assert_eq!(resolve_outputs(Some(vec!["DP-1".into()]), None, "p").unwrap(), named(&["DP-1"]));
+        assert!(resolve_outputs(Some(vec!["DP-1".into()]), Some(1), "p").is_err());
+        assert!(resolve_outputs(Some(vec![]), None, "p").is_err());
+        assert!(resolve_outputs(None, Some(0), "p").is_err());
+    }
+
+    #[test]
+    fn command_args_are_checked_against_outputs() {
+        let check = |a: &[&str], outputs: &Outputs| check_command_args(&args(a), outputs, "playlist p").is_ok();
+
+        assert!(check(&["{}"], &Outputs::Count(1)));
+        assert!(check(&["{path:0}", "{path:1}"], &Outputs::Count(2)));
+        assert!(check(&["{path:0}", "{path:1}"], &named(&["DP-1", "DP-2"])));
+        assert!(check(&["-o", "{output}", "{}"], &named(&["DP-1", "DP-2"])));
+        // indices are zero-based
+        assert!(!check(&["{path:0}", "{path:2}"], &Outputs::Count(2)));
+        // an output name requires named outputs
+        assert!(!check(&["-o", "{output}", "{}"], &Outputs::Count(2)));
+        // a per-output command only gets its own image
+        assert!(!check(&["-o", "{output}", "{path:1}"], &named(&["DP-1", "DP-2"])));
+    }
+
+    #[test]
+    fn commands_require_a_file_placeholder() {
+        let command = |c: &[&str]| c.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+
+        assert!(check_command(&command(&["feh", "{}"]), None).is_ok());
+        assert!(check_command(&command(&["feh", "{path:1}"]), None).is_ok());
+        assert!(check_command(&command(&["feh", "{output}"]), None).is_err());
+        assert!(check_command(&[], Some("playlist p")).is_err());
+    }
+
+    // a configuration with a single playlist, with `settings` added to the playlist section
+    fn parse_playlist(settings: &str) -> Result<ValidatedConfig, ConfigError> {
+        parse(&format!(r#"
+            [common]
+            endpoint = "127.0.0.1:0"
+
+            [server]
+            default_playlist = "default"
+            stats_backend = "memory"
+
+            [server.defaults]
+            command = ["true", "{{}}"]
+            change_every = "1 hour"
+
+            [server.defaults.weights]
+            skips = 4.0
+
+            [server.playlists.default]
+            mode = "weighted"
+            {}
+        "#, settings))
+    }
+
+    #[test]
+    fn weight_coefficients_come_from_the_playlist_then_the_defaults() {
+        let mut config = parse_playlist("weights = { displays = 0.5, recent_period = \"1 day\" }").unwrap().server;
+        let channel = config.channels.remove(&config.default_channel).unwrap();
+        let weights = &channel.playlists["default"].weights;
+
+        assert_eq!(weights.displays, 0.5);
+        assert_eq!(weights.skips, 4.0);
+        assert_eq!(weights.favorite, WeightFormula::default().favorite);
+        assert_eq!(weights.recent_period, Duration::days(1));
+    }
+
+    #[test]
+    fn invalid_weight_coefficients_are_rejected() {
+        assert!(parse_playlist("weights = { displays = -1.0 }").is_err());
+        assert!(parse_playlist("weights = { skips = nan }").is_err());
+        assert!(parse_playlist("weights = { favorite = 0.0 }").is_err());
+        assert!(parse_playlist("weights = { recent = 0.0 }").is_err());
+        // zero penalties just disable them
+        assert!(parse_playlist("weights = { displays = 0.0, skips = 0.0 }").is_ok());
+    }
+
+    #[test]
+    fn weighted_mode_requires_statistics() {
+        let config = parse_playlist("").unwrap().server;
+        assert!(config.stats.is_some());
+        let e = validate(toml::from_str(r#"
+            [common]
+            endpoint = "127.0.0.1:0"
+
+            [server]
+            default_playlist = "default"
+
+            [server.playlists.default]
+            mode = "weighted"
+            command = ["true", "{}"]
+            change_every = "1 hour"
+        "#).unwrap()).unwrap_err();
+        assert!(e.to_string().contains("no statistics database"));
+    }
+
+    fn parse_tls(settings: &str) -> Result<ValidatedConfig, ConfigError> {
+        parse(&format!(r#"
+            [common]
+            endpoint = "127.0.0.1:0"
+
+            [common.tls]
+            {}
+
+            [server]
+            default_playlist = "default"
+
+            [server.playlists.default]
+            mode = "sequential"
+            command = ["true", "{{}}"]
+            change_every = "1 hour"
+        "#, settings))
+    }
+
+    #[test]
+    fn tls_files_are_only_checked_by_their_users() {
+        // clients do not need the daemon's files, so they are not read during validation
+        let tls = parse_tls("cert_file = \"/nonexistent/cert.pem\"\nkey_file = \"/nonexistent/key.pem\"")
+            .unwrap().common.tls.unwrap();
+        assert_eq!(tls.cert_file, PathBuf::from("/nonexistent/cert.pem"));
+        assert_eq!(tls.ca_file, None);
+        assert_eq!(tls.server_name, None);
+    }
+
+    #[test]
+    fn tls_requires_certificate_and_key() {
+        for settings in &[
+            "cert_file = \"/etc/wcd/cert.pem\"",
+            "key_file = \"/etc/wcd/key.pem\"",
+            "ca_file = \"/etc/wcd/ca.pem\"\nserver_name = \"wcd.local\"",
+        ] {
+            let e = parse_tls(settings).unwrap_err();
+            assert!(e.to_string().contains("must be set in the TLS configuration"), "{}", settings);
+        }
+    }
+}
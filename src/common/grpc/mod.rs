@@ -1,2 +1,4 @@
+pub mod health;
+pub mod health_grpc;
 pub mod wcd;
 pub mod wcd_grpc;
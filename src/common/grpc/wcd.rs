@@ -1,4 +1,4 @@
-// This file is generated by rust-protobuf 2.8.1. Do not edit
+// This file is generated by rust-protobuf 2.8.2. Do not edit
 // @generated
 
 // https://github.com/Manishearth/rust-clippy/issues/702
@@ -24,7 +24,7 @@ use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
 
 /// Generated files are compatible only with the same version
 /// of protobuf runtime.
-const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_8_1;
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_8_2;
 
 #[derive(PartialEq,Clone,Default)]
 pub struct Empty {
@@ -152,10 +152,180 @@ impl ::protobuf::reflect::ProtobufValue for Empty {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct ChannelName {
+    // message fields
+    pub channel: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ChannelName {
+    fn default() -> &'a ChannelName {
+        <ChannelName as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ChannelName {
+    pub fn new() -> ChannelName {
+        ::std::default::Default::default()
+    }
+
+    // string channel = 1;
+
+
+    pub fn get_channel(&self) -> &str {
+        &self.channel
+    }
+    pub fn clear_channel(&mut self) {
+        self.channel.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_channel(&mut self, v: ::std::string::String) {
+        self.channel = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_channel(&mut self) -> &mut ::std::string::String {
+        &mut self.channel
+    }
+
+    // Take field
+    pub fn take_channel(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.channel, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ChannelName {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.channel)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.channel.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.channel);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.channel.is_empty() {
+            os.write_string(1, &self.channel)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ChannelName {
+        ChannelName::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "channel",
+                    |m: &ChannelName| { &m.channel },
+                    |m: &mut ChannelName| { &mut m.channel },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ChannelName>(
+                    "ChannelName",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ChannelName {
+        static mut instance: ::protobuf::lazy::Lazy<ChannelName> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ChannelName,
+        };
+        unsafe {
+            instance.get(ChannelName::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ChannelName {
+    fn clear(&mut self) {
+        self.channel.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ChannelName {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ChannelName {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct PlaylistName {
     // message fields
     pub name: ::std::string::String,
+    pub channel: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -197,6 +367,32 @@ impl PlaylistName {
     pub fn take_name(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.name, ::std::string::String::new())
     }
+
+    // string channel = 2;
+
+
+    pub fn get_channel(&self) -> &str {
+        &self.channel
+    }
+    pub fn clear_channel(&mut self) {
+        self.channel.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_channel(&mut self, v: ::std::string::String) {
+        self.channel = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_channel(&mut self) -> &mut ::std::string::String {
+        &mut self.channel
+    }
+
+    // Take field
+    pub fn take_channel(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.channel, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for PlaylistName {
@@ -211,6 +407,9 @@ impl ::protobuf::Message for PlaylistName {
                 1 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
                 },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.channel)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -226,6 +425,9 @@ impl ::protobuf::Message for PlaylistName {
         if !self.name.is_empty() {
             my_size += ::protobuf::rt::string_size(1, &self.name);
         }
+        if !self.channel.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.channel);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -235,6 +437,9 @@ impl ::protobuf::Message for PlaylistName {
         if !self.name.is_empty() {
             os.write_string(1, &self.name)?;
         }
+        if !self.channel.is_empty() {
+            os.write_string(2, &self.channel)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -282,6 +487,11 @@ impl ::protobuf::Message for PlaylistName {
                     |m: &PlaylistName| { &m.name },
                     |m: &mut PlaylistName| { &mut m.name },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "channel",
+                    |m: &PlaylistName| { &m.channel },
+                    |m: &mut PlaylistName| { &mut m.channel },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<PlaylistName>(
                     "PlaylistName",
                     fields,
@@ -305,6 +515,7 @@ impl ::protobuf::Message for PlaylistName {
 impl ::protobuf::Clear for PlaylistName {
     fn clear(&mut self) {
         self.name.clear();
+        self.channel.clear();
         self.unknown_fields.clear();
     }
 }
@@ -327,6 +538,9 @@ pub struct StatusInfo {
     pub playlists: ::std::collections::HashMap<::std::string::String, PlaylistInfo>,
     pub current_playlist: ::std::string::String,
     pub last_update: i64,
+    pub channel: ::std::string::String,
+    pub channels: ::protobuf::RepeatedField<::std::string::String>,
+    pub paused: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -408,6 +622,72 @@ impl StatusInfo {
     pub fn set_last_update(&mut self, v: i64) {
         self.last_update = v;
     }
+
+    // string channel = 4;
+
+
+    pub fn get_channel(&self) -> &str {
+        &self.channel
+    }
+    pub fn clear_channel(&mut self) {
+        self.channel.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_channel(&mut self, v: ::std::string::String) {
+        self.channel = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_channel(&mut self) -> &mut ::std::string::String {
+        &mut self.channel
+    }
+
+    // Take field
+    pub fn take_channel(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.channel, ::std::string::String::new())
+    }
+
+    // repeated string channels = 5;
+
+
+    pub fn get_channels(&self) -> &[::std::string::String] {
+        &self.channels
+    }
+    pub fn clear_channels(&mut self) {
+        self.channels.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_channels(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.channels = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_channels(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.channels
+    }
+
+    // Take field
+    pub fn take_channels(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.channels, ::protobuf::RepeatedField::new())
+    }
+
+    // bool paused = 6;
+
+
+    pub fn get_paused(&self) -> bool {
+        self.paused
+    }
+    pub fn clear_paused(&mut self) {
+        self.paused = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_paused(&mut self, v: bool) {
+        self.paused = v;
+    }
 }
 
 impl ::protobuf::Message for StatusInfo {
@@ -432,6 +712,19 @@ impl ::protobuf::Message for StatusInfo {
                     let tmp = is.read_int64()?;
                     self.last_update = tmp;
                 },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.channel)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.channels)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.paused = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -451,6 +744,15 @@ impl ::protobuf::Message for StatusInfo {
         if self.last_update != 0 {
             my_size += ::protobuf::rt::value_size(3, self.last_update, ::protobuf::wire_format::WireTypeVarint);
         }
+        if !self.channel.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.channel);
+        }
+        for value in &self.channels {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        if self.paused != false {
+            my_size += 2;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -464,6 +766,15 @@ impl ::protobuf::Message for StatusInfo {
         if self.last_update != 0 {
             os.write_int64(3, self.last_update)?;
         }
+        if !self.channel.is_empty() {
+            os.write_string(4, &self.channel)?;
+        }
+        for v in &self.channels {
+            os.write_string(5, &v)?;
+        };
+        if self.paused != false {
+            os.write_bool(6, self.paused)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -521,6 +832,21 @@ impl ::protobuf::Message for StatusInfo {
                     |m: &StatusInfo| { &m.last_update },
                     |m: &mut StatusInfo| { &mut m.last_update },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "channel",
+                    |m: &StatusInfo| { &m.channel },
+                    |m: &mut StatusInfo| { &mut m.channel },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "channels",
+                    |m: &StatusInfo| { &m.channels },
+                    |m: &mut StatusInfo| { &mut m.channels },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "paused",
+                    |m: &StatusInfo| { &m.paused },
+                    |m: &mut StatusInfo| { &mut m.paused },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<StatusInfo>(
                     "StatusInfo",
                     fields,
@@ -546,6 +872,9 @@ impl ::protobuf::Clear for StatusInfo {
         self.playlists.clear();
         self.current_playlist.clear();
         self.last_update = 0;
+        self.channel.clear();
+        self.channels.clear();
+        self.paused = false;
         self.unknown_fields.clear();
     }
 }
@@ -573,6 +902,7 @@ pub struct PlaylistInfo {
     pub trigger_on_select: bool,
     pub use_last_on_select: bool,
     pub next_update: i64,
+    pub current_images: ::protobuf::RepeatedField<OutputImage>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -739,10 +1069,40 @@ impl PlaylistInfo {
     pub fn set_next_update(&mut self, v: i64) {
         self.next_update = v;
     }
+
+    // repeated .wcd.OutputImage current_images = 9;
+
+
+    pub fn get_current_images(&self) -> &[OutputImage] {
+        &self.current_images
+    }
+    pub fn clear_current_images(&mut self) {
+        self.current_images.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_current_images(&mut self, v: ::protobuf::RepeatedField<OutputImage>) {
+        self.current_images = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_current_images(&mut self) -> &mut ::protobuf::RepeatedField<OutputImage> {
+        &mut self.current_images
+    }
+
+    // Take field
+    pub fn take_current_images(&mut self) -> ::protobuf::RepeatedField<OutputImage> {
+        ::std::mem::replace(&mut self.current_images, ::protobuf::RepeatedField::new())
+    }
 }
 
 impl ::protobuf::Message for PlaylistInfo {
     fn is_initialized(&self) -> bool {
+        for v in &self.current_images {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -790,6 +1150,9 @@ impl ::protobuf::Message for PlaylistInfo {
                     let tmp = is.read_int64()?;
                     self.next_update = tmp;
                 },
+                9 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.current_images)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -826,6 +1189,10 @@ impl ::protobuf::Message for PlaylistInfo {
         if self.next_update != 0 {
             my_size += ::protobuf::rt::value_size(8, self.next_update, ::protobuf::wire_format::WireTypeVarint);
         }
+        for value in &self.current_images {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -856,6 +1223,11 @@ impl ::protobuf::Message for PlaylistInfo {
         if self.next_update != 0 {
             os.write_int64(8, self.next_update)?;
         }
+        for v in &self.current_images {
+            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -938,6 +1310,11 @@ impl ::protobuf::Message for PlaylistInfo {
                     |m: &PlaylistInfo| { &m.next_update },
                     |m: &mut PlaylistInfo| { &mut m.next_update },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OutputImage>>(
+                    "current_images",
+                    |m: &PlaylistInfo| { &m.current_images },
+                    |m: &mut PlaylistInfo| { &mut m.current_images },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<PlaylistInfo>(
                     "PlaylistInfo",
                     fields,
@@ -968,6 +1345,7 @@ impl ::protobuf::Clear for PlaylistInfo {
         self.trigger_on_select = false;
         self.use_last_on_select = false;
         self.next_update = 0;
+        self.current_images.clear();
         self.unknown_fields.clear();
     }
 }
@@ -985,54 +1363,6477 @@ impl ::protobuf::reflect::ProtobufValue for PlaylistInfo {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct StatsInfo {
+pub struct OutputImage {
     // message fields
-    pub image_stats: ::protobuf::RepeatedField<ImageStatsInfo>,
+    pub output: ::std::string::String,
+    pub image: ::std::string::String,
+    pub override_name: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a StatsInfo {
-    fn default() -> &'a StatsInfo {
-        <StatsInfo as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a OutputImage {
+    fn default() -> &'a OutputImage {
+        <OutputImage as ::protobuf::Message>::default_instance()
     }
 }
 
-impl StatsInfo {
-    pub fn new() -> StatsInfo {
+impl OutputImage {
+    pub fn new() -> OutputImage {
         ::std::default::Default::default()
     }
 
-    // repeated .wcd.ImageStatsInfo image_stats = 1;
+    // string output = 1;
 
 
-    pub fn get_image_stats(&self) -> &[ImageStatsInfo] {
-        &self.image_stats
+    pub fn get_output(&self) -> &str {
+        &self.output
     }
-    pub fn clear_image_stats(&mut self) {
-        self.image_stats.clear();
+    pub fn clear_output(&mut self) {
+        self.output.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_image_stats(&mut self, v: ::protobuf::RepeatedField<ImageStatsInfo>) {
-        self.image_stats = v;
+    pub fn set_output(&mut self, v: ::std::string::String) {
+        self.output = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_image_stats(&mut self) -> &mut ::protobuf::RepeatedField<ImageStatsInfo> {
-        &mut self.image_stats
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_output(&mut self) -> &mut ::std::string::String {
+        &mut self.output
     }
 
     // Take field
-    pub fn take_image_stats(&mut self) -> ::protobuf::RepeatedField<ImageStatsInfo> {
-        ::std::mem::replace(&mut self.image_stats, ::protobuf::RepeatedField::new())
+    pub fn take_output(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.output, ::std::string::String::new())
+    }
+
+    // string image = 2;
+
+
+    pub fn get_image(&self) -> &str {
+        &self.image
+    }
+    pub fn clear_image(&mut self) {
+        self.image.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_image(&mut self, v: ::std::string::String) {
+        self.image = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_image(&mut self) -> &mut ::std::string::String {
+        &mut self.image
+    }
+
+    // Take field
+    pub fn take_image(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.image, ::std::string::String::new())
+    }
+
+    // string override_name = 3;
+
+
+    pub fn get_override_name(&self) -> &str {
+        &self.override_name
+    }
+    pub fn clear_override_name(&mut self) {
+        self.override_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_override_name(&mut self, v: ::std::string::String) {
+        self.override_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_override_name(&mut self) -> &mut ::std::string::String {
+        &mut self.override_name
+    }
+
+    // Take field
+    pub fn take_override_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.override_name, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for OutputImage {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.output)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.image)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.override_name)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.output.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.output);
+        }
+        if !self.image.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.image);
+        }
+        if !self.override_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.override_name);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.output.is_empty() {
+            os.write_string(1, &self.output)?;
+        }
+        if !self.image.is_empty() {
+            os.write_string(2, &self.image)?;
+        }
+        if !self.override_name.is_empty() {
+            os.write_string(3, &self.override_name)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> OutputImage {
+        OutputImage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "output",
+                    |m: &OutputImage| { &m.output },
+                    |m: &mut OutputImage| { &mut m.output },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "image",
+                    |m: &OutputImage| { &m.image },
+                    |m: &mut OutputImage| { &mut m.image },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "override_name",
+                    |m: &OutputImage| { &m.override_name },
+                    |m: &mut OutputImage| { &mut m.override_name },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OutputImage>(
+                    "OutputImage",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static OutputImage {
+        static mut instance: ::protobuf::lazy::Lazy<OutputImage> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OutputImage,
+        };
+        unsafe {
+            instance.get(OutputImage::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for OutputImage {
+    fn clear(&mut self) {
+        self.output.clear();
+        self.image.clear();
+        self.override_name.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for OutputImage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OutputImage {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Event {
+    // message fields
+    pub timestamp: i64,
+    pub channel: ::std::string::String,
+    // message oneof groups
+    pub kind: ::std::option::Option<Event_oneof_kind>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Event {
+    fn default() -> &'a Event {
+        <Event as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum Event_oneof_kind {
+    image_changed(ImageChanged),
+    playlist_changed(PlaylistChanged),
+    playlists_refreshed(PlaylistsRefreshed),
+    paused(Empty),
+    resumed(Empty),
+    command_failed(CommandFailed),
+    shutting_down(Empty),
+}
+
+impl Event {
+    pub fn new() -> Event {
+        ::std::default::Default::default()
+    }
+
+    // int64 timestamp = 1;
+
+
+    pub fn get_timestamp(&self) -> i64 {
+        self.timestamp
+    }
+    pub fn clear_timestamp(&mut self) {
+        self.timestamp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_timestamp(&mut self, v: i64) {
+        self.timestamp = v;
+    }
+
+    // string channel = 2;
+
+
+    pub fn get_channel(&self) -> &str {
+        &self.channel
+    }
+    pub fn clear_channel(&mut self) {
+        self.channel.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_channel(&mut self, v: ::std::string::String) {
+        self.channel = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_channel(&mut self) -> &mut ::std::string::String {
+        &mut self.channel
+    }
+
+    // Take field
+    pub fn take_channel(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.channel, ::std::string::String::new())
+    }
+
+    // .wcd.ImageChanged image_changed = 3;
+
+
+    pub fn get_image_changed(&self) -> &ImageChanged {
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::image_changed(ref v)) => v,
+            _ => ImageChanged::default_instance(),
+        }
+    }
+    pub fn clear_image_changed(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_image_changed(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::image_changed(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_image_changed(&mut self, v: ImageChanged) {
+        self.kind = ::std::option::Option::Some(Event_oneof_kind::image_changed(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_image_changed(&mut self) -> &mut ImageChanged {
+        if let ::std::option::Option::Some(Event_oneof_kind::image_changed(_)) = self.kind {
+        } else {
+            self.kind = ::std::option::Option::Some(Event_oneof_kind::image_changed(ImageChanged::new()));
+        }
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::image_changed(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_image_changed(&mut self) -> ImageChanged {
+        if self.has_image_changed() {
+            match self.kind.take() {
+                ::std::option::Option::Some(Event_oneof_kind::image_changed(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ImageChanged::new()
+        }
+    }
+
+    // .wcd.PlaylistChanged playlist_changed = 4;
+
+
+    pub fn get_playlist_changed(&self) -> &PlaylistChanged {
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::playlist_changed(ref v)) => v,
+            _ => PlaylistChanged::default_instance(),
+        }
+    }
+    pub fn clear_playlist_changed(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_playlist_changed(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::playlist_changed(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_playlist_changed(&mut self, v: PlaylistChanged) {
+        self.kind = ::std::option::Option::Some(Event_oneof_kind::playlist_changed(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_playlist_changed(&mut self) -> &mut PlaylistChanged {
+        if let ::std::option::Option::Some(Event_oneof_kind::playlist_changed(_)) = self.kind {
+        } else {
+            self.kind = ::std::option::Option::Some(Event_oneof_kind::playlist_changed(PlaylistChanged::new()));
+        }
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::playlist_changed(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_playlist_changed(&mut self) -> PlaylistChanged {
+        if self.has_playlist_changed() {
+            match self.kind.take() {
+                ::std::option::Option::Some(Event_oneof_kind::playlist_changed(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PlaylistChanged::new()
+        }
+    }
+
+    // .wcd.PlaylistsRefreshed playlists_refreshed = 5;
+
+
+    pub fn get_playlists_refreshed(&self) -> &PlaylistsRefreshed {
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::playlists_refreshed(ref v)) => v,
+            _ => PlaylistsRefreshed::default_instance(),
+        }
+    }
+    pub fn clear_playlists_refreshed(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_playlists_refreshed(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::playlists_refreshed(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_playlists_refreshed(&mut self, v: PlaylistsRefreshed) {
+        self.kind = ::std::option::Option::Some(Event_oneof_kind::playlists_refreshed(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_playlists_refreshed(&mut self) -> &mut PlaylistsRefreshed {
+        if let ::std::option::Option::Some(Event_oneof_kind::playlists_refreshed(_)) = self.kind {
+        } else {
+            self.kind = ::std::option::Option::Some(Event_oneof_kind::playlists_refreshed(PlaylistsRefreshed::new()));
+        }
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::playlists_refreshed(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_playlists_refreshed(&mut self) -> PlaylistsRefreshed {
+        if self.has_playlists_refreshed() {
+            match self.kind.take() {
+                ::std::option::Option::Some(Event_oneof_kind::playlists_refreshed(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PlaylistsRefreshed::new()
+        }
+    }
+
+    // .wcd.Empty paused = 6;
+
+
+    pub fn get_paused(&self) -> &Empty {
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::paused(ref v)) => v,
+            _ => Empty::default_instance(),
+        }
+    }
+    pub fn clear_paused(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_paused(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::paused(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_paused(&mut self, v: Empty) {
+        self.kind = ::std::option::Option::Some(Event_oneof_kind::paused(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_paused(&mut self) -> &mut Empty {
+        if let ::std::option::Option::Some(Event_oneof_kind::paused(_)) = self.kind {
+        } else {
+            self.kind = ::std::option::Option::Some(Event_oneof_kind::paused(Empty::new()));
+        }
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::paused(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_paused(&mut self) -> Empty {
+        if self.has_paused() {
+            match self.kind.take() {
+                ::std::option::Option::Some(Event_oneof_kind::paused(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Empty::new()
+        }
+    }
+
+    // .wcd.Empty resumed = 7;
+
+
+    pub fn get_resumed(&self) -> &Empty {
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::resumed(ref v)) => v,
+            _ => Empty::default_instance(),
+        }
+    }
+    pub fn clear_resumed(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_resumed(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::resumed(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resumed(&mut self, v: Empty) {
+        self.kind = ::std::option::Option::Some(Event_oneof_kind::resumed(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_resumed(&mut self) -> &mut Empty {
+        if let ::std::option::Option::Some(Event_oneof_kind::resumed(_)) = self.kind {
+        } else {
+            self.kind = ::std::option::Option::Some(Event_oneof_kind::resumed(Empty::new()));
+        }
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::resumed(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_resumed(&mut self) -> Empty {
+        if self.has_resumed() {
+            match self.kind.take() {
+                ::std::option::Option::Some(Event_oneof_kind::resumed(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Empty::new()
+        }
+    }
+
+    // .wcd.CommandFailed command_failed = 8;
+
+
+    pub fn get_command_failed(&self) -> &CommandFailed {
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::command_failed(ref v)) => v,
+            _ => CommandFailed::default_instance(),
+        }
+    }
+    pub fn clear_command_failed(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_command_failed(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::command_failed(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_command_failed(&mut self, v: CommandFailed) {
+        self.kind = ::std::option::Option::Some(Event_oneof_kind::command_failed(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_command_failed(&mut self) -> &mut CommandFailed {
+        if let ::std::option::Option::Some(Event_oneof_kind::command_failed(_)) = self.kind {
+        } else {
+            self.kind = ::std::option::Option::Some(Event_oneof_kind::command_failed(CommandFailed::new()));
+        }
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::command_failed(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_command_failed(&mut self) -> CommandFailed {
+        if self.has_command_failed() {
+            match self.kind.take() {
+                ::std::option::Option::Some(Event_oneof_kind::command_failed(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CommandFailed::new()
+        }
+    }
+
+    // .wcd.Empty shutting_down = 9;
+
+
+    pub fn get_shutting_down(&self) -> &Empty {
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::shutting_down(ref v)) => v,
+            _ => Empty::default_instance(),
+        }
+    }
+    pub fn clear_shutting_down(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_shutting_down(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::shutting_down(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shutting_down(&mut self, v: Empty) {
+        self.kind = ::std::option::Option::Some(Event_oneof_kind::shutting_down(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_shutting_down(&mut self) -> &mut Empty {
+        if let ::std::option::Option::Some(Event_oneof_kind::shutting_down(_)) = self.kind {
+        } else {
+            self.kind = ::std::option::Option::Some(Event_oneof_kind::shutting_down(Empty::new()));
+        }
+        match self.kind {
+            ::std::option::Option::Some(Event_oneof_kind::shutting_down(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_shutting_down(&mut self) -> Empty {
+        if self.has_shutting_down() {
+            match self.kind.take() {
+                ::std::option::Option::Some(Event_oneof_kind::shutting_down(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Empty::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for Event {
+    fn is_initialized(&self) -> bool {
+        if let Some(Event_oneof_kind::image_changed(ref v)) = self.kind {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Event_oneof_kind::playlist_changed(ref v)) = self.kind {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Event_oneof_kind::playlists_refreshed(ref v)) = self.kind {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Event_oneof_kind::paused(ref v)) = self.kind {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Event_oneof_kind::resumed(ref v)) = self.kind {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Event_oneof_kind::command_failed(ref v)) = self.kind {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Event_oneof_kind::shutting_down(ref v)) = self.kind {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.timestamp = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.channel)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.kind = ::std::option::Option::Some(Event_oneof_kind::image_changed(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.kind = ::std::option::Option::Some(Event_oneof_kind::playlist_changed(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.kind = ::std::option::Option::Some(Event_oneof_kind::playlists_refreshed(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.kind = ::std::option::Option::Some(Event_oneof_kind::paused(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.kind = ::std::option::Option::Some(Event_oneof_kind::resumed(is.read_message()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.kind = ::std::option::Option::Some(Event_oneof_kind::command_failed(is.read_message()?));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.kind = ::std::option::Option::Some(Event_oneof_kind::shutting_down(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.timestamp != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.timestamp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.channel.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.channel);
+        }
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &Event_oneof_kind::image_changed(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Event_oneof_kind::playlist_changed(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Event_oneof_kind::playlists_refreshed(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Event_oneof_kind::paused(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Event_oneof_kind::resumed(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Event_oneof_kind::command_failed(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Event_oneof_kind::shutting_down(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.timestamp != 0 {
+            os.write_int64(1, self.timestamp)?;
+        }
+        if !self.channel.is_empty() {
+            os.write_string(2, &self.channel)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &Event_oneof_kind::image_changed(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Event_oneof_kind::playlist_changed(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Event_oneof_kind::playlists_refreshed(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Event_oneof_kind::paused(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Event_oneof_kind::resumed(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Event_oneof_kind::command_failed(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Event_oneof_kind::shutting_down(ref v) => {
+                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Event {
+        Event::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "timestamp",
+                    |m: &Event| { &m.timestamp },
+                    |m: &mut Event| { &mut m.timestamp },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "channel",
+                    |m: &Event| { &m.channel },
+                    |m: &mut Event| { &mut m.channel },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ImageChanged>(
+                    "image_changed",
+                    Event::has_image_changed,
+                    Event::get_image_changed,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, PlaylistChanged>(
+                    "playlist_changed",
+                    Event::has_playlist_changed,
+                    Event::get_playlist_changed,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, PlaylistsRefreshed>(
+                    "playlists_refreshed",
+                    Event::has_playlists_refreshed,
+                    Event::get_playlists_refreshed,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Empty>(
+                    "paused",
+                    Event::has_paused,
+                    Event::get_paused,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Empty>(
+                    "resumed",
+                    Event::has_resumed,
+                    Event::get_resumed,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, CommandFailed>(
+                    "command_failed",
+                    Event::has_command_failed,
+                    Event::get_command_failed,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Empty>(
+                    "shutting_down",
+                    Event::has_shutting_down,
+                    Event::get_shutting_down,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Event>(
+                    "Event",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Event {
+        static mut instance: ::protobuf::lazy::Lazy<Event> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Event,
+        };
+        unsafe {
+            instance.get(Event::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Event {
+    fn clear(&mut self) {
+        self.timestamp = 0;
+        self.channel.clear();
+        self.kind = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Event {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Event {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ImageChanged {
+    // message fields
+    pub playlist: ::std::string::String,
+    pub old_images: ::protobuf::RepeatedField<::std::string::String>,
+    pub new_images: ::protobuf::RepeatedField<::std::string::String>,
+    pub reason: DisplayReason,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ImageChanged {
+    fn default() -> &'a ImageChanged {
+        <ImageChanged as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ImageChanged {
+    pub fn new() -> ImageChanged {
+        ::std::default::Default::default()
+    }
+
+    // string playlist = 1;
+
+
+    pub fn get_playlist(&self) -> &str {
+        &self.playlist
+    }
+    pub fn clear_playlist(&mut self) {
+        self.playlist.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_playlist(&mut self, v: ::std::string::String) {
+        self.playlist = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_playlist(&mut self) -> &mut ::std::string::String {
+        &mut self.playlist
+    }
+
+    // Take field
+    pub fn take_playlist(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.playlist, ::std::string::String::new())
+    }
+
+    // repeated string old_images = 2;
+
+
+    pub fn get_old_images(&self) -> &[::std::string::String] {
+        &self.old_images
+    }
+    pub fn clear_old_images(&mut self) {
+        self.old_images.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_old_images(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.old_images = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_old_images(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.old_images
+    }
+
+    // Take field
+    pub fn take_old_images(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.old_images, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string new_images = 3;
+
+
+    pub fn get_new_images(&self) -> &[::std::string::String] {
+        &self.new_images
+    }
+    pub fn clear_new_images(&mut self) {
+        self.new_images.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_new_images(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.new_images = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_new_images(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.new_images
+    }
+
+    // Take field
+    pub fn take_new_images(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.new_images, ::protobuf::RepeatedField::new())
+    }
+
+    // .wcd.DisplayReason reason = 4;
+
+
+    pub fn get_reason(&self) -> DisplayReason {
+        self.reason
+    }
+    pub fn clear_reason(&mut self) {
+        self.reason = DisplayReason::SCHEDULED;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reason(&mut self, v: DisplayReason) {
+        self.reason = v;
+    }
+}
+
+impl ::protobuf::Message for ImageChanged {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.playlist)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.old_images)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.new_images)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.reason, 4, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.playlist.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.playlist);
+        }
+        for value in &self.old_images {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in &self.new_images {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        if self.reason != DisplayReason::SCHEDULED {
+            my_size += ::protobuf::rt::enum_size(4, self.reason);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.playlist.is_empty() {
+            os.write_string(1, &self.playlist)?;
+        }
+        for v in &self.old_images {
+            os.write_string(2, &v)?;
+        };
+        for v in &self.new_images {
+            os.write_string(3, &v)?;
+        };
+        if self.reason != DisplayReason::SCHEDULED {
+            os.write_enum(4, self.reason.value())?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ImageChanged {
+        ImageChanged::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "playlist",
+                    |m: &ImageChanged| { &m.playlist },
+                    |m: &mut ImageChanged| { &mut m.playlist },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "old_images",
+                    |m: &ImageChanged| { &m.old_images },
+                    |m: &mut ImageChanged| { &mut m.old_images },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "new_images",
+                    |m: &ImageChanged| { &m.new_images },
+                    |m: &mut ImageChanged| { &mut m.new_images },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<DisplayReason>>(
+                    "reason",
+                    |m: &ImageChanged| { &m.reason },
+                    |m: &mut ImageChanged| { &mut m.reason },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ImageChanged>(
+                    "ImageChanged",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ImageChanged {
+        static mut instance: ::protobuf::lazy::Lazy<ImageChanged> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ImageChanged,
+        };
+        unsafe {
+            instance.get(ImageChanged::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ImageChanged {
+    fn clear(&mut self) {
+        self.playlist.clear();
+        self.old_images.clear();
+        self.new_images.clear();
+        self.reason = DisplayReason::SCHEDULED;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ImageChanged {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ImageChanged {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PlaylistChanged {
+    // message fields
+    pub old_playlist: ::std::string::String,
+    pub new_playlist: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PlaylistChanged {
+    fn default() -> &'a PlaylistChanged {
+        <PlaylistChanged as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PlaylistChanged {
+    pub fn new() -> PlaylistChanged {
+        ::std::default::Default::default()
+    }
+
+    // string old_playlist = 1;
+
+
+    pub fn get_old_playlist(&self) -> &str {
+        &self.old_playlist
+    }
+    pub fn clear_old_playlist(&mut self) {
+        self.old_playlist.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_old_playlist(&mut self, v: ::std::string::String) {
+        self.old_playlist = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_old_playlist(&mut self) -> &mut ::std::string::String {
+        &mut self.old_playlist
+    }
+
+    // Take field
+    pub fn take_old_playlist(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.old_playlist, ::std::string::String::new())
+    }
+
+    // string new_playlist = 2;
+
+
+    pub fn get_new_playlist(&self) -> &str {
+        &self.new_playlist
+    }
+    pub fn clear_new_playlist(&mut self) {
+        self.new_playlist.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_new_playlist(&mut self, v: ::std::string::String) {
+        self.new_playlist = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_new_playlist(&mut self) -> &mut ::std::string::String {
+        &mut self.new_playlist
+    }
+
+    // Take field
+    pub fn take_new_playlist(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.new_playlist, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for PlaylistChanged {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.old_playlist)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.new_playlist)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.old_playlist.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.old_playlist);
+        }
+        if !self.new_playlist.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.new_playlist);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.old_playlist.is_empty() {
+            os.write_string(1, &self.old_playlist)?;
+        }
+        if !self.new_playlist.is_empty() {
+            os.write_string(2, &self.new_playlist)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PlaylistChanged {
+        PlaylistChanged::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "old_playlist",
+                    |m: &PlaylistChanged| { &m.old_playlist },
+                    |m: &mut PlaylistChanged| { &mut m.old_playlist },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "new_playlist",
+                    |m: &PlaylistChanged| { &m.new_playlist },
+                    |m: &mut PlaylistChanged| { &mut m.new_playlist },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PlaylistChanged>(
+                    "PlaylistChanged",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PlaylistChanged {
+        static mut instance: ::protobuf::lazy::Lazy<PlaylistChanged> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PlaylistChanged,
+        };
+        unsafe {
+            instance.get(PlaylistChanged::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PlaylistChanged {
+    fn clear(&mut self) {
+        self.old_playlist.clear();
+        self.new_playlist.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PlaylistChanged {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PlaylistChanged {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PlaylistsRefreshed {
+    // message fields
+    pub added: u64,
+    pub removed: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PlaylistsRefreshed {
+    fn default() -> &'a PlaylistsRefreshed {
+        <PlaylistsRefreshed as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PlaylistsRefreshed {
+    pub fn new() -> PlaylistsRefreshed {
+        ::std::default::Default::default()
+    }
+
+    // uint64 added = 1;
+
+
+    pub fn get_added(&self) -> u64 {
+        self.added
+    }
+    pub fn clear_added(&mut self) {
+        self.added = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_added(&mut self, v: u64) {
+        self.added = v;
+    }
+
+    // uint64 removed = 2;
+
+
+    pub fn get_removed(&self) -> u64 {
+        self.removed
+    }
+    pub fn clear_removed(&mut self) {
+        self.removed = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_removed(&mut self, v: u64) {
+        self.removed = v;
+    }
+}
+
+impl ::protobuf::Message for PlaylistsRefreshed {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.added = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.removed = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.added != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.added, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.removed != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.removed, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.added != 0 {
+            os.write_uint64(1, self.added)?;
+        }
+        if self.removed != 0 {
+            os.write_uint64(2, self.removed)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PlaylistsRefreshed {
+        PlaylistsRefreshed::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "added",
+                    |m: &PlaylistsRefreshed| { &m.added },
+                    |m: &mut PlaylistsRefreshed| { &mut m.added },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "removed",
+                    |m: &PlaylistsRefreshed| { &m.removed },
+                    |m: &mut PlaylistsRefreshed| { &mut m.removed },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PlaylistsRefreshed>(
+                    "PlaylistsRefreshed",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PlaylistsRefreshed {
+        static mut instance: ::protobuf::lazy::Lazy<PlaylistsRefreshed> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PlaylistsRefreshed,
+        };
+        unsafe {
+            instance.get(PlaylistsRefreshed::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PlaylistsRefreshed {
+    fn clear(&mut self) {
+        self.added = 0;
+        self.removed = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PlaylistsRefreshed {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PlaylistsRefreshed {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CommandFailed {
+    // message fields
+    pub playlist: ::std::string::String,
+    pub images: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CommandFailed {
+    fn default() -> &'a CommandFailed {
+        <CommandFailed as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CommandFailed {
+    pub fn new() -> CommandFailed {
+        ::std::default::Default::default()
+    }
+
+    // string playlist = 1;
+
+
+    pub fn get_playlist(&self) -> &str {
+        &self.playlist
+    }
+    pub fn clear_playlist(&mut self) {
+        self.playlist.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_playlist(&mut self, v: ::std::string::String) {
+        self.playlist = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_playlist(&mut self) -> &mut ::std::string::String {
+        &mut self.playlist
+    }
+
+    // Take field
+    pub fn take_playlist(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.playlist, ::std::string::String::new())
+    }
+
+    // repeated string images = 2;
+
+
+    pub fn get_images(&self) -> &[::std::string::String] {
+        &self.images
+    }
+    pub fn clear_images(&mut self) {
+        self.images.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_images(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.images = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_images(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.images
+    }
+
+    // Take field
+    pub fn take_images(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.images, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CommandFailed {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.playlist)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.images)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.playlist.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.playlist);
+        }
+        for value in &self.images {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.playlist.is_empty() {
+            os.write_string(1, &self.playlist)?;
+        }
+        for v in &self.images {
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CommandFailed {
+        CommandFailed::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "playlist",
+                    |m: &CommandFailed| { &m.playlist },
+                    |m: &mut CommandFailed| { &mut m.playlist },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "images",
+                    |m: &CommandFailed| { &m.images },
+                    |m: &mut CommandFailed| { &mut m.images },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CommandFailed>(
+                    "CommandFailed",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CommandFailed {
+        static mut instance: ::protobuf::lazy::Lazy<CommandFailed> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CommandFailed,
+        };
+        unsafe {
+            instance.get(CommandFailed::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CommandFailed {
+    fn clear(&mut self) {
+        self.playlist.clear();
+        self.images.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CommandFailed {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CommandFailed {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StatsQuery {
+    // message fields
+    pub playlist: ::std::string::String,
+    pub sort: StatsSort,
+    pub top: u32,
+    pub missing_only: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StatsQuery {
+    fn default() -> &'a StatsQuery {
+        <StatsQuery as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StatsQuery {
+    pub fn new() -> StatsQuery {
+        ::std::default::Default::default()
+    }
+
+    // string playlist = 1;
+
+
+    pub fn get_playlist(&self) -> &str {
+        &self.playlist
+    }
+    pub fn clear_playlist(&mut self) {
+        self.playlist.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_playlist(&mut self, v: ::std::string::String) {
+        self.playlist = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_playlist(&mut self) -> &mut ::std::string::String {
+        &mut self.playlist
+    }
+
+    // Take field
+    pub fn take_playlist(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.playlist, ::std::string::String::new())
+    }
+
+    // .wcd.StatsSort sort = 2;
+
+
+    pub fn get_sort(&self) -> StatsSort {
+        self.sort
+    }
+    pub fn clear_sort(&mut self) {
+        self.sort = StatsSort::FILENAME;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sort(&mut self, v: StatsSort) {
+        self.sort = v;
+    }
+
+    // uint32 top = 3;
+
+
+    pub fn get_top(&self) -> u32 {
+        self.top
+    }
+    pub fn clear_top(&mut self) {
+        self.top = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_top(&mut self, v: u32) {
+        self.top = v;
+    }
+
+    // bool missing_only = 4;
+
+
+    pub fn get_missing_only(&self) -> bool {
+        self.missing_only
+    }
+    pub fn clear_missing_only(&mut self) {
+        self.missing_only = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_missing_only(&mut self, v: bool) {
+        self.missing_only = v;
+    }
+}
+
+impl ::protobuf::Message for StatsQuery {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.playlist)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.sort, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.top = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.missing_only = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.playlist.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.playlist);
+        }
+        if self.sort != StatsSort::FILENAME {
+            my_size += ::protobuf::rt::enum_size(2, self.sort);
+        }
+        if self.top != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.top, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.missing_only != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.playlist.is_empty() {
+            os.write_string(1, &self.playlist)?;
+        }
+        if self.sort != StatsSort::FILENAME {
+            os.write_enum(2, self.sort.value())?;
+        }
+        if self.top != 0 {
+            os.write_uint32(3, self.top)?;
+        }
+        if self.missing_only != false {
+            os.write_bool(4, self.missing_only)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StatsQuery {
+        StatsQuery::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "playlist",
+                    |m: &StatsQuery| { &m.playlist },
+                    |m: &mut StatsQuery| { &mut m.playlist },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<StatsSort>>(
+                    "sort",
+                    |m: &StatsQuery| { &m.sort },
+                    |m: &mut StatsQuery| { &mut m.sort },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "top",
+                    |m: &StatsQuery| { &m.top },
+                    |m: &mut StatsQuery| { &mut m.top },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "missing_only",
+                    |m: &StatsQuery| { &m.missing_only },
+                    |m: &mut StatsQuery| { &mut m.missing_only },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<StatsQuery>(
+                    "StatsQuery",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StatsQuery {
+        static mut instance: ::protobuf::lazy::Lazy<StatsQuery> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StatsQuery,
+        };
+        unsafe {
+            instance.get(StatsQuery::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StatsQuery {
+    fn clear(&mut self) {
+        self.playlist.clear();
+        self.sort = StatsSort::FILENAME;
+        self.top = 0;
+        self.missing_only = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StatsQuery {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StatsQuery {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StatsInfo {
+    // message fields
+    pub image_stats: ::protobuf::RepeatedField<ImageStatsInfo>,
+    pub dropped_writes: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StatsInfo {
+    fn default() -> &'a StatsInfo {
+        <StatsInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StatsInfo {
+    pub fn new() -> StatsInfo {
+        ::std::default::Default::default()
+    }
+
+    // repeated .wcd.ImageStatsInfo image_stats = 1;
+
+
+    pub fn get_image_stats(&self) -> &[ImageStatsInfo] {
+        &self.image_stats
+    }
+    pub fn clear_image_stats(&mut self) {
+        self.image_stats.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_image_stats(&mut self, v: ::protobuf::RepeatedField<ImageStatsInfo>) {
+        self.image_stats = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_image_stats(&mut self) -> &mut ::protobuf::RepeatedField<ImageStatsInfo> {
+        &mut self.image_stats
+    }
+
+    // Take field
+    pub fn take_image_stats(&mut self) -> ::protobuf::RepeatedField<ImageStatsInfo> {
+        ::std::mem::replace(&mut self.image_stats, ::protobuf::RepeatedField::new())
+    }
+
+    // uint64 dropped_writes = 2;
+
+
+    pub fn get_dropped_writes(&self) -> u64 {
+        self.dropped_writes
+    }
+    pub fn clear_dropped_writes(&mut self) {
+        self.dropped_writes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dropped_writes(&mut self, v: u64) {
+        self.dropped_writes = v;
+    }
+}
+
+impl ::protobuf::Message for StatsInfo {
+    fn is_initialized(&self) -> bool {
+        for v in &self.image_stats {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.image_stats)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.dropped_writes = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.image_stats {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.dropped_writes != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.dropped_writes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.image_stats {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.dropped_writes != 0 {
+            os.write_uint64(2, self.dropped_writes)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StatsInfo {
+        StatsInfo::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ImageStatsInfo>>(
+                    "image_stats",
+                    |m: &StatsInfo| { &m.image_stats },
+                    |m: &mut StatsInfo| { &mut m.image_stats },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "dropped_writes",
+                    |m: &StatsInfo| { &m.dropped_writes },
+                    |m: &mut StatsInfo| { &mut m.dropped_writes },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<StatsInfo>(
+                    "StatsInfo",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StatsInfo {
+        static mut instance: ::protobuf::lazy::Lazy<StatsInfo> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StatsInfo,
+        };
+        unsafe {
+            instance.get(StatsInfo::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StatsInfo {
+    fn clear(&mut self) {
+        self.image_stats.clear();
+        self.dropped_writes = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StatsInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StatsInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ImageStatsInfo {
+    // message fields
+    pub filename: ::std::string::String,
+    pub total_displays: i64,
+    pub total_skips: i64,
+    pub total_display_time: i64,
+    pub rating: u32,
+    pub favorite: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ImageStatsInfo {
+    fn default() -> &'a ImageStatsInfo {
+        <ImageStatsInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ImageStatsInfo {
+    pub fn new() -> ImageStatsInfo {
+        ::std::default::Default::default()
+    }
+
+    // string filename = 1;
+
+
+    pub fn get_filename(&self) -> &str {
+        &self.filename
+    }
+    pub fn clear_filename(&mut self) {
+        self.filename.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_filename(&mut self, v: ::std::string::String) {
+        self.filename = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_filename(&mut self) -> &mut ::std::string::String {
+        &mut self.filename
+    }
+
+    // Take field
+    pub fn take_filename(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.filename, ::std::string::String::new())
+    }
+
+    // int64 total_displays = 2;
+
+
+    pub fn get_total_displays(&self) -> i64 {
+        self.total_displays
+    }
+    pub fn clear_total_displays(&mut self) {
+        self.total_displays = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total_displays(&mut self, v: i64) {
+        self.total_displays = v;
+    }
+
+    // int64 total_skips = 3;
+
+
+    pub fn get_total_skips(&self) -> i64 {
+        self.total_skips
+    }
+    pub fn clear_total_skips(&mut self) {
+        self.total_skips = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total_skips(&mut self, v: i64) {
+        self.total_skips = v;
+    }
+
+    // int64 total_display_time = 4;
+
+
+    pub fn get_total_display_time(&self) -> i64 {
+        self.total_display_time
+    }
+    pub fn clear_total_display_time(&mut self) {
+        self.total_display_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total_display_time(&mut self, v: i64) {
+        self.total_display_time = v;
+    }
+
+    // uint32 rating = 5;
+
+
+    pub fn get_rating(&self) -> u32 {
+        self.rating
+    }
+    pub fn clear_rating(&mut self) {
+        self.rating = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rating(&mut self, v: u32) {
+        self.rating = v;
+    }
+
+    // bool favorite = 6;
+
+
+    pub fn get_favorite(&self) -> bool {
+        self.favorite
+    }
+    pub fn clear_favorite(&mut self) {
+        self.favorite = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_favorite(&mut self, v: bool) {
+        self.favorite = v;
+    }
+}
+
+impl ::protobuf::Message for ImageStatsInfo {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.filename)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.total_displays = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.total_skips = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.total_display_time = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.rating = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.favorite = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.filename.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.filename);
+        }
+        if self.total_displays != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.total_displays, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.total_skips != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.total_skips, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.total_display_time != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.total_display_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.rating != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.rating, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.favorite != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.filename.is_empty() {
+            os.write_string(1, &self.filename)?;
+        }
+        if self.total_displays != 0 {
+            os.write_int64(2, self.total_displays)?;
+        }
+        if self.total_skips != 0 {
+            os.write_int64(3, self.total_skips)?;
+        }
+        if self.total_display_time != 0 {
+            os.write_int64(4, self.total_display_time)?;
+        }
+        if self.rating != 0 {
+            os.write_uint32(5, self.rating)?;
+        }
+        if self.favorite != false {
+            os.write_bool(6, self.favorite)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ImageStatsInfo {
+        ImageStatsInfo::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "filename",
+                    |m: &ImageStatsInfo| { &m.filename },
+                    |m: &mut ImageStatsInfo| { &mut m.filename },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "total_displays",
+                    |m: &ImageStatsInfo| { &m.total_displays },
+                    |m: &mut ImageStatsInfo| { &mut m.total_displays },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "total_skips",
+                    |m: &ImageStatsInfo| { &m.total_skips },
+                    |m: &mut ImageStatsInfo| { &mut m.total_skips },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "total_display_time",
+                    |m: &ImageStatsInfo| { &m.total_display_time },
+                    |m: &mut ImageStatsInfo| { &mut m.total_display_time },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "rating",
+                    |m: &ImageStatsInfo| { &m.rating },
+                    |m: &mut ImageStatsInfo| { &mut m.rating },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "favorite",
+                    |m: &ImageStatsInfo| { &m.favorite },
+                    |m: &mut ImageStatsInfo| { &mut m.favorite },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ImageStatsInfo>(
+                    "ImageStatsInfo",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ImageStatsInfo {
+        static mut instance: ::protobuf::lazy::Lazy<ImageStatsInfo> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ImageStatsInfo,
+        };
+        unsafe {
+            instance.get(ImageStatsInfo::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ImageStatsInfo {
+    fn clear(&mut self) {
+        self.filename.clear();
+        self.total_displays = 0;
+        self.total_skips = 0;
+        self.total_display_time = 0;
+        self.rating = 0;
+        self.favorite = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ImageStatsInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ImageStatsInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RelinkResult {
+    // message fields
+    pub merged: u32,
+    pub relinked: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RelinkResult {
+    fn default() -> &'a RelinkResult {
+        <RelinkResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RelinkResult {
+    pub fn new() -> RelinkResult {
+        ::std::default::Default::default()
+    }
+
+    // uint32 merged = 1;
+
+
+    pub fn get_merged(&self) -> u32 {
+        self.merged
+    }
+    pub fn clear_merged(&mut self) {
+        self.merged = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_merged(&mut self, v: u32) {
+        self.merged = v;
+    }
+
+    // uint32 relinked = 2;
+
+
+    pub fn get_relinked(&self) -> u32 {
+        self.relinked
+    }
+    pub fn clear_relinked(&mut self) {
+        self.relinked = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_relinked(&mut self, v: u32) {
+        self.relinked = v;
+    }
+}
+
+impl ::protobuf::Message for RelinkResult {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.merged = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.relinked = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.merged != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.merged, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.relinked != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.relinked, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.merged != 0 {
+            os.write_uint32(1, self.merged)?;
+        }
+        if self.relinked != 0 {
+            os.write_uint32(2, self.relinked)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RelinkResult {
+        RelinkResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "merged",
+                    |m: &RelinkResult| { &m.merged },
+                    |m: &mut RelinkResult| { &mut m.merged },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "relinked",
+                    |m: &RelinkResult| { &m.relinked },
+                    |m: &mut RelinkResult| { &mut m.relinked },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RelinkResult>(
+                    "RelinkResult",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static RelinkResult {
+        static mut instance: ::protobuf::lazy::Lazy<RelinkResult> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RelinkResult,
+        };
+        unsafe {
+            instance.get(RelinkResult::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for RelinkResult {
+    fn clear(&mut self) {
+        self.merged = 0;
+        self.relinked = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RelinkResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RelinkResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GcQuery {
+    // message fields
+    pub dry_run: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GcQuery {
+    fn default() -> &'a GcQuery {
+        <GcQuery as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GcQuery {
+    pub fn new() -> GcQuery {
+        ::std::default::Default::default()
+    }
+
+    // bool dry_run = 1;
+
+
+    pub fn get_dry_run(&self) -> bool {
+        self.dry_run
+    }
+    pub fn clear_dry_run(&mut self) {
+        self.dry_run = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dry_run(&mut self, v: bool) {
+        self.dry_run = v;
+    }
+}
+
+impl ::protobuf::Message for GcQuery {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.dry_run = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.dry_run != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.dry_run != false {
+            os.write_bool(1, self.dry_run)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GcQuery {
+        GcQuery::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "dry_run",
+                    |m: &GcQuery| { &m.dry_run },
+                    |m: &mut GcQuery| { &mut m.dry_run },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<GcQuery>(
+                    "GcQuery",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static GcQuery {
+        static mut instance: ::protobuf::lazy::Lazy<GcQuery> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const GcQuery,
+        };
+        unsafe {
+            instance.get(GcQuery::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for GcQuery {
+    fn clear(&mut self) {
+        self.dry_run = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GcQuery {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GcQuery {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GcResult {
+    // message fields
+    pub images: ::protobuf::RepeatedField<::std::string::String>,
+    pub paths: u32,
+    pub preferences: u32,
+    pub dry_run: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GcResult {
+    fn default() -> &'a GcResult {
+        <GcResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GcResult {
+    pub fn new() -> GcResult {
+        ::std::default::Default::default()
+    }
+
+    // repeated string images = 1;
+
+
+    pub fn get_images(&self) -> &[::std::string::String] {
+        &self.images
+    }
+    pub fn clear_images(&mut self) {
+        self.images.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_images(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.images = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_images(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.images
+    }
+
+    // Take field
+    pub fn take_images(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.images, ::protobuf::RepeatedField::new())
+    }
+
+    // uint32 paths = 2;
+
+
+    pub fn get_paths(&self) -> u32 {
+        self.paths
+    }
+    pub fn clear_paths(&mut self) {
+        self.paths = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_paths(&mut self, v: u32) {
+        self.paths = v;
+    }
+
+    // uint32 preferences = 3;
+
+
+    pub fn get_preferences(&self) -> u32 {
+        self.preferences
+    }
+    pub fn clear_preferences(&mut self) {
+        self.preferences = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_preferences(&mut self, v: u32) {
+        self.preferences = v;
+    }
+
+    // bool dry_run = 4;
+
+
+    pub fn get_dry_run(&self) -> bool {
+        self.dry_run
+    }
+    pub fn clear_dry_run(&mut self) {
+        self.dry_run = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dry_run(&mut self, v: bool) {
+        self.dry_run = v;
+    }
+}
+
+impl ::protobuf::Message for GcResult {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.images)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.paths = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.preferences = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.dry_run = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.images {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        if self.paths != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.paths, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.preferences != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.preferences, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.dry_run != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.images {
+            os.write_string(1, &v)?;
+        };
+        if self.paths != 0 {
+            os.write_uint32(2, self.paths)?;
+        }
+        if self.preferences != 0 {
+            os.write_uint32(3, self.preferences)?;
+        }
+        if self.dry_run != false {
+            os.write_bool(4, self.dry_run)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GcResult {
+        GcResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "images",
+                    |m: &GcResult| { &m.images },
+                    |m: &mut GcResult| { &mut m.images },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "paths",
+                    |m: &GcResult| { &m.paths },
+                    |m: &mut GcResult| { &mut m.paths },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "preferences",
+                    |m: &GcResult| { &m.preferences },
+                    |m: &mut GcResult| { &mut m.preferences },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "dry_run",
+                    |m: &GcResult| { &m.dry_run },
+                    |m: &mut GcResult| { &mut m.dry_run },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<GcResult>(
+                    "GcResult",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static GcResult {
+        static mut instance: ::protobuf::lazy::Lazy<GcResult> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const GcResult,
+        };
+        unsafe {
+            instance.get(GcResult::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for GcResult {
+    fn clear(&mut self) {
+        self.images.clear();
+        self.paths = 0;
+        self.preferences = 0;
+        self.dry_run = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GcResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GcResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RenamePrefix {
+    // message fields
+    pub old_prefix: ::std::string::String,
+    pub new_prefix: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RenamePrefix {
+    fn default() -> &'a RenamePrefix {
+        <RenamePrefix as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RenamePrefix {
+    pub fn new() -> RenamePrefix {
+        ::std::default::Default::default()
+    }
+
+    // string old_prefix = 1;
+
+
+    pub fn get_old_prefix(&self) -> &str {
+        &self.old_prefix
+    }
+    pub fn clear_old_prefix(&mut self) {
+        self.old_prefix.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_old_prefix(&mut self, v: ::std::string::String) {
+        self.old_prefix = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_old_prefix(&mut self) -> &mut ::std::string::String {
+        &mut self.old_prefix
+    }
+
+    // Take field
+    pub fn take_old_prefix(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.old_prefix, ::std::string::String::new())
+    }
+
+    // string new_prefix = 2;
+
+
+    pub fn get_new_prefix(&self) -> &str {
+        &self.new_prefix
+    }
+    pub fn clear_new_prefix(&mut self) {
+        self.new_prefix.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_new_prefix(&mut self, v: ::std::string::String) {
+        self.new_prefix = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_new_prefix(&mut self) -> &mut ::std::string::String {
+        &mut self.new_prefix
+    }
+
+    // Take field
+    pub fn take_new_prefix(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.new_prefix, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RenamePrefix {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.old_prefix)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.new_prefix)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.old_prefix.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.old_prefix);
+        }
+        if !self.new_prefix.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.new_prefix);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.old_prefix.is_empty() {
+            os.write_string(1, &self.old_prefix)?;
+        }
+        if !self.new_prefix.is_empty() {
+            os.write_string(2, &self.new_prefix)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RenamePrefix {
+        RenamePrefix::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "old_prefix",
+                    |m: &RenamePrefix| { &m.old_prefix },
+                    |m: &mut RenamePrefix| { &mut m.old_prefix },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "new_prefix",
+                    |m: &RenamePrefix| { &m.new_prefix },
+                    |m: &mut RenamePrefix| { &mut m.new_prefix },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RenamePrefix>(
+                    "RenamePrefix",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static RenamePrefix {
+        static mut instance: ::protobuf::lazy::Lazy<RenamePrefix> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RenamePrefix,
+        };
+        unsafe {
+            instance.get(RenamePrefix::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for RenamePrefix {
+    fn clear(&mut self) {
+        self.old_prefix.clear();
+        self.new_prefix.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RenamePrefix {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RenamePrefix {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RenameResult {
+    // message fields
+    pub paths: u32,
+    pub preferences: u32,
+    pub merged: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RenameResult {
+    fn default() -> &'a RenameResult {
+        <RenameResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RenameResult {
+    pub fn new() -> RenameResult {
+        ::std::default::Default::default()
+    }
+
+    // uint32 paths = 1;
+
+
+    pub fn get_paths(&self) -> u32 {
+        self.paths
+    }
+    pub fn clear_paths(&mut self) {
+        self.paths = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_paths(&mut self, v: u32) {
+        self.paths = v;
+    }
+
+    // uint32 preferences = 2;
+
+
+    pub fn get_preferences(&self) -> u32 {
+        self.preferences
+    }
+    pub fn clear_preferences(&mut self) {
+        self.preferences = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_preferences(&mut self, v: u32) {
+        self.preferences = v;
+    }
+
+    // uint32 merged = 3;
+
+
+    pub fn get_merged(&self) -> u32 {
+        self.merged
+    }
+    pub fn clear_merged(&mut self) {
+        self.merged = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_merged(&mut self, v: u32) {
+        self.merged = v;
+    }
+}
+
+impl ::protobuf::Message for RenameResult {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.paths = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.preferences = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.merged = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.paths != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.paths, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.preferences != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.preferences, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.merged != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.merged, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.paths != 0 {
+            os.write_uint32(1, self.paths)?;
+        }
+        if self.preferences != 0 {
+            os.write_uint32(2, self.preferences)?;
+        }
+        if self.merged != 0 {
+            os.write_uint32(3, self.merged)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RenameResult {
+        RenameResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "paths",
+                    |m: &RenameResult| { &m.paths },
+                    |m: &mut RenameResult| { &mut m.paths },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "preferences",
+                    |m: &RenameResult| { &m.preferences },
+                    |m: &mut RenameResult| { &mut m.preferences },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "merged",
+                    |m: &RenameResult| { &m.merged },
+                    |m: &mut RenameResult| { &mut m.merged },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RenameResult>(
+                    "RenameResult",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static RenameResult {
+        static mut instance: ::protobuf::lazy::Lazy<RenameResult> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RenameResult,
+        };
+        unsafe {
+            instance.get(RenameResult::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for RenameResult {
+    fn clear(&mut self) {
+        self.paths = 0;
+        self.preferences = 0;
+        self.merged = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RenameResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RenameResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StatsExport {
+    // message fields
+    pub data: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StatsExport {
+    fn default() -> &'a StatsExport {
+        <StatsExport as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StatsExport {
+    pub fn new() -> StatsExport {
+        ::std::default::Default::default()
+    }
+
+    // string data = 1;
+
+
+    pub fn get_data(&self) -> &str {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::string::String) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::string::String {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.data, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for StatsExport {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.data);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.data.is_empty() {
+            os.write_string(1, &self.data)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StatsExport {
+        StatsExport::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "data",
+                    |m: &StatsExport| { &m.data },
+                    |m: &mut StatsExport| { &mut m.data },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<StatsExport>(
+                    "StatsExport",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StatsExport {
+        static mut instance: ::protobuf::lazy::Lazy<StatsExport> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StatsExport,
+        };
+        unsafe {
+            instance.get(StatsExport::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StatsExport {
+    fn clear(&mut self) {
+        self.data.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StatsExport {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StatsExport {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StatsImport {
+    // message fields
+    pub data: ::std::string::String,
+    pub merge: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StatsImport {
+    fn default() -> &'a StatsImport {
+        <StatsImport as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StatsImport {
+    pub fn new() -> StatsImport {
+        ::std::default::Default::default()
+    }
+
+    // string data = 1;
+
+
+    pub fn get_data(&self) -> &str {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::string::String) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::string::String {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.data, ::std::string::String::new())
+    }
+
+    // bool merge = 2;
+
+
+    pub fn get_merge(&self) -> bool {
+        self.merge
+    }
+    pub fn clear_merge(&mut self) {
+        self.merge = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_merge(&mut self, v: bool) {
+        self.merge = v;
+    }
+}
+
+impl ::protobuf::Message for StatsImport {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.data)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.merge = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.data);
+        }
+        if self.merge != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.data.is_empty() {
+            os.write_string(1, &self.data)?;
+        }
+        if self.merge != false {
+            os.write_bool(2, self.merge)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StatsImport {
+        StatsImport::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "data",
+                    |m: &StatsImport| { &m.data },
+                    |m: &mut StatsImport| { &mut m.data },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "merge",
+                    |m: &StatsImport| { &m.merge },
+                    |m: &mut StatsImport| { &mut m.merge },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<StatsImport>(
+                    "StatsImport",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StatsImport {
+        static mut instance: ::protobuf::lazy::Lazy<StatsImport> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StatsImport,
+        };
+        unsafe {
+            instance.get(StatsImport::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StatsImport {
+    fn clear(&mut self) {
+        self.data.clear();
+        self.merge = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StatsImport {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StatsImport {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ImportResult {
+    // message fields
+    pub images: u32,
+    pub events: u32,
+    pub duplicate_events: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ImportResult {
+    fn default() -> &'a ImportResult {
+        <ImportResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ImportResult {
+    pub fn new() -> ImportResult {
+        ::std::default::Default::default()
+    }
+
+    // uint32 images = 1;
+
+
+    pub fn get_images(&self) -> u32 {
+        self.images
+    }
+    pub fn clear_images(&mut self) {
+        self.images = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_images(&mut self, v: u32) {
+        self.images = v;
+    }
+
+    // uint32 events = 2;
+
+
+    pub fn get_events(&self) -> u32 {
+        self.events
+    }
+    pub fn clear_events(&mut self) {
+        self.events = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_events(&mut self, v: u32) {
+        self.events = v;
+    }
+
+    // uint32 duplicate_events = 3;
+
+
+    pub fn get_duplicate_events(&self) -> u32 {
+        self.duplicate_events
+    }
+    pub fn clear_duplicate_events(&mut self) {
+        self.duplicate_events = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_duplicate_events(&mut self, v: u32) {
+        self.duplicate_events = v;
+    }
+}
+
+impl ::protobuf::Message for ImportResult {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.images = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.events = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.duplicate_events = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.images != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.images, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.events != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.events, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.duplicate_events != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.duplicate_events, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.images != 0 {
+            os.write_uint32(1, self.images)?;
+        }
+        if self.events != 0 {
+            os.write_uint32(2, self.events)?;
+        }
+        if self.duplicate_events != 0 {
+            os.write_uint32(3, self.duplicate_events)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ImportResult {
+        ImportResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "images",
+                    |m: &ImportResult| { &m.images },
+                    |m: &mut ImportResult| { &mut m.images },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "events",
+                    |m: &ImportResult| { &m.events },
+                    |m: &mut ImportResult| { &mut m.events },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "duplicate_events",
+                    |m: &ImportResult| { &m.duplicate_events },
+                    |m: &mut ImportResult| { &mut m.duplicate_events },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ImportResult>(
+                    "ImportResult",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ImportResult {
+        static mut instance: ::protobuf::lazy::Lazy<ImportResult> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ImportResult,
+        };
+        unsafe {
+            instance.get(ImportResult::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ImportResult {
+    fn clear(&mut self) {
+        self.images = 0;
+        self.events = 0;
+        self.duplicate_events = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ImportResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ImportResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReportQuery {
+    // message fields
+    pub since: i64,
+    pub group_by: ReportGrouping,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ReportQuery {
+    fn default() -> &'a ReportQuery {
+        <ReportQuery as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReportQuery {
+    pub fn new() -> ReportQuery {
+        ::std::default::Default::default()
+    }
+
+    // int64 since = 1;
+
+
+    pub fn get_since(&self) -> i64 {
+        self.since
+    }
+    pub fn clear_since(&mut self) {
+        self.since = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_since(&mut self, v: i64) {
+        self.since = v;
+    }
+
+    // .wcd.ReportGrouping group_by = 2;
+
+
+    pub fn get_group_by(&self) -> ReportGrouping {
+        self.group_by
+    }
+    pub fn clear_group_by(&mut self) {
+        self.group_by = ReportGrouping::PLAYLIST;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_group_by(&mut self, v: ReportGrouping) {
+        self.group_by = v;
+    }
+}
+
+impl ::protobuf::Message for ReportQuery {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.since = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.group_by, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.since != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.since, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.group_by != ReportGrouping::PLAYLIST {
+            my_size += ::protobuf::rt::enum_size(2, self.group_by);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.since != 0 {
+            os.write_int64(1, self.since)?;
+        }
+        if self.group_by != ReportGrouping::PLAYLIST {
+            os.write_enum(2, self.group_by.value())?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReportQuery {
+        ReportQuery::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "since",
+                    |m: &ReportQuery| { &m.since },
+                    |m: &mut ReportQuery| { &mut m.since },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ReportGrouping>>(
+                    "group_by",
+                    |m: &ReportQuery| { &m.group_by },
+                    |m: &mut ReportQuery| { &mut m.group_by },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ReportQuery>(
+                    "ReportQuery",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ReportQuery {
+        static mut instance: ::protobuf::lazy::Lazy<ReportQuery> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ReportQuery,
+        };
+        unsafe {
+            instance.get(ReportQuery::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ReportQuery {
+    fn clear(&mut self) {
+        self.since = 0;
+        self.group_by = ReportGrouping::PLAYLIST;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReportQuery {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReportQuery {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Report {
+    // message fields
+    pub groups: ::protobuf::RepeatedField<ReportGroup>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Report {
+    fn default() -> &'a Report {
+        <Report as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Report {
+    pub fn new() -> Report {
+        ::std::default::Default::default()
+    }
+
+    // repeated .wcd.ReportGroup groups = 1;
+
+
+    pub fn get_groups(&self) -> &[ReportGroup] {
+        &self.groups
+    }
+    pub fn clear_groups(&mut self) {
+        self.groups.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_groups(&mut self, v: ::protobuf::RepeatedField<ReportGroup>) {
+        self.groups = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_groups(&mut self) -> &mut ::protobuf::RepeatedField<ReportGroup> {
+        &mut self.groups
+    }
+
+    // Take field
+    pub fn take_groups(&mut self) -> ::protobuf::RepeatedField<ReportGroup> {
+        ::std::mem::replace(&mut self.groups, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Report {
+    fn is_initialized(&self) -> bool {
+        for v in &self.groups {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.groups)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.groups {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.groups {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Report {
+        Report::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ReportGroup>>(
+                    "groups",
+                    |m: &Report| { &m.groups },
+                    |m: &mut Report| { &mut m.groups },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Report>(
+                    "Report",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Report {
+        static mut instance: ::protobuf::lazy::Lazy<Report> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Report,
+        };
+        unsafe {
+            instance.get(Report::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Report {
+    fn clear(&mut self) {
+        self.groups.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Report {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Report {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReportGroup {
+    // message fields
+    pub name: ::std::string::String,
+    pub display_time: i64,
+    pub displays: i64,
+    pub skips: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ReportGroup {
+    fn default() -> &'a ReportGroup {
+        <ReportGroup as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReportGroup {
+    pub fn new() -> ReportGroup {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // int64 display_time = 2;
+
+
+    pub fn get_display_time(&self) -> i64 {
+        self.display_time
+    }
+    pub fn clear_display_time(&mut self) {
+        self.display_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_display_time(&mut self, v: i64) {
+        self.display_time = v;
+    }
+
+    // int64 displays = 3;
+
+
+    pub fn get_displays(&self) -> i64 {
+        self.displays
+    }
+    pub fn clear_displays(&mut self) {
+        self.displays = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_displays(&mut self, v: i64) {
+        self.displays = v;
+    }
+
+    // int64 skips = 4;
+
+
+    pub fn get_skips(&self) -> i64 {
+        self.skips
+    }
+    pub fn clear_skips(&mut self) {
+        self.skips = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_skips(&mut self, v: i64) {
+        self.skips = v;
+    }
+}
+
+impl ::protobuf::Message for ReportGroup {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.display_time = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.displays = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.skips = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.display_time != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.display_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.displays != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.displays, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.skips != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.skips, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.display_time != 0 {
+            os.write_int64(2, self.display_time)?;
+        }
+        if self.displays != 0 {
+            os.write_int64(3, self.displays)?;
+        }
+        if self.skips != 0 {
+            os.write_int64(4, self.skips)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReportGroup {
+        ReportGroup::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    |m: &ReportGroup| { &m.name },
+                    |m: &mut ReportGroup| { &mut m.name },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "display_time",
+                    |m: &ReportGroup| { &m.display_time },
+                    |m: &mut ReportGroup| { &mut m.display_time },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "displays",
+                    |m: &ReportGroup| { &m.displays },
+                    |m: &mut ReportGroup| { &mut m.displays },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "skips",
+                    |m: &ReportGroup| { &m.skips },
+                    |m: &mut ReportGroup| { &mut m.skips },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ReportGroup>(
+                    "ReportGroup",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ReportGroup {
+        static mut instance: ::protobuf::lazy::Lazy<ReportGroup> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ReportGroup,
+        };
+        unsafe {
+            instance.get(ReportGroup::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ReportGroup {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.display_time = 0;
+        self.displays = 0;
+        self.skips = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReportGroup {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReportGroup {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ImageRating {
+    // message fields
+    pub image: ::std::string::String,
+    pub channel: ::std::string::String,
+    pub rating: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ImageRating {
+    fn default() -> &'a ImageRating {
+        <ImageRating as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ImageRating {
+    pub fn new() -> ImageRating {
+        ::std::default::Default::default()
+    }
+
+    // string image = 1;
+
+
+    pub fn get_image(&self) -> &str {
+        &self.image
+    }
+    pub fn clear_image(&mut self) {
+        self.image.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_image(&mut self, v: ::std::string::String) {
+        self.image = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_image(&mut self) -> &mut ::std::string::String {
+        &mut self.image
+    }
+
+    // Take field
+    pub fn take_image(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.image, ::std::string::String::new())
+    }
+
+    // string channel = 2;
+
+
+    pub fn get_channel(&self) -> &str {
+        &self.channel
+    }
+    pub fn clear_channel(&mut self) {
+        self.channel.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_channel(&mut self, v: ::std::string::String) {
+        self.channel = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_channel(&mut self) -> &mut ::std::string::String {
+        &mut self.channel
+    }
+
+    // Take field
+    pub fn take_channel(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.channel, ::std::string::String::new())
+    }
+
+    // uint32 rating = 3;
+
+
+    pub fn get_rating(&self) -> u32 {
+        self.rating
+    }
+    pub fn clear_rating(&mut self) {
+        self.rating = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rating(&mut self, v: u32) {
+        self.rating = v;
+    }
+}
+
+impl ::protobuf::Message for ImageRating {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.image)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.channel)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.rating = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.image.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.image);
+        }
+        if !self.channel.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.channel);
+        }
+        if self.rating != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.rating, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.image.is_empty() {
+            os.write_string(1, &self.image)?;
+        }
+        if !self.channel.is_empty() {
+            os.write_string(2, &self.channel)?;
+        }
+        if self.rating != 0 {
+            os.write_uint32(3, self.rating)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ImageRating {
+        ImageRating::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "image",
+                    |m: &ImageRating| { &m.image },
+                    |m: &mut ImageRating| { &mut m.image },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "channel",
+                    |m: &ImageRating| { &m.channel },
+                    |m: &mut ImageRating| { &mut m.channel },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "rating",
+                    |m: &ImageRating| { &m.rating },
+                    |m: &mut ImageRating| { &mut m.rating },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ImageRating>(
+                    "ImageRating",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ImageRating {
+        static mut instance: ::protobuf::lazy::Lazy<ImageRating> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ImageRating,
+        };
+        unsafe {
+            instance.get(ImageRating::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ImageRating {
+    fn clear(&mut self) {
+        self.image.clear();
+        self.channel.clear();
+        self.rating = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ImageRating {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ImageRating {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ImageFavorite {
+    // message fields
+    pub image: ::std::string::String,
+    pub channel: ::std::string::String,
+    pub favorite: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ImageFavorite {
+    fn default() -> &'a ImageFavorite {
+        <ImageFavorite as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ImageFavorite {
+    pub fn new() -> ImageFavorite {
+        ::std::default::Default::default()
+    }
+
+    // string image = 1;
+
+
+    pub fn get_image(&self) -> &str {
+        &self.image
+    }
+    pub fn clear_image(&mut self) {
+        self.image.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_image(&mut self, v: ::std::string::String) {
+        self.image = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_image(&mut self) -> &mut ::std::string::String {
+        &mut self.image
+    }
+
+    // Take field
+    pub fn take_image(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.image, ::std::string::String::new())
+    }
+
+    // string channel = 2;
+
+
+    pub fn get_channel(&self) -> &str {
+        &self.channel
+    }
+    pub fn clear_channel(&mut self) {
+        self.channel.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_channel(&mut self, v: ::std::string::String) {
+        self.channel = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_channel(&mut self) -> &mut ::std::string::String {
+        &mut self.channel
+    }
+
+    // Take field
+    pub fn take_channel(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.channel, ::std::string::String::new())
+    }
+
+    // bool favorite = 3;
+
+
+    pub fn get_favorite(&self) -> bool {
+        self.favorite
+    }
+    pub fn clear_favorite(&mut self) {
+        self.favorite = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_favorite(&mut self, v: bool) {
+        self.favorite = v;
+    }
+}
+
+impl ::protobuf::Message for ImageFavorite {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.image)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.channel)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.favorite = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.image.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.image);
+        }
+        if !self.channel.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.channel);
+        }
+        if self.favorite != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.image.is_empty() {
+            os.write_string(1, &self.image)?;
+        }
+        if !self.channel.is_empty() {
+            os.write_string(2, &self.channel)?;
+        }
+        if self.favorite != false {
+            os.write_bool(3, self.favorite)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ImageFavorite {
+        ImageFavorite::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "image",
+                    |m: &ImageFavorite| { &m.image },
+                    |m: &mut ImageFavorite| { &mut m.image },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "channel",
+                    |m: &ImageFavorite| { &m.channel },
+                    |m: &mut ImageFavorite| { &mut m.channel },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "favorite",
+                    |m: &ImageFavorite| { &m.favorite },
+                    |m: &mut ImageFavorite| { &mut m.favorite },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ImageFavorite>(
+                    "ImageFavorite",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ImageFavorite {
+        static mut instance: ::protobuf::lazy::Lazy<ImageFavorite> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ImageFavorite,
+        };
+        unsafe {
+            instance.get(ImageFavorite::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ImageFavorite {
+    fn clear(&mut self) {
+        self.image.clear();
+        self.channel.clear();
+        self.favorite = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ImageFavorite {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ImageFavorite {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ImageName {
+    // message fields
+    pub image: ::std::string::String,
+    pub channel: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ImageName {
+    fn default() -> &'a ImageName {
+        <ImageName as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ImageName {
+    pub fn new() -> ImageName {
+        ::std::default::Default::default()
+    }
+
+    // string image = 1;
+
+
+    pub fn get_image(&self) -> &str {
+        &self.image
+    }
+    pub fn clear_image(&mut self) {
+        self.image.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_image(&mut self, v: ::std::string::String) {
+        self.image = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_image(&mut self) -> &mut ::std::string::String {
+        &mut self.image
+    }
+
+    // Take field
+    pub fn take_image(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.image, ::std::string::String::new())
+    }
+
+    // string channel = 2;
+
+
+    pub fn get_channel(&self) -> &str {
+        &self.channel
+    }
+    pub fn clear_channel(&mut self) {
+        self.channel.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_channel(&mut self, v: ::std::string::String) {
+        self.channel = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_channel(&mut self) -> &mut ::std::string::String {
+        &mut self.channel
+    }
+
+    // Take field
+    pub fn take_channel(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.channel, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ImageName {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.image)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.channel)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.image.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.image);
+        }
+        if !self.channel.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.channel);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.image.is_empty() {
+            os.write_string(1, &self.image)?;
+        }
+        if !self.channel.is_empty() {
+            os.write_string(2, &self.channel)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ImageName {
+        ImageName::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "image",
+                    |m: &ImageName| { &m.image },
+                    |m: &mut ImageName| { &mut m.image },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "channel",
+                    |m: &ImageName| { &m.channel },
+                    |m: &mut ImageName| { &mut m.channel },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ImageName>(
+                    "ImageName",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ImageName {
+        static mut instance: ::protobuf::lazy::Lazy<ImageName> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ImageName,
+        };
+        unsafe {
+            instance.get(ImageName::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ImageName {
+    fn clear(&mut self) {
+        self.image.clear();
+        self.channel.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ImageName {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ImageName {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BanList {
+    // message fields
+    pub bans: ::protobuf::RepeatedField<BannedImage>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BanList {
+    fn default() -> &'a BanList {
+        <BanList as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BanList {
+    pub fn new() -> BanList {
+        ::std::default::Default::default()
+    }
+
+    // repeated .wcd.BannedImage bans = 1;
+
+
+    pub fn get_bans(&self) -> &[BannedImage] {
+        &self.bans
+    }
+    pub fn clear_bans(&mut self) {
+        self.bans.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bans(&mut self, v: ::protobuf::RepeatedField<BannedImage>) {
+        self.bans = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_bans(&mut self) -> &mut ::protobuf::RepeatedField<BannedImage> {
+        &mut self.bans
+    }
+
+    // Take field
+    pub fn take_bans(&mut self) -> ::protobuf::RepeatedField<BannedImage> {
+        ::std::mem::replace(&mut self.bans, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BanList {
+    fn is_initialized(&self) -> bool {
+        for v in &self.bans {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.bans)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.bans {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.bans {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BanList {
+        BanList::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BannedImage>>(
+                    "bans",
+                    |m: &BanList| { &m.bans },
+                    |m: &mut BanList| { &mut m.bans },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<BanList>(
+                    "BanList",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static BanList {
+        static mut instance: ::protobuf::lazy::Lazy<BanList> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const BanList,
+        };
+        unsafe {
+            instance.get(BanList::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for BanList {
+    fn clear(&mut self) {
+        self.bans.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BanList {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BanList {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BannedImage {
+    // message fields
+    pub filename: ::std::string::String,
+    pub banned_at: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BannedImage {
+    fn default() -> &'a BannedImage {
+        <BannedImage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BannedImage {
+    pub fn new() -> BannedImage {
+        ::std::default::Default::default()
+    }
+
+    // string filename = 1;
+
+
+    pub fn get_filename(&self) -> &str {
+        &self.filename
+    }
+    pub fn clear_filename(&mut self) {
+        self.filename.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_filename(&mut self, v: ::std::string::String) {
+        self.filename = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_filename(&mut self) -> &mut ::std::string::String {
+        &mut self.filename
+    }
+
+    // Take field
+    pub fn take_filename(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.filename, ::std::string::String::new())
+    }
+
+    // int64 banned_at = 2;
+
+
+    pub fn get_banned_at(&self) -> i64 {
+        self.banned_at
+    }
+    pub fn clear_banned_at(&mut self) {
+        self.banned_at = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_banned_at(&mut self, v: i64) {
+        self.banned_at = v;
+    }
+}
+
+impl ::protobuf::Message for BannedImage {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.filename)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.banned_at = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.filename.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.filename);
+        }
+        if self.banned_at != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.banned_at, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.filename.is_empty() {
+            os.write_string(1, &self.filename)?;
+        }
+        if self.banned_at != 0 {
+            os.write_int64(2, self.banned_at)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BannedImage {
+        BannedImage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "filename",
+                    |m: &BannedImage| { &m.filename },
+                    |m: &mut BannedImage| { &mut m.filename },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "banned_at",
+                    |m: &BannedImage| { &m.banned_at },
+                    |m: &mut BannedImage| { &mut m.banned_at },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<BannedImage>(
+                    "BannedImage",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static BannedImage {
+        static mut instance: ::protobuf::lazy::Lazy<BannedImage> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const BannedImage,
+        };
+        unsafe {
+            instance.get(BannedImage::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for BannedImage {
+    fn clear(&mut self) {
+        self.filename.clear();
+        self.banned_at = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BannedImage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BannedImage {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ListQuery {
+    // message fields
+    pub channel: ::std::string::String,
+    pub playlist: ::std::string::String,
+    pub upcoming: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ListQuery {
+    fn default() -> &'a ListQuery {
+        <ListQuery as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListQuery {
+    pub fn new() -> ListQuery {
+        ::std::default::Default::default()
+    }
+
+    // string channel = 1;
+
+
+    pub fn get_channel(&self) -> &str {
+        &self.channel
+    }
+    pub fn clear_channel(&mut self) {
+        self.channel.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_channel(&mut self, v: ::std::string::String) {
+        self.channel = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_channel(&mut self) -> &mut ::std::string::String {
+        &mut self.channel
+    }
+
+    // Take field
+    pub fn take_channel(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.channel, ::std::string::String::new())
+    }
+
+    // string playlist = 2;
+
+
+    pub fn get_playlist(&self) -> &str {
+        &self.playlist
+    }
+    pub fn clear_playlist(&mut self) {
+        self.playlist.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_playlist(&mut self, v: ::std::string::String) {
+        self.playlist = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_playlist(&mut self) -> &mut ::std::string::String {
+        &mut self.playlist
+    }
+
+    // Take field
+    pub fn take_playlist(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.playlist, ::std::string::String::new())
+    }
+
+    // bool upcoming = 3;
+
+
+    pub fn get_upcoming(&self) -> bool {
+        self.upcoming
+    }
+    pub fn clear_upcoming(&mut self) {
+        self.upcoming = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_upcoming(&mut self, v: bool) {
+        self.upcoming = v;
+    }
+}
+
+impl ::protobuf::Message for ListQuery {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.channel)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.playlist)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.upcoming = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.channel.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.channel);
+        }
+        if !self.playlist.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.playlist);
+        }
+        if self.upcoming != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.channel.is_empty() {
+            os.write_string(1, &self.channel)?;
+        }
+        if !self.playlist.is_empty() {
+            os.write_string(2, &self.playlist)?;
+        }
+        if self.upcoming != false {
+            os.write_bool(3, self.upcoming)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ListQuery {
+        ListQuery::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "channel",
+                    |m: &ListQuery| { &m.channel },
+                    |m: &mut ListQuery| { &mut m.channel },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "playlist",
+                    |m: &ListQuery| { &m.playlist },
+                    |m: &mut ListQuery| { &mut m.playlist },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "upcoming",
+                    |m: &ListQuery| { &m.upcoming },
+                    |m: &mut ListQuery| { &mut m.upcoming },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ListQuery>(
+                    "ListQuery",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ListQuery {
+        static mut instance: ::protobuf::lazy::Lazy<ListQuery> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ListQuery,
+        };
+        unsafe {
+            instance.get(ListQuery::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ListQuery {
+    fn clear(&mut self) {
+        self.channel.clear();
+        self.playlist.clear();
+        self.upcoming = false;
+        self.unknown_fields.clear();
     }
 }
 
-impl ::protobuf::Message for StatsInfo {
+impl ::std::fmt::Debug for ListQuery {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListQuery {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ImageList {
+    // message fields
+    pub playlist: ::std::string::String,
+    pub mode: ChangeMode,
+    pub images: ::protobuf::RepeatedField<ListedImage>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ImageList {
+    fn default() -> &'a ImageList {
+        <ImageList as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ImageList {
+    pub fn new() -> ImageList {
+        ::std::default::Default::default()
+    }
+
+    // string playlist = 1;
+
+
+    pub fn get_playlist(&self) -> &str {
+        &self.playlist
+    }
+    pub fn clear_playlist(&mut self) {
+        self.playlist.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_playlist(&mut self, v: ::std::string::String) {
+        self.playlist = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_playlist(&mut self) -> &mut ::std::string::String {
+        &mut self.playlist
+    }
+
+    // Take field
+    pub fn take_playlist(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.playlist, ::std::string::String::new())
+    }
+
+    // .wcd.ChangeMode mode = 2;
+
+
+    pub fn get_mode(&self) -> ChangeMode {
+        self.mode
+    }
+    pub fn clear_mode(&mut self) {
+        self.mode = ChangeMode::SEQUENTIAL;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mode(&mut self, v: ChangeMode) {
+        self.mode = v;
+    }
+
+    // repeated .wcd.ListedImage images = 3;
+
+
+    pub fn get_images(&self) -> &[ListedImage] {
+        &self.images
+    }
+    pub fn clear_images(&mut self) {
+        self.images.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_images(&mut self, v: ::protobuf::RepeatedField<ListedImage>) {
+        self.images = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_images(&mut self) -> &mut ::protobuf::RepeatedField<ListedImage> {
+        &mut self.images
+    }
+
+    // Take field
+    pub fn take_images(&mut self) -> ::protobuf::RepeatedField<ListedImage> {
+        ::std::mem::replace(&mut self.images, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ImageList {
     fn is_initialized(&self) -> bool {
-        for v in &self.image_stats {
+        for v in &self.images {
             if !v.is_initialized() {
                 return false;
             }
@@ -1045,7 +7846,13 @@ impl ::protobuf::Message for StatsInfo {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.image_stats)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.playlist)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.mode, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.images)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1059,7 +7866,13 @@ impl ::protobuf::Message for StatsInfo {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in &self.image_stats {
+        if !self.playlist.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.playlist);
+        }
+        if self.mode != ChangeMode::SEQUENTIAL {
+            my_size += ::protobuf::rt::enum_size(2, self.mode);
+        }
+        for value in &self.images {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
@@ -1069,8 +7882,14 @@ impl ::protobuf::Message for StatsInfo {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        for v in &self.image_stats {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+        if !self.playlist.is_empty() {
+            os.write_string(1, &self.playlist)?;
+        }
+        if self.mode != ChangeMode::SEQUENTIAL {
+            os.write_enum(2, self.mode.value())?;
+        }
+        for v in &self.images {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         };
@@ -1104,8 +7923,254 @@ impl ::protobuf::Message for StatsInfo {
         Self::descriptor_static()
     }
 
-    fn new() -> StatsInfo {
-        StatsInfo::new()
+    fn new() -> ImageList {
+        ImageList::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "playlist",
+                    |m: &ImageList| { &m.playlist },
+                    |m: &mut ImageList| { &mut m.playlist },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ChangeMode>>(
+                    "mode",
+                    |m: &ImageList| { &m.mode },
+                    |m: &mut ImageList| { &mut m.mode },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ListedImage>>(
+                    "images",
+                    |m: &ImageList| { &m.images },
+                    |m: &mut ImageList| { &mut m.images },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ImageList>(
+                    "ImageList",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ImageList {
+        static mut instance: ::protobuf::lazy::Lazy<ImageList> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ImageList,
+        };
+        unsafe {
+            instance.get(ImageList::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ImageList {
+    fn clear(&mut self) {
+        self.playlist.clear();
+        self.mode = ChangeMode::SEQUENTIAL;
+        self.images.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ImageList {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ImageList {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ListedImage {
+    // message fields
+    pub path: ::std::string::String,
+    pub weight: f64,
+    pub override_name: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ListedImage {
+    fn default() -> &'a ListedImage {
+        <ListedImage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListedImage {
+    pub fn new() -> ListedImage {
+        ::std::default::Default::default()
+    }
+
+    // string path = 1;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    // double weight = 2;
+
+
+    pub fn get_weight(&self) -> f64 {
+        self.weight
+    }
+    pub fn clear_weight(&mut self) {
+        self.weight = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_weight(&mut self, v: f64) {
+        self.weight = v;
+    }
+
+    // string override_name = 3;
+
+
+    pub fn get_override_name(&self) -> &str {
+        &self.override_name
+    }
+    pub fn clear_override_name(&mut self) {
+        self.override_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_override_name(&mut self, v: ::std::string::String) {
+        self.override_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_override_name(&mut self) -> &mut ::std::string::String {
+        &mut self.override_name
+    }
+
+    // Take field
+    pub fn take_override_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.override_name, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ListedImage {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.weight = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.override_name)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
+        }
+        if self.weight != 0. {
+            my_size += 9;
+        }
+        if !self.override_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.override_name);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
+        }
+        if self.weight != 0. {
+            os.write_double(2, self.weight)?;
+        }
+        if !self.override_name.is_empty() {
+            os.write_string(3, &self.override_name)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ListedImage {
+        ListedImage::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1116,13 +8181,23 @@ impl ::protobuf::Message for StatsInfo {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ImageStatsInfo>>(
-                    "image_stats",
-                    |m: &StatsInfo| { &m.image_stats },
-                    |m: &mut StatsInfo| { &mut m.image_stats },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "path",
+                    |m: &ListedImage| { &m.path },
+                    |m: &mut ListedImage| { &mut m.path },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<StatsInfo>(
-                    "StatsInfo",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                    "weight",
+                    |m: &ListedImage| { &m.weight },
+                    |m: &mut ListedImage| { &mut m.weight },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "override_name",
+                    |m: &ListedImage| { &m.override_name },
+                    |m: &mut ListedImage| { &mut m.override_name },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ListedImage>(
+                    "ListedImage",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1130,132 +8205,160 @@ impl ::protobuf::Message for StatsInfo {
         }
     }
 
-    fn default_instance() -> &'static StatsInfo {
-        static mut instance: ::protobuf::lazy::Lazy<StatsInfo> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static ListedImage {
+        static mut instance: ::protobuf::lazy::Lazy<ListedImage> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const StatsInfo,
+            ptr: 0 as *const ListedImage,
         };
         unsafe {
-            instance.get(StatsInfo::new)
+            instance.get(ListedImage::new)
         }
     }
 }
 
-impl ::protobuf::Clear for StatsInfo {
+impl ::protobuf::Clear for ListedImage {
     fn clear(&mut self) {
-        self.image_stats.clear();
+        self.path.clear();
+        self.weight = 0.;
+        self.override_name.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for StatsInfo {
+impl ::std::fmt::Debug for ListedImage {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for StatsInfo {
+impl ::protobuf::reflect::ProtobufValue for ListedImage {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct ImageStatsInfo {
+pub struct VersionInfo {
     // message fields
-    pub filename: ::std::string::String,
-    pub total_displays: i64,
-    pub total_skips: i64,
-    pub total_display_time: i64,
+    pub version: ::std::string::String,
+    pub protocol_version: u32,
+    pub stats_enabled: bool,
+    pub watch_interval: i64,
+    pub rpcs: ::protobuf::RepeatedField<::std::string::String>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a ImageStatsInfo {
-    fn default() -> &'a ImageStatsInfo {
-        <ImageStatsInfo as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a VersionInfo {
+    fn default() -> &'a VersionInfo {
+        <VersionInfo as ::protobuf::Message>::default_instance()
     }
 }
 
-impl ImageStatsInfo {
-    pub fn new() -> ImageStatsInfo {
+impl VersionInfo {
+    pub fn new() -> VersionInfo {
         ::std::default::Default::default()
     }
 
-    // string filename = 1;
+    // string version = 1;
 
 
-    pub fn get_filename(&self) -> &str {
-        &self.filename
+    pub fn get_version(&self) -> &str {
+        &self.version
     }
-    pub fn clear_filename(&mut self) {
-        self.filename.clear();
+    pub fn clear_version(&mut self) {
+        self.version.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_filename(&mut self, v: ::std::string::String) {
-        self.filename = v;
+    pub fn set_version(&mut self, v: ::std::string::String) {
+        self.version = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_filename(&mut self) -> &mut ::std::string::String {
-        &mut self.filename
+    pub fn mut_version(&mut self) -> &mut ::std::string::String {
+        &mut self.version
     }
 
     // Take field
-    pub fn take_filename(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.filename, ::std::string::String::new())
+    pub fn take_version(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.version, ::std::string::String::new())
     }
 
-    // int64 total_displays = 2;
+    // uint32 protocol_version = 2;
 
 
-    pub fn get_total_displays(&self) -> i64 {
-        self.total_displays
+    pub fn get_protocol_version(&self) -> u32 {
+        self.protocol_version
     }
-    pub fn clear_total_displays(&mut self) {
-        self.total_displays = 0;
+    pub fn clear_protocol_version(&mut self) {
+        self.protocol_version = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_total_displays(&mut self, v: i64) {
-        self.total_displays = v;
+    pub fn set_protocol_version(&mut self, v: u32) {
+        self.protocol_version = v;
     }
 
-    // int64 total_skips = 3;
+    // bool stats_enabled = 3;
 
 
-    pub fn get_total_skips(&self) -> i64 {
-        self.total_skips
+    pub fn get_stats_enabled(&self) -> bool {
+        self.stats_enabled
     }
-    pub fn clear_total_skips(&mut self) {
-        self.total_skips = 0;
+    pub fn clear_stats_enabled(&mut self) {
+        self.stats_enabled = false;
     }
 
     // Param is passed by value, moved
-    pub fn set_total_skips(&mut self, v: i64) {
-        self.total_skips = v;
+    pub fn set_stats_enabled(&mut self, v: bool) {
+        self.stats_enabled = v;
     }
 
-    // int64 total_display_time = 4;
+    // int64 watch_interval = 4;
 
 
-    pub fn get_total_display_time(&self) -> i64 {
-        self.total_display_time
+    pub fn get_watch_interval(&self) -> i64 {
+        self.watch_interval
     }
-    pub fn clear_total_display_time(&mut self) {
-        self.total_display_time = 0;
+    pub fn clear_watch_interval(&mut self) {
+        self.watch_interval = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_total_display_time(&mut self, v: i64) {
-        self.total_display_time = v;
+    pub fn set_watch_interval(&mut self, v: i64) {
+        self.watch_interval = v;
+    }
+
+    // repeated string rpcs = 5;
+
+
+    pub fn get_rpcs(&self) -> &[::std::string::String] {
+        &self.rpcs
+    }
+    pub fn clear_rpcs(&mut self) {
+        self.rpcs.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rpcs(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.rpcs = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_rpcs(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.rpcs
+    }
+
+    // Take field
+    pub fn take_rpcs(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.rpcs, ::protobuf::RepeatedField::new())
     }
 }
 
-impl ::protobuf::Message for ImageStatsInfo {
+impl ::protobuf::Message for VersionInfo {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1265,28 +8368,31 @@ impl ::protobuf::Message for ImageStatsInfo {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.filename)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.version)?;
                 },
                 2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_int64()?;
-                    self.total_displays = tmp;
+                    let tmp = is.read_uint32()?;
+                    self.protocol_version = tmp;
                 },
                 3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_int64()?;
-                    self.total_skips = tmp;
+                    let tmp = is.read_bool()?;
+                    self.stats_enabled = tmp;
                 },
                 4 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_int64()?;
-                    self.total_display_time = tmp;
+                    self.watch_interval = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.rpcs)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1300,36 +8406,42 @@ impl ::protobuf::Message for ImageStatsInfo {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.filename.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.filename);
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.version);
         }
-        if self.total_displays != 0 {
-            my_size += ::protobuf::rt::value_size(2, self.total_displays, ::protobuf::wire_format::WireTypeVarint);
+        if self.protocol_version != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.protocol_version, ::protobuf::wire_format::WireTypeVarint);
         }
-        if self.total_skips != 0 {
-            my_size += ::protobuf::rt::value_size(3, self.total_skips, ::protobuf::wire_format::WireTypeVarint);
+        if self.stats_enabled != false {
+            my_size += 2;
         }
-        if self.total_display_time != 0 {
-            my_size += ::protobuf::rt::value_size(4, self.total_display_time, ::protobuf::wire_format::WireTypeVarint);
+        if self.watch_interval != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.watch_interval, ::protobuf::wire_format::WireTypeVarint);
         }
+        for value in &self.rpcs {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.filename.is_empty() {
-            os.write_string(1, &self.filename)?;
+        if !self.version.is_empty() {
+            os.write_string(1, &self.version)?;
         }
-        if self.total_displays != 0 {
-            os.write_int64(2, self.total_displays)?;
+        if self.protocol_version != 0 {
+            os.write_uint32(2, self.protocol_version)?;
         }
-        if self.total_skips != 0 {
-            os.write_int64(3, self.total_skips)?;
+        if self.stats_enabled != false {
+            os.write_bool(3, self.stats_enabled)?;
         }
-        if self.total_display_time != 0 {
-            os.write_int64(4, self.total_display_time)?;
+        if self.watch_interval != 0 {
+            os.write_int64(4, self.watch_interval)?;
         }
+        for v in &self.rpcs {
+            os.write_string(5, &v)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1360,8 +8472,8 @@ impl ::protobuf::Message for ImageStatsInfo {
         Self::descriptor_static()
     }
 
-    fn new() -> ImageStatsInfo {
-        ImageStatsInfo::new()
+    fn new() -> VersionInfo {
+        VersionInfo::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1373,27 +8485,32 @@ impl ::protobuf::Message for ImageStatsInfo {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "filename",
-                    |m: &ImageStatsInfo| { &m.filename },
-                    |m: &mut ImageStatsInfo| { &mut m.filename },
+                    "version",
+                    |m: &VersionInfo| { &m.version },
+                    |m: &mut VersionInfo| { &mut m.version },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                    "total_displays",
-                    |m: &ImageStatsInfo| { &m.total_displays },
-                    |m: &mut ImageStatsInfo| { &mut m.total_displays },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "protocol_version",
+                    |m: &VersionInfo| { &m.protocol_version },
+                    |m: &mut VersionInfo| { &mut m.protocol_version },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                    "total_skips",
-                    |m: &ImageStatsInfo| { &m.total_skips },
-                    |m: &mut ImageStatsInfo| { &mut m.total_skips },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "stats_enabled",
+                    |m: &VersionInfo| { &m.stats_enabled },
+                    |m: &mut VersionInfo| { &mut m.stats_enabled },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                    "total_display_time",
-                    |m: &ImageStatsInfo| { &m.total_display_time },
-                    |m: &mut ImageStatsInfo| { &mut m.total_display_time },
+                    "watch_interval",
+                    |m: &VersionInfo| { &m.watch_interval },
+                    |m: &mut VersionInfo| { &mut m.watch_interval },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<ImageStatsInfo>(
-                    "ImageStatsInfo",
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "rpcs",
+                    |m: &VersionInfo| { &m.rpcs },
+                    |m: &mut VersionInfo| { &mut m.rpcs },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<VersionInfo>(
+                    "VersionInfo",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1401,34 +8518,35 @@ impl ::protobuf::Message for ImageStatsInfo {
         }
     }
 
-    fn default_instance() -> &'static ImageStatsInfo {
-        static mut instance: ::protobuf::lazy::Lazy<ImageStatsInfo> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static VersionInfo {
+        static mut instance: ::protobuf::lazy::Lazy<VersionInfo> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ImageStatsInfo,
+            ptr: 0 as *const VersionInfo,
         };
         unsafe {
-            instance.get(ImageStatsInfo::new)
+            instance.get(VersionInfo::new)
         }
     }
 }
 
-impl ::protobuf::Clear for ImageStatsInfo {
+impl ::protobuf::Clear for VersionInfo {
     fn clear(&mut self) {
-        self.filename.clear();
-        self.total_displays = 0;
-        self.total_skips = 0;
-        self.total_display_time = 0;
+        self.version.clear();
+        self.protocol_version = 0;
+        self.stats_enabled = false;
+        self.watch_interval = 0;
+        self.rpcs.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for ImageStatsInfo {
+impl ::std::fmt::Debug for VersionInfo {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ImageStatsInfo {
+impl ::protobuf::reflect::ProtobufValue for VersionInfo {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -1438,6 +8556,7 @@ impl ::protobuf::reflect::ProtobufValue for ImageStatsInfo {
 pub enum ChangeMode {
     SEQUENTIAL = 0,
     RANDOM = 1,
+    WEIGHTED = 2,
 }
 
 impl ::protobuf::ProtobufEnum for ChangeMode {
@@ -1449,6 +8568,7 @@ impl ::protobuf::ProtobufEnum for ChangeMode {
         match value {
             0 => ::std::option::Option::Some(ChangeMode::SEQUENTIAL),
             1 => ::std::option::Option::Some(ChangeMode::RANDOM),
+            2 => ::std::option::Option::Some(ChangeMode::WEIGHTED),
             _ => ::std::option::Option::None
         }
     }
@@ -1457,6 +8577,7 @@ impl ::protobuf::ProtobufEnum for ChangeMode {
         static values: &'static [ChangeMode] = &[
             ChangeMode::SEQUENTIAL,
             ChangeMode::RANDOM,
+            ChangeMode::WEIGHTED,
         ];
         values
     }
@@ -1489,34 +8610,314 @@ impl ::protobuf::reflect::ProtobufValue for ChangeMode {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum DisplayReason {
+    SCHEDULED = 0,
+    MANUAL = 1,
+    SELECT = 2,
+    RESTORE = 3,
+}
+
+impl ::protobuf::ProtobufEnum for DisplayReason {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<DisplayReason> {
+        match value {
+            0 => ::std::option::Option::Some(DisplayReason::SCHEDULED),
+            1 => ::std::option::Option::Some(DisplayReason::MANUAL),
+            2 => ::std::option::Option::Some(DisplayReason::SELECT),
+            3 => ::std::option::Option::Some(DisplayReason::RESTORE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [DisplayReason] = &[
+            DisplayReason::SCHEDULED,
+            DisplayReason::MANUAL,
+            DisplayReason::SELECT,
+            DisplayReason::RESTORE,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("DisplayReason", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for DisplayReason {
+}
+
+impl ::std::default::Default for DisplayReason {
+    fn default() -> Self {
+        DisplayReason::SCHEDULED
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DisplayReason {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum StatsSort {
+    FILENAME = 0,
+    DISPLAYS = 1,
+    SKIPS = 2,
+    DISPLAY_TIME = 3,
+}
+
+impl ::protobuf::ProtobufEnum for StatsSort {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<StatsSort> {
+        match value {
+            0 => ::std::option::Option::Some(StatsSort::FILENAME),
+            1 => ::std::option::Option::Some(StatsSort::DISPLAYS),
+            2 => ::std::option::Option::Some(StatsSort::SKIPS),
+            3 => ::std::option::Option::Some(StatsSort::DISPLAY_TIME),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [StatsSort] = &[
+            StatsSort::FILENAME,
+            StatsSort::DISPLAYS,
+            StatsSort::SKIPS,
+            StatsSort::DISPLAY_TIME,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("StatsSort", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for StatsSort {
+}
+
+impl ::std::default::Default for StatsSort {
+    fn default() -> Self {
+        StatsSort::FILENAME
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StatsSort {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum ReportGrouping {
+    PLAYLIST = 0,
+    DAY = 1,
+    DIRECTORY = 2,
+}
+
+impl ::protobuf::ProtobufEnum for ReportGrouping {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ReportGrouping> {
+        match value {
+            0 => ::std::option::Option::Some(ReportGrouping::PLAYLIST),
+            1 => ::std::option::Option::Some(ReportGrouping::DAY),
+            2 => ::std::option::Option::Some(ReportGrouping::DIRECTORY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [ReportGrouping] = &[
+            ReportGrouping::PLAYLIST,
+            ReportGrouping::DAY,
+            ReportGrouping::DIRECTORY,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("ReportGrouping", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for ReportGrouping {
+}
+
+impl ::std::default::Default for ReportGrouping {
+    fn default() -> Self {
+        ReportGrouping::PLAYLIST
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReportGrouping {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0fproto/wcd.proto\x12\x03wcd\"\x07\n\x05Empty\"\"\n\x0cPlaylistName\
-    \x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\"\xe7\x01\n\nStatusInfo\
-    \x12<\n\tplaylists\x18\x01\x20\x03(\x0b2\x1e.wcd.StatusInfo.PlaylistsEnt\
-    ryR\tplaylists\x12)\n\x10current_playlist\x18\x02\x20\x01(\tR\x0fcurrent\
-    Playlist\x12\x1f\n\x0blast_update\x18\x03\x20\x01(\x03R\nlastUpdate\x1aO\
-    \n\x0ePlaylistsEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12'\n\
+    \n\x0fproto/wcd.proto\x12\x03wcd\"\x07\n\x05Empty\"'\n\x0bChannelName\
+    \x12\x18\n\x07channel\x18\x01\x20\x01(\tR\x07channel\"<\n\x0cPlaylistNam\
+    e\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x18\n\x07channel\x18\
+    \x02\x20\x01(\tR\x07channel\"\xb5\x02\n\nStatusInfo\x12<\n\tplaylists\
+    \x18\x01\x20\x03(\x0b2\x1e.wcd.StatusInfo.PlaylistsEntryR\tplaylists\x12\
+    )\n\x10current_playlist\x18\x02\x20\x01(\tR\x0fcurrentPlaylist\x12\x1f\n\
+    \x0blast_update\x18\x03\x20\x01(\x03R\nlastUpdate\x12\x18\n\x07channel\
+    \x18\x04\x20\x01(\tR\x07channel\x12\x1a\n\x08channels\x18\x05\x20\x03(\t\
+    R\x08channels\x12\x16\n\x06paused\x18\x06\x20\x01(\x08R\x06paused\x1aO\n\
+    \x0ePlaylistsEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12'\n\
     \x05value\x18\x02\x20\x01(\x0b2\x11.wcd.PlaylistInfoR\x05value:\x028\x01\
-    \"\xab\x02\n\x0cPlaylistInfo\x12\x20\n\x0bdirectories\x18\x01\x20\x03(\t\
+    \"\xe4\x02\n\x0cPlaylistInfo\x12\x20\n\x0bdirectories\x18\x01\x20\x03(\t\
     R\x0bdirectories\x12\x14\n\x05files\x18\x02\x20\x03(\tR\x05files\x12\x1f\
     \n\x0btotal_files\x18\x03\x20\x01(\x04R\ntotalFiles\x12#\n\x04mode\x18\
     \x04\x20\x01(\x0e2\x0f.wcd.ChangeModeR\x04mode\x12#\n\rcurrent_image\x18\
     \x05\x20\x01(\tR\x0ccurrentImage\x12*\n\x11trigger_on_select\x18\x06\x20\
     \x01(\x08R\x0ftriggerOnSelect\x12+\n\x12use_last_on_select\x18\x07\x20\
     \x01(\x08R\x0fuseLastOnSelect\x12\x1f\n\x0bnext_update\x18\x08\x20\x01(\
-    \x03R\nnextUpdate\"A\n\tStatsInfo\x124\n\x0bimage_stats\x18\x01\x20\x03(\
-    \x0b2\x13.wcd.ImageStatsInfoR\nimageStats\"\xa2\x01\n\x0eImageStatsInfo\
-    \x12\x1a\n\x08filename\x18\x01\x20\x01(\tR\x08filename\x12%\n\x0etotal_d\
-    isplays\x18\x02\x20\x01(\x03R\rtotalDisplays\x12\x1f\n\x0btotal_skips\
-    \x18\x03\x20\x01(\x03R\ntotalSkips\x12,\n\x12total_display_time\x18\x04\
-    \x20\x01(\x03R\x10totalDisplayTime*(\n\nChangeMode\x12\x0e\n\nSEQUENTIAL\
-    \x10\0\x12\n\n\x06RANDOM\x10\x012\xbe\x02\n\x03Wcd\x12)\n\rTriggerChange\
-    \x12\n.wcd.Empty\x1a\n.wcd.Empty\"\0\x12)\n\rTriggerUpdate\x12\n.wcd.Emp\
-    ty\x1a\n.wcd.Empty\"\0\x12,\n\x10RefreshPlaylists\x12\n.wcd.Empty\x1a\n.\
-    wcd.Empty\"\0\x12%\n\tTerminate\x12\n.wcd.Empty\x1a\n.wcd.Empty\"\0\x12*\
-    \n\tGetStatus\x12\n.wcd.Empty\x1a\x0f.wcd.StatusInfo\"\0\x121\n\x0eChang\
-    ePlaylist\x12\x11.wcd.PlaylistName\x1a\n.wcd.Empty\"\0\x12-\n\rGetStatis\
-    tics\x12\n.wcd.Empty\x1a\x0e.wcd.StatsInfo\"\0b\x06proto3\
+    \x03R\nnextUpdate\x127\n\x0ecurrent_images\x18\t\x20\x03(\x0b2\x10.wcd.O\
+    utputImageR\rcurrentImages\"`\n\x0bOutputImage\x12\x16\n\x06output\x18\
+    \x01\x20\x01(\tR\x06output\x12\x14\n\x05image\x18\x02\x20\x01(\tR\x05ima\
+    ge\x12#\n\roverride_name\x18\x03\x20\x01(\tR\x0coverrideName\"\xce\x03\n\
+    \x05Event\x12\x1c\n\ttimestamp\x18\x01\x20\x01(\x03R\ttimestamp\x12\x18\
+    \n\x07channel\x18\x02\x20\x01(\tR\x07channel\x128\n\rimage_changed\x18\
+    \x03\x20\x01(\x0b2\x11.wcd.ImageChangedH\0R\x0cimageChanged\x12A\n\x10pl\
+    aylist_changed\x18\x04\x20\x01(\x0b2\x14.wcd.PlaylistChangedH\0R\x0fplay\
+    listChanged\x12J\n\x13playlists_refreshed\x18\x05\x20\x01(\x0b2\x17.wcd.\
+    PlaylistsRefreshedH\0R\x12playlistsRefreshed\x12$\n\x06paused\x18\x06\
+    \x20\x01(\x0b2\n.wcd.EmptyH\0R\x06paused\x12&\n\x07resumed\x18\x07\x20\
+    \x01(\x0b2\n.wcd.EmptyH\0R\x07resumed\x12;\n\x0ecommand_failed\x18\x08\
+    \x20\x01(\x0b2\x12.wcd.CommandFailedH\0R\rcommandFailed\x121\n\rshutting\
+    _down\x18\t\x20\x01(\x0b2\n.wcd.EmptyH\0R\x0cshuttingDownB\x06\n\x04kind\
+    \"\x94\x01\n\x0cImageChanged\x12\x1a\n\x08playlist\x18\x01\x20\x01(\tR\
+    \x08playlist\x12\x1d\n\nold_images\x18\x02\x20\x03(\tR\toldImages\x12\
+    \x1d\n\nnew_images\x18\x03\x20\x03(\tR\tnewImages\x12*\n\x06reason\x18\
+    \x04\x20\x01(\x0e2\x12.wcd.DisplayReasonR\x06reason\"W\n\x0fPlaylistChan\
+    ged\x12!\n\x0cold_playlist\x18\x01\x20\x01(\tR\x0boldPlaylist\x12!\n\x0c\
+    new_playlist\x18\x02\x20\x01(\tR\x0bnewPlaylist\"D\n\x12PlaylistsRefresh\
+    ed\x12\x14\n\x05added\x18\x01\x20\x01(\x04R\x05added\x12\x18\n\x07remove\
+    d\x18\x02\x20\x01(\x04R\x07removed\"C\n\rCommandFailed\x12\x1a\n\x08play\
+    list\x18\x01\x20\x01(\tR\x08playlist\x12\x16\n\x06images\x18\x02\x20\x03\
+    (\tR\x06images\"\x81\x01\n\nStatsQuery\x12\x1a\n\x08playlist\x18\x01\x20\
+    \x01(\tR\x08playlist\x12\"\n\x04sort\x18\x02\x20\x01(\x0e2\x0e.wcd.Stats\
+    SortR\x04sort\x12\x10\n\x03top\x18\x03\x20\x01(\rR\x03top\x12!\n\x0cmiss\
+    ing_only\x18\x04\x20\x01(\x08R\x0bmissingOnly\"h\n\tStatsInfo\x124\n\x0b\
+    image_stats\x18\x01\x20\x03(\x0b2\x13.wcd.ImageStatsInfoR\nimageStats\
+    \x12%\n\x0edropped_writes\x18\x02\x20\x01(\x04R\rdroppedWrites\"\xd6\x01\
+    \n\x0eImageStatsInfo\x12\x1a\n\x08filename\x18\x01\x20\x01(\tR\x08filena\
+    me\x12%\n\x0etotal_displays\x18\x02\x20\x01(\x03R\rtotalDisplays\x12\x1f\
+    \n\x0btotal_skips\x18\x03\x20\x01(\x03R\ntotalSkips\x12,\n\x12total_disp\
+    lay_time\x18\x04\x20\x01(\x03R\x10totalDisplayTime\x12\x16\n\x06rating\
+    \x18\x05\x20\x01(\rR\x06rating\x12\x1a\n\x08favorite\x18\x06\x20\x01(\
+    \x08R\x08favorite\"B\n\x0cRelinkResult\x12\x16\n\x06merged\x18\x01\x20\
+    \x01(\rR\x06merged\x12\x1a\n\x08relinked\x18\x02\x20\x01(\rR\x08relinked\
+    \"\"\n\x07GcQuery\x12\x17\n\x07dry_run\x18\x01\x20\x01(\x08R\x06dryRun\"\
+    s\n\x08GcResult\x12\x16\n\x06images\x18\x01\x20\x03(\tR\x06images\x12\
+    \x14\n\x05paths\x18\x02\x20\x01(\rR\x05paths\x12\x20\n\x0bpreferences\
+    \x18\x03\x20\x01(\rR\x0bpreferences\x12\x17\n\x07dry_run\x18\x04\x20\x01\
+    (\x08R\x06dryRun\"L\n\x0cRenamePrefix\x12\x1d\n\nold_prefix\x18\x01\x20\
+    \x01(\tR\toldPrefix\x12\x1d\n\nnew_prefix\x18\x02\x20\x01(\tR\tnewPrefix\
+    \"^\n\x0cRenameResult\x12\x14\n\x05paths\x18\x01\x20\x01(\rR\x05paths\
+    \x12\x20\n\x0bpreferences\x18\x02\x20\x01(\rR\x0bpreferences\x12\x16\n\
+    \x06merged\x18\x03\x20\x01(\rR\x06merged\"!\n\x0bStatsExport\x12\x12\n\
+    \x04data\x18\x01\x20\x01(\tR\x04data\"7\n\x0bStatsImport\x12\x12\n\x04da\
+    ta\x18\x01\x20\x01(\tR\x04data\x12\x14\n\x05merge\x18\x02\x20\x01(\x08R\
+    \x05merge\"i\n\x0cImportResult\x12\x16\n\x06images\x18\x01\x20\x01(\rR\
+    \x06images\x12\x16\n\x06events\x18\x02\x20\x01(\rR\x06events\x12)\n\x10d\
+    uplicate_events\x18\x03\x20\x01(\rR\x0fduplicateEvents\"S\n\x0bReportQue\
+    ry\x12\x14\n\x05since\x18\x01\x20\x01(\x03R\x05since\x12.\n\x08group_by\
+    \x18\x02\x20\x01(\x0e2\x13.wcd.ReportGroupingR\x07groupBy\"2\n\x06Report\
+    \x12(\n\x06groups\x18\x01\x20\x03(\x0b2\x10.wcd.ReportGroupR\x06groups\"\
+    v\n\x0bReportGroup\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12!\n\
+    \x0cdisplay_time\x18\x02\x20\x01(\x03R\x0bdisplayTime\x12\x1a\n\x08displ\
+    ays\x18\x03\x20\x01(\x03R\x08displays\x12\x14\n\x05skips\x18\x04\x20\x01\
+    (\x03R\x05skips\"U\n\x0bImageRating\x12\x14\n\x05image\x18\x01\x20\x01(\
+    \tR\x05image\x12\x18\n\x07channel\x18\x02\x20\x01(\tR\x07channel\x12\x16\
+    \n\x06rating\x18\x03\x20\x01(\rR\x06rating\"[\n\rImageFavorite\x12\x14\n\
+    \x05image\x18\x01\x20\x01(\tR\x05image\x12\x18\n\x07channel\x18\x02\x20\
+    \x01(\tR\x07channel\x12\x1a\n\x08favorite\x18\x03\x20\x01(\x08R\x08favor\
+    ite\";\n\tImageName\x12\x14\n\x05image\x18\x01\x20\x01(\tR\x05image\x12\
+    \x18\n\x07channel\x18\x02\x20\x01(\tR\x07channel\"/\n\x07BanList\x12$\n\
+    \x04bans\x18\x01\x20\x03(\x0b2\x10.wcd.BannedImageR\x04bans\"F\n\x0bBann\
+    edImage\x12\x1a\n\x08filename\x18\x01\x20\x01(\tR\x08filename\x12\x1b\n\
+    \tbanned_at\x18\x02\x20\x01(\x03R\x08bannedAt\"]\n\tListQuery\x12\x18\n\
+    \x07channel\x18\x01\x20\x01(\tR\x07channel\x12\x1a\n\x08playlist\x18\x02\
+    \x20\x01(\tR\x08playlist\x12\x1a\n\x08upcoming\x18\x03\x20\x01(\x08R\x08\
+    upcoming\"v\n\tImageList\x12\x1a\n\x08playlist\x18\x01\x20\x01(\tR\x08pl\
+    aylist\x12#\n\x04mode\x18\x02\x20\x01(\x0e2\x0f.wcd.ChangeModeR\x04mode\
+    \x12(\n\x06images\x18\x03\x20\x03(\x0b2\x10.wcd.ListedImageR\x06images\"\
+    ^\n\x0bListedImage\x12\x12\n\x04path\x18\x01\x20\x01(\tR\x04path\x12\x16\
+    \n\x06weight\x18\x02\x20\x01(\x01R\x06weight\x12#\n\roverride_name\x18\
+    \x03\x20\x01(\tR\x0coverrideName\"\xb2\x01\n\x0bVersionInfo\x12\x18\n\
+    \x07version\x18\x01\x20\x01(\tR\x07version\x12)\n\x10protocol_version\
+    \x18\x02\x20\x01(\rR\x0fprotocolVersion\x12#\n\rstats_enabled\x18\x03\
+    \x20\x01(\x08R\x0cstatsEnabled\x12%\n\x0ewatch_interval\x18\x04\x20\x01(\
+    \x03R\rwatchInterval\x12\x12\n\x04rpcs\x18\x05\x20\x03(\tR\x04rpcs*6\n\n\
+    ChangeMode\x12\x0e\n\nSEQUENTIAL\x10\0\x12\n\n\x06RANDOM\x10\x01\x12\x0c\
+    \n\x08WEIGHTED\x10\x02*C\n\rDisplayReason\x12\r\n\tSCHEDULED\x10\0\x12\n\
+    \n\x06MANUAL\x10\x01\x12\n\n\x06SELECT\x10\x02\x12\x0b\n\x07RESTORE\x10\
+    \x03*D\n\tStatsSort\x12\x0c\n\x08FILENAME\x10\0\x12\x0c\n\x08DISPLAYS\
+    \x10\x01\x12\t\n\x05SKIPS\x10\x02\x12\x10\n\x0cDISPLAY_TIME\x10\x03*6\n\
+    \x0eReportGrouping\x12\x0c\n\x08PLAYLIST\x10\0\x12\x07\n\x03DAY\x10\x01\
+    \x12\r\n\tDIRECTORY\x10\x022\xe4\t\n\x03Wcd\x12/\n\rTriggerChange\x12\
+    \x10.wcd.ChannelName\x1a\n.wcd.Empty\"\0\x12/\n\rTriggerUpdate\x12\x10.w\
+    cd.ChannelName\x1a\n.wcd.Empty\"\0\x12,\n\x10RefreshPlaylists\x12\n.wcd.\
+    Empty\x1a\n.wcd.Empty\"\0\x12%\n\tTerminate\x12\n.wcd.Empty\x1a\n.wcd.Em\
+    pty\"\0\x120\n\tGetStatus\x12\x10.wcd.ChannelName\x1a\x0f.wcd.StatusInfo\
+    \"\0\x121\n\x0eChangePlaylist\x12\x11.wcd.PlaylistName\x1a\n.wcd.Empty\"\
+    \0\x12)\n\tShowImage\x12\x0e.wcd.ImageName\x1a\n.wcd.Empty\"\0\x12.\n\
+    \x0cShowPrevious\x12\x10.wcd.ChannelName\x1a\n.wcd.Empty\"\0\x12'\n\x05P\
+    ause\x12\x10.wcd.ChannelName\x1a\n.wcd.Empty\"\0\x12(\n\x06Resume\x12\
+    \x10.wcd.ChannelName\x1a\n.wcd.Empty\"\0\x12'\n\tSubscribe\x12\n.wcd.Emp\
+    ty\x1a\n.wcd.Event\"\00\x01\x122\n\rGetStatistics\x12\x0f.wcd.StatsQuery\
+    \x1a\x0e.wcd.StatsInfo\"\0\x12-\n\x11RebuildStatistics\x12\n.wcd.Empty\
+    \x1a\n.wcd.Empty\"\0\x123\n\x10RelinkStatistics\x12\n.wcd.Empty\x1a\x11.\
+    wcd.RelinkResult\"\0\x129\n\x18CollectStatisticsGarbage\x12\x0c.wcd.GcQu\
+    ery\x1a\r.wcd.GcResult\"\0\x12@\n\x16RenameStatisticsPrefix\x12\x11.wcd.\
+    RenamePrefix\x1a\x11.wcd.RenameResult\"\0\x122\n\x10ExportStatistics\x12\
+    \n.wcd.Empty\x1a\x10.wcd.StatsExport\"\0\x129\n\x10ImportStatistics\x12\
+    \x10.wcd.StatsImport\x1a\x11.wcd.ImportResult\"\0\x12,\n\tGetReport\x12\
+    \x10.wcd.ReportQuery\x1a\x0b.wcd.Report\"\0\x12+\n\tRateImage\x12\x10.wc\
+    d.ImageRating\x1a\n.wcd.Empty\"\0\x12/\n\x0bSetFavorite\x12\x12.wcd.Imag\
+    eFavorite\x1a\n.wcd.Empty\"\0\x12(\n\x08BanImage\x12\x0e.wcd.ImageName\
+    \x1a\n.wcd.Empty\"\0\x12*\n\nUnbanImage\x12\x0e.wcd.ImageName\x1a\n.wcd.\
+    Empty\"\0\x12&\n\x08ListBans\x12\n.wcd.Empty\x1a\x0c.wcd.BanList\"\0\x12\
+    .\n\nListImages\x12\x0e.wcd.ListQuery\x1a\x0e.wcd.ImageList\"\0\x12,\n\n\
+    GetVersion\x12\n.wcd.Empty\x1a\x10.wcd.VersionInfo\"\0b\x06proto3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
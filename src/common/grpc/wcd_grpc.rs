@@ -22,32 +22,89 @@
 // interface
 
 pub trait Wcd {
-    fn trigger_change(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::Empty>;
+    fn trigger_change(&self, o: ::grpc::RequestOptions, p: super::wcd::ChannelName) -> ::grpc::SingleResponse<super::wcd::Empty>;
 
-    fn trigger_update(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::Empty>;
+    fn trigger_update(&self, o: ::grpc::RequestOptions, p: super::wcd::ChannelName) -> ::grpc::SingleResponse<super::wcd::Empty>;
 
     fn refresh_playlists(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::Empty>;
 
     fn terminate(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::Empty>;
 
-    fn get_status(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::StatusInfo>;
+    fn get_status(&self, o: ::grpc::RequestOptions, p: super::wcd::ChannelName) -> ::grpc::SingleResponse<super::wcd::StatusInfo>;
 
     fn change_playlist(&self, o: ::grpc::RequestOptions, p: super::wcd::PlaylistName) -> ::grpc::SingleResponse<super::wcd::Empty>;
 
-    fn get_statistics(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::StatsInfo>;
+    fn show_image(&self, o: ::grpc::RequestOptions, p: super::wcd::ImageName) -> ::grpc::SingleResponse<super::wcd::Empty>;
+
+    fn show_previous(&self, o: ::grpc::RequestOptions, p: super::wcd::ChannelName) -> ::grpc::SingleResponse<super::wcd::Empty>;
+
+    fn pause(&self, o: ::grpc::RequestOptions, p: super::wcd::ChannelName) -> ::grpc::SingleResponse<super::wcd::Empty>;
+
+    fn resume(&self, o: ::grpc::RequestOptions, p: super::wcd::ChannelName) -> ::grpc::SingleResponse<super::wcd::Empty>;
+
+    fn subscribe(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::StreamingResponse<super::wcd::Event>;
+
+    fn get_statistics(&self, o: ::grpc::RequestOptions, p: super::wcd::StatsQuery) -> ::grpc::SingleResponse<super::wcd::StatsInfo>;
+
+    fn rebuild_statistics(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::Empty>;
+
+    fn relink_statistics(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::RelinkResult>;
+
+    fn collect_statistics_garbage(&self, o: ::grpc::RequestOptions, p: super::wcd::GcQuery) -> ::grpc::SingleResponse<super::wcd::GcResult>;
+
+    fn rename_statistics_prefix(&self, o: ::grpc::RequestOptions, p: super::wcd::RenamePrefix) -> ::grpc::SingleResponse<super::wcd::RenameResult>;
+
+    fn export_statistics(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::StatsExport>;
+
+    fn import_statistics(&self, o: ::grpc::RequestOptions, p: super::wcd::StatsImport) -> ::grpc::SingleResponse<super::wcd::ImportResult>;
+
+    fn get_report(&self, o: ::grpc::RequestOptions, p: super::wcd::ReportQuery) -> ::grpc::SingleResponse<super::wcd::Report>;
+
+    fn rate_image(&self, o: ::grpc::RequestOptions, p: super::wcd::ImageRating) -> ::grpc::SingleResponse<super::wcd::Empty>;
+
+    fn set_favorite(&self, o: ::grpc::RequestOptions, p: super::wcd::ImageFavorite) -> ::grpc::SingleResponse<super::wcd::Empty>;
+
+    fn ban_image(&self, o: ::grpc::RequestOptions, p: super::wcd::ImageName) -> ::grpc::SingleResponse<super::wcd::Empty>;
+
+    fn unban_image(&self, o: ::grpc::RequestOptions, p: super::wcd::ImageName) -> ::grpc::SingleResponse<super::wcd::Empty>;
+
+    fn list_bans(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::BanList>;
+
+    fn list_images(&self, o: ::grpc::RequestOptions, p: super::wcd::ListQuery) -> ::grpc::SingleResponse<super::wcd::ImageList>;
+
+    fn get_version(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::VersionInfo>;
 }
 
 // client
 
 pub struct WcdClient {
     grpc_client: ::std::sync::Arc<::grpc::Client>,
-    method_TriggerChange: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::Empty, super::wcd::Empty>>,
-    method_TriggerUpdate: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::Empty, super::wcd::Empty>>,
+    method_TriggerChange: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::ChannelName, super::wcd::Empty>>,
+    method_TriggerUpdate: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::ChannelName, super::wcd::Empty>>,
     method_RefreshPlaylists: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::Empty, super::wcd::Empty>>,
     method_Terminate: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::Empty, super::wcd::Empty>>,
-    method_GetStatus: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::Empty, super::wcd::StatusInfo>>,
+    method_GetStatus: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::ChannelName, super::wcd::StatusInfo>>,
     method_ChangePlaylist: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::PlaylistName, super::wcd::Empty>>,
-    method_GetStatistics: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::Empty, super::wcd::StatsInfo>>,
+    method_ShowImage: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::ImageName, super::wcd::Empty>>,
+    method_ShowPrevious: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::ChannelName, super::wcd::Empty>>,
+    method_Pause: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::ChannelName, super::wcd::Empty>>,
+    method_Resume: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::ChannelName, super::wcd::Empty>>,
+    method_Subscribe: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::Empty, super::wcd::Event>>,
+    method_GetStatistics: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::StatsQuery, super::wcd::StatsInfo>>,
+    method_RebuildStatistics: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::Empty, super::wcd::Empty>>,
+    method_RelinkStatistics: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::Empty, super::wcd::RelinkResult>>,
+    method_CollectStatisticsGarbage: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::GcQuery, super::wcd::GcResult>>,
+    method_RenameStatisticsPrefix: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::RenamePrefix, super::wcd::RenameResult>>,
+    method_ExportStatistics: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::Empty, super::wcd::StatsExport>>,
+    method_ImportStatistics: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::StatsImport, super::wcd::ImportResult>>,
+    method_GetReport: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::ReportQuery, super::wcd::Report>>,
+    method_RateImage: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::ImageRating, super::wcd::Empty>>,
+    method_SetFavorite: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::ImageFavorite, super::wcd::Empty>>,
+    method_BanImage: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::ImageName, super::wcd::Empty>>,
+    method_UnbanImage: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::ImageName, super::wcd::Empty>>,
+    method_ListBans: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::Empty, super::wcd::BanList>>,
+    method_ListImages: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::ListQuery, super::wcd::ImageList>>,
+    method_GetVersion: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::wcd::Empty, super::wcd::VersionInfo>>,
 }
 
 impl ::grpc::ClientStub for WcdClient {
@@ -90,22 +147,136 @@ impl ::grpc::ClientStub for WcdClient {
                 req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
                 resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
             }),
+            method_ShowImage: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/wcd.Wcd/ShowImage".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_ShowPrevious: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/wcd.Wcd/ShowPrevious".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_Pause: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/wcd.Wcd/Pause".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_Resume: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/wcd.Wcd/Resume".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_Subscribe: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/wcd.Wcd/Subscribe".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::ServerStreaming,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
             method_GetStatistics: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
                 name: "/wcd.Wcd/GetStatistics".to_string(),
                 streaming: ::grpc::rt::GrpcStreaming::Unary,
                 req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
                 resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
             }),
+            method_RebuildStatistics: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/wcd.Wcd/RebuildStatistics".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_RelinkStatistics: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/wcd.Wcd/RelinkStatistics".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_CollectStatisticsGarbage: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/wcd.Wcd/CollectStatisticsGarbage".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_RenameStatisticsPrefix: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/wcd.Wcd/RenameStatisticsPrefix".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_ExportStatistics: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/wcd.Wcd/ExportStatistics".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_ImportStatistics: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/wcd.Wcd/ImportStatistics".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_GetReport: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/wcd.Wcd/GetReport".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_RateImage: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/wcd.Wcd/RateImage".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_SetFavorite: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/wcd.Wcd/SetFavorite".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_BanImage: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/wcd.Wcd/BanImage".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_UnbanImage: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/wcd.Wcd/UnbanImage".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_ListBans: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/wcd.Wcd/ListBans".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_ListImages: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/wcd.Wcd/ListImages".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_GetVersion: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/wcd.Wcd/GetVersion".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
         }
     }
 }
 
 impl Wcd for WcdClient {
-    fn trigger_change(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::Empty> {
+    fn trigger_change(&self, o: ::grpc::RequestOptions, p: super::wcd::ChannelName) -> ::grpc::SingleResponse<super::wcd::Empty> {
         self.grpc_client.call_unary(o, p, self.method_TriggerChange.clone())
     }
 
-    fn trigger_update(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::Empty> {
+    fn trigger_update(&self, o: ::grpc::RequestOptions, p: super::wcd::ChannelName) -> ::grpc::SingleResponse<super::wcd::Empty> {
         self.grpc_client.call_unary(o, p, self.method_TriggerUpdate.clone())
     }
 
@@ -117,7 +288,7 @@ impl Wcd for WcdClient {
         self.grpc_client.call_unary(o, p, self.method_Terminate.clone())
     }
 
-    fn get_status(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::StatusInfo> {
+    fn get_status(&self, o: ::grpc::RequestOptions, p: super::wcd::ChannelName) -> ::grpc::SingleResponse<super::wcd::StatusInfo> {
         self.grpc_client.call_unary(o, p, self.method_GetStatus.clone())
     }
 
@@ -125,9 +296,85 @@ impl Wcd for WcdClient {
         self.grpc_client.call_unary(o, p, self.method_ChangePlaylist.clone())
     }
 
-    fn get_statistics(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::StatsInfo> {
+    fn show_image(&self, o: ::grpc::RequestOptions, p: super::wcd::ImageName) -> ::grpc::SingleResponse<super::wcd::Empty> {
+        self.grpc_client.call_unary(o, p, self.method_ShowImage.clone())
+    }
+
+    fn show_previous(&self, o: ::grpc::RequestOptions, p: super::wcd::ChannelName) -> ::grpc::SingleResponse<super::wcd::Empty> {
+        self.grpc_client.call_unary(o, p, self.method_ShowPrevious.clone())
+    }
+
+    fn pause(&self, o: ::grpc::RequestOptions, p: super::wcd::ChannelName) -> ::grpc::SingleResponse<super::wcd::Empty> {
+        self.grpc_client.call_unary(o, p, self.method_Pause.clone())
+    }
+
+    fn resume(&self, o: ::grpc::RequestOptions, p: super::wcd::ChannelName) -> ::grpc::SingleResponse<super::wcd::Empty> {
+        self.grpc_client.call_unary(o, p, self.method_Resume.clone())
+    }
+
+    fn subscribe(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::StreamingResponse<super::wcd::Event> {
+        self.grpc_client.call_server_streaming(o, p, self.method_Subscribe.clone())
+    }
+
+    fn get_statistics(&self, o: ::grpc::RequestOptions, p: super::wcd::StatsQuery) -> ::grpc::SingleResponse<super::wcd::StatsInfo> {
         self.grpc_client.call_unary(o, p, self.method_GetStatistics.clone())
     }
+
+    fn rebuild_statistics(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::Empty> {
+        self.grpc_client.call_unary(o, p, self.method_RebuildStatistics.clone())
+    }
+
+    fn relink_statistics(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::RelinkResult> {
+        self.grpc_client.call_unary(o, p, self.method_RelinkStatistics.clone())
+    }
+
+    fn collect_statistics_garbage(&self, o: ::grpc::RequestOptions, p: super::wcd::GcQuery) -> ::grpc::SingleResponse<super::wcd::GcResult> {
+        self.grpc_client.call_unary(o, p, self.method_CollectStatisticsGarbage.clone())
+    }
+
+    fn rename_statistics_prefix(&self, o: ::grpc::RequestOptions, p: super::wcd::RenamePrefix) -> ::grpc::SingleResponse<super::wcd::RenameResult> {
+        self.grpc_client.call_unary(o, p, self.method_RenameStatisticsPrefix.clone())
+    }
+
+    fn export_statistics(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::StatsExport> {
+        self.grpc_client.call_unary(o, p, self.method_ExportStatistics.clone())
+    }
+
+    fn import_statistics(&self, o: ::grpc::RequestOptions, p: super::wcd::StatsImport) -> ::grpc::SingleResponse<super::wcd::ImportResult> {
+        self.grpc_client.call_unary(o, p, self.method_ImportStatistics.clone())
+    }
+
+    fn get_report(&self, o: ::grpc::RequestOptions, p: super::wcd::ReportQuery) -> ::grpc::SingleResponse<super::wcd::Report> {
+        self.grpc_client.call_unary(o, p, self.method_GetReport.clone())
+    }
+
+    fn rate_image(&self, o: ::grpc::RequestOptions, p: super::wcd::ImageRating) -> ::grpc::SingleResponse<super::wcd::Empty> {
+        self.grpc_client.call_unary(o, p, self.method_RateImage.clone())
+    }
+
+    fn set_favorite(&self, o: ::grpc::RequestOptions, p: super::wcd::ImageFavorite) -> ::grpc::SingleResponse<super::wcd::Empty> {
+        self.grpc_client.call_unary(o, p, self.method_SetFavorite.clone())
+    }
+
+    fn ban_image(&self, o: ::grpc::RequestOptions, p: super::wcd::ImageName) -> ::grpc::SingleResponse<super::wcd::Empty> {
+        self.grpc_client.call_unary(o, p, self.method_BanImage.clone())
+    }
+
+    fn unban_image(&self, o: ::grpc::RequestOptions, p: super::wcd::ImageName) -> ::grpc::SingleResponse<super::wcd::Empty> {
+        self.grpc_client.call_unary(o, p, self.method_UnbanImage.clone())
+    }
+
+    fn list_bans(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::BanList> {
+        self.grpc_client.call_unary(o, p, self.method_ListBans.clone())
+    }
+
+    fn list_images(&self, o: ::grpc::RequestOptions, p: super::wcd::ListQuery) -> ::grpc::SingleResponse<super::wcd::ImageList> {
+        self.grpc_client.call_unary(o, p, self.method_ListImages.clone())
+    }
+
+    fn get_version(&self, o: ::grpc::RequestOptions, p: super::wcd::Empty) -> ::grpc::SingleResponse<super::wcd::VersionInfo> {
+        self.grpc_client.call_unary(o, p, self.method_GetVersion.clone())
+    }
 }
 
 // server
@@ -212,6 +459,66 @@ impl WcdServer {
                         ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.change_playlist(o, p))
                     },
                 ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/wcd.Wcd/ShowImage".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.show_image(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/wcd.Wcd/ShowPrevious".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.show_previous(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/wcd.Wcd/Pause".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.pause(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/wcd.Wcd/Resume".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.resume(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/wcd.Wcd/Subscribe".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::ServerStreaming,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerServerStreaming::new(move |o, p| handler_copy.subscribe(o, p))
+                    },
+                ),
                 ::grpc::rt::ServerMethod::new(
                     ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
                         name: "/wcd.Wcd/GetStatistics".to_string(),
@@ -224,6 +531,174 @@ impl WcdServer {
                         ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.get_statistics(o, p))
                     },
                 ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/wcd.Wcd/RebuildStatistics".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.rebuild_statistics(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/wcd.Wcd/RelinkStatistics".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.relink_statistics(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/wcd.Wcd/CollectStatisticsGarbage".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.collect_statistics_garbage(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/wcd.Wcd/RenameStatisticsPrefix".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.rename_statistics_prefix(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/wcd.Wcd/ExportStatistics".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.export_statistics(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/wcd.Wcd/ImportStatistics".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.import_statistics(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/wcd.Wcd/GetReport".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.get_report(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/wcd.Wcd/RateImage".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.rate_image(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/wcd.Wcd/SetFavorite".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.set_favorite(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/wcd.Wcd/BanImage".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.ban_image(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/wcd.Wcd/UnbanImage".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.unban_image(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/wcd.Wcd/ListBans".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.list_bans(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/wcd.Wcd/ListImages".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.list_images(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/wcd.Wcd/GetVersion".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.get_version(o, p))
+                    },
+                ),
             ],
         )
     }
@@ -1,5 +1,7 @@
+pub mod auth;
 pub mod config;
 pub mod proto;
 pub mod util;
 pub mod log;
 pub mod grpc;
+pub mod tls;
@@ -1,41 +1,186 @@
 use std::collections::HashMap;
+use std::fmt;
 
 use serde_derive::{Serialize, Deserialize};
 
 use crate::common::config;
-use crate::common::grpc::wcd;
+use crate::common::grpc::{health, wcd};
 
+// increased whenever RPCs are added or their messages change; daemons which do not implement
+// `GetVersion` have the protocol version 0
+pub const PROTOCOL_VERSION: u32 = 2;
+
+// channel names are optional everywhere, `None` means the default channel
 #[derive(Debug, Clone, PartialEq)]
 pub enum ControlRequest {
-    TriggerChange,
-    TriggerUpdate,
+    TriggerChange(Option<String>),
+    TriggerUpdate(Option<String>),
     RefreshPlaylists,
     Terminate,
 
-    GetStatus,
+    GetStatus(Option<String>),
+    GetStatistics(StatsQuery),
+
+    ChangePlaylist(Option<String>, String),
+    ShowImage { channel: Option<String>, image: String },
+    ShowPrevious(Option<String>),
+    Pause(Option<String>),
+    Resume(Option<String>),
+
+    RebuildStatistics,
+    RelinkStatistics,
+    CollectStatisticsGarbage { dry_run: bool },
+    RenameStatisticsPrefix { old_prefix: String, new_prefix: String },
+    ExportStatistics,
+    // `data` is the output of `ExportStatistics`
+    ImportStatistics { data: String, merge: bool },
+    GetReport(ReportQuery),
+
+    // `image` is `None` for the images currently displayed in the channel
+    RateImage { channel: Option<String>, image: Option<String>, rating: u8 },
+    SetFavorite { channel: Option<String>, image: Option<String>, favorite: bool },
+    BanImage { channel: Option<String>, image: Option<String> },
+    UnbanImage(String),
+    ListBans,
 
-    ChangePlaylist(String),
+    ListImages(ListQuery),
+
+    GetVersion,
+    CheckHealth,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ControlResponse {
     TriggerChangeOk,
+    TriggerChangeFailed(Failure),
+
     TriggerUpdateOk,
+    TriggerUpdateFailed(Failure),
+
     RefreshPlaylistsOk,
+    RefreshPlaylistsFailed(Failure),
+
     TerminateOk,
+    TerminateFailed(Failure),
 
     StatusInfoOk(StatusInfo),
-    StatusInfoFailed(String),
+    StatusInfoFailed(Failure),
+
+    StatsInfoOk(StatsInfo),
+    StatsInfoFailed(Failure),
 
     ChangePlaylistOk,
-    ChangePlaylistFailed(String),
+    ChangePlaylistFailed(Failure),
+
+    ShowImageOk,
+    ShowImageFailed(Failure),
+
+    ShowPreviousOk,
+    ShowPreviousFailed(Failure),
+
+    PauseOk,
+    PauseFailed(Failure),
+
+    ResumeOk,
+    ResumeFailed(Failure),
+
+    RebuildStatisticsOk,
+    RebuildStatisticsFailed(Failure),
+
+    RelinkStatisticsOk(RelinkResult),
+    RelinkStatisticsFailed(Failure),
+
+    GcStatisticsOk(GcResult),
+    GcStatisticsFailed(Failure),
+
+    RenameStatisticsPrefixOk(RenameResult),
+    RenameStatisticsPrefixFailed(Failure),
+
+    ExportStatisticsOk(String),
+    ExportStatisticsFailed(Failure),
+
+    ImportStatisticsOk(ImportResult),
+    ImportStatisticsFailed(Failure),
+
+    ReportOk(Report),
+    ReportFailed(Failure),
+
+    RateImageOk,
+    RateImageFailed(Failure),
+
+    SetFavoriteOk,
+    SetFavoriteFailed(Failure),
+
+    BanImageOk,
+    BanImageFailed(Failure),
+
+    UnbanImageOk,
+    UnbanImageFailed(Failure),
+
+    BanListOk(BanList),
+    BanListFailed(Failure),
+
+    ImageListOk(ImageList),
+    ImageListFailed(Failure),
+
+    VersionOk(VersionInfo),
+    VersionFailed(Failure),
+
+    HealthOk(HealthStatus),
+    HealthFailed(Failure),
 }
 
+// a request rejected by the daemon, classified by the status it was reported with
 #[derive(Debug, Clone, PartialEq)]
+pub struct Failure {
+    pub kind: FailureKind,
+    pub message: String,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FailureKind {
+    InvalidArgument,
+    NotFound,
+    FailedPrecondition,
+    Unavailable,
+    Unauthenticated,
+    PermissionDenied,
+    // the daemon does not implement the request, usually because it is older than the client
+    Unimplemented,
+    // failures of the daemon itself, and statuses unknown to this client
+    Internal,
+}
+
+impl From<::grpc::GrpcMessageError> for Failure {
+    fn from(e: ::grpc::GrpcMessageError) -> Failure {
+        let kind = match e.grpc_status {
+            s if s == ::grpc::GrpcStatus::Argument as i32 => FailureKind::InvalidArgument,
+            s if s == ::grpc::GrpcStatus::NotFound as i32 => FailureKind::NotFound,
+            s if s == ::grpc::GrpcStatus::FailedPrecondition as i32 => FailureKind::FailedPrecondition,
+            s if s == ::grpc::GrpcStatus::Unavailable as i32 => FailureKind::Unavailable,
+            s if s == ::grpc::GrpcStatus::Unauthenticated as i32 => FailureKind::Unauthenticated,
+            s if s == ::grpc::GrpcStatus::PermissionDenied as i32 => FailureKind::PermissionDenied,
+            s if s == ::grpc::GrpcStatus::Unimplemented as i32 => FailureKind::Unimplemented,
+            _ => FailureKind::Internal,
+        };
+        Failure { kind, message: e.grpc_message, }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
 pub struct StatusInfo {
     pub playlists: HashMap<String, PlaylistInfo>,
     pub current_playlist: String,
     pub last_update: i64,
+    pub channel: String,
+    pub channels: Vec<String>,
+    pub paused: bool,
 }
 
 impl From<wcd::StatusInfo> for StatusInfo {
@@ -46,6 +191,9 @@ impl From<wcd::StatusInfo> for StatusInfo {
                 .collect(),
             current_playlist: proto.current_playlist,
             last_update: proto.last_update,
+            channel: proto.channel,
+            channels: proto.channels.into_vec(),
+            paused: proto.paused,
         }
     }
 }
@@ -56,17 +204,21 @@ impl Into<wcd::StatusInfo> for StatusInfo {
         proto.set_playlists(self.playlists.into_iter().map(|(k, v)| (k, v.into())).collect());
         proto.set_current_playlist(self.current_playlist);
         proto.set_last_update(self.last_update);
+        proto.set_channel(self.channel);
+        proto.set_channels(::protobuf::RepeatedField::from_vec(self.channels));
+        proto.set_paused(self.paused);
         proto
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Serialize, Debug, Clone, PartialEq)]
 pub struct PlaylistInfo {
     pub directories: Vec<String>,
     pub files: Vec<String>,
     pub total_files: u64,
     pub mode: ChangeMode,
     pub current_image: Option<String>,
+    pub current_images: Vec<OutputImage>,
     pub trigger_on_select: bool,
     pub use_last_on_select: bool,
     pub next_update: i64,
@@ -80,6 +232,7 @@ impl From<wcd::PlaylistInfo> for PlaylistInfo {
             total_files: proto.total_files,
             mode: proto.mode.into(),
             current_image: if proto.current_image.is_empty() { None } else { Some(proto.current_image) },
+            current_images: proto.current_images.into_iter().map(Into::into).collect(),
             trigger_on_select: proto.trigger_on_select,
             use_last_on_select: proto.use_last_on_select,
             next_update: proto.next_update,
@@ -95,6 +248,7 @@ impl Into<wcd::PlaylistInfo> for PlaylistInfo {
         proto.set_total_files(self.total_files);
         proto.set_mode(self.mode.into());
         proto.set_current_image(self.current_image.unwrap_or(String::new()));
+        proto.set_current_images(self.current_images.into_iter().map(Into::into).collect());
         proto.set_trigger_on_select(self.trigger_on_select);
         proto.set_use_last_on_select(self.use_last_on_select);
         proto.set_next_update(self.next_update);
@@ -102,10 +256,38 @@ impl Into<wcd::PlaylistInfo> for PlaylistInfo {
     }
 }
 
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct OutputImage {
+    pub output: String,
+    pub image: String,
+    pub override_name: Option<String>,
+}
+
+impl From<wcd::OutputImage> for OutputImage {
+    fn from(proto: wcd::OutputImage) -> OutputImage {
+        OutputImage {
+            output: proto.output,
+            image: proto.image,
+            override_name: if proto.override_name.is_empty() { None } else { Some(proto.override_name) },
+        }
+    }
+}
+
+impl Into<wcd::OutputImage> for OutputImage {
+    fn into(self) -> wcd::OutputImage {
+        let mut proto = wcd::OutputImage::new();
+        proto.set_output(self.output);
+        proto.set_image(self.image);
+        proto.set_override_name(self.override_name.unwrap_or_default());
+        proto
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub enum ChangeMode {
     Sequential,
     Random,
+    Weighted,
 }
 
 impl From<wcd::ChangeMode> for ChangeMode {
@@ -113,6 +295,7 @@ impl From<wcd::ChangeMode> for ChangeMode {
         match proto {
             wcd::ChangeMode::RANDOM => ChangeMode::Random,
             wcd::ChangeMode::SEQUENTIAL => ChangeMode::Sequential,
+            wcd::ChangeMode::WEIGHTED => ChangeMode::Weighted,
         }
     }
 }
@@ -122,6 +305,7 @@ impl Into<wcd::ChangeMode> for ChangeMode {
         match self {
             ChangeMode::Random => wcd::ChangeMode::RANDOM,
             ChangeMode::Sequential => wcd::ChangeMode::SEQUENTIAL,
+            ChangeMode::Weighted => wcd::ChangeMode::WEIGHTED,
         }
     }
 }
@@ -130,28 +314,544 @@ impl From<config::ChangeMode> for ChangeMode {
     fn from(mode: config::ChangeMode) -> Self {
         match mode {
             config::ChangeMode::Sequential => ChangeMode::Sequential,
-            config::ChangeMode::Random => ChangeMode::Random
+            config::ChangeMode::Random => ChangeMode::Random,
+            config::ChangeMode::Weighted => ChangeMode::Weighted,
+        }
+    }
+}
+
+// an event streamed by the daemon to subscribed clients
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Event {
+    pub timestamp: i64,
+    pub channel: Option<String>,
+    #[serde(flatten)]
+    pub kind: EventKind,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum EventKind {
+    ImageChanged { playlist: String, old_images: Vec<String>, new_images: Vec<String>, reason: DisplayReason },
+    PlaylistChanged { old_playlist: String, new_playlist: String },
+    PlaylistsRefreshed { added: u64, removed: u64 },
+    Paused,
+    Resumed,
+    CommandFailed { playlist: String, images: Vec<String> },
+    ShuttingDown,
+    // sent by a newer daemon
+    Unknown,
+}
+
+impl From<wcd::Event> for Event {
+    fn from(proto: wcd::Event) -> Event {
+        let kind = match proto.kind {
+            Some(wcd::Event_oneof_kind::image_changed(e)) => EventKind::ImageChanged {
+                playlist: e.playlist,
+                old_images: e.old_images.into_vec(),
+                new_images: e.new_images.into_vec(),
+                reason: e.reason.into(),
+            },
+            Some(wcd::Event_oneof_kind::playlist_changed(e)) => EventKind::PlaylistChanged {
+                old_playlist: e.old_playlist,
+                new_playlist: e.new_playlist,
+            },
+            Some(wcd::Event_oneof_kind::playlists_refreshed(e)) => EventKind::PlaylistsRefreshed {
+                added: e.added,
+                removed: e.removed,
+            },
+            Some(wcd::Event_oneof_kind::paused(_)) => EventKind::Paused,
+            Some(wcd::Event_oneof_kind::resumed(_)) => EventKind::Resumed,
+            Some(wcd::Event_oneof_kind::command_failed(e)) => EventKind::CommandFailed {
+                playlist: e.playlist,
+                images: e.images.into_vec(),
+            },
+            Some(wcd::Event_oneof_kind::shutting_down(_)) => EventKind::ShuttingDown,
+            None => EventKind::Unknown,
+        };
+
+        Event {
+            timestamp: proto.timestamp,
+            channel: if proto.channel.is_empty() { None } else { Some(proto.channel) },
+            kind,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DisplayReason {
+    Scheduled,
+    Manual,
+    Select,
+    Restore,
+}
+
+impl From<wcd::DisplayReason> for DisplayReason {
+    fn from(proto: wcd::DisplayReason) -> DisplayReason {
+        match proto {
+            wcd::DisplayReason::SCHEDULED => DisplayReason::Scheduled,
+            wcd::DisplayReason::MANUAL => DisplayReason::Manual,
+            wcd::DisplayReason::SELECT => DisplayReason::Select,
+            wcd::DisplayReason::RESTORE => DisplayReason::Restore,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatsQuery {
+    pub playlist: Option<String>,
+    pub sort: StatsSort,
+    pub top: Option<u32>,
+    pub missing_only: bool,
+}
+
+impl From<wcd::StatsQuery> for StatsQuery {
+    fn from(proto: wcd::StatsQuery) -> StatsQuery {
+        StatsQuery {
+            playlist: if proto.playlist.is_empty() { None } else { Some(proto.playlist) },
+            sort: proto.sort.into(),
+            top: if proto.top == 0 { None } else { Some(proto.top) },
+            missing_only: proto.missing_only,
+        }
+    }
+}
+
+impl Into<wcd::StatsQuery> for StatsQuery {
+    fn into(self) -> wcd::StatsQuery {
+        let mut proto = wcd::StatsQuery::new();
+        proto.set_playlist(self.playlist.unwrap_or_default());
+        proto.set_sort(self.sort.into());
+        proto.set_top(self.top.unwrap_or(0));
+        proto.set_missing_only(self.missing_only);
+        proto
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StatsSort {
+    Filename,
+    Displays,
+    Skips,
+    DisplayTime,
+}
+
+impl StatsSort {
+    // the names used by `wcd stats --sort`
+    pub fn from_name(name: &str) -> Option<StatsSort> {
+        match name {
+            "name" => Some(StatsSort::Filename),
+            "displays" => Some(StatsSort::Displays),
+            "skips" => Some(StatsSort::Skips),
+            "time" => Some(StatsSort::DisplayTime),
+            _ => None,
+        }
+    }
+}
+
+impl From<wcd::StatsSort> for StatsSort {
+    fn from(proto: wcd::StatsSort) -> StatsSort {
+        match proto {
+            wcd::StatsSort::FILENAME => StatsSort::Filename,
+            wcd::StatsSort::DISPLAYS => StatsSort::Displays,
+            wcd::StatsSort::SKIPS => StatsSort::Skips,
+            wcd::StatsSort::DISPLAY_TIME => StatsSort::DisplayTime,
+        }
+    }
+}
+
+impl Into<wcd::StatsSort> for StatsSort {
+    fn into(self) -> wcd::StatsSort {
+        match self {
+            StatsSort::Filename => wcd::StatsSort::FILENAME,
+            StatsSort::Displays => wcd::StatsSort::DISPLAYS,
+            StatsSort::Skips => wcd::StatsSort::SKIPS,
+            StatsSort::DisplayTime => wcd::StatsSort::DISPLAY_TIME,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct StatsInfo {
+    pub image_stats: Vec<ImageStats>,
+    pub dropped_writes: u64,
+}
+
+impl From<wcd::StatsInfo> for StatsInfo {
+    fn from(proto: wcd::StatsInfo) -> StatsInfo {
+        StatsInfo {
+            image_stats: proto.image_stats.into_iter().map(Into::into).collect(),
+            dropped_writes: proto.dropped_writes,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ImageStats {
+    pub filename: String,
+    pub total_displays: i64,
+    pub total_skips: i64,
+    pub total_display_time: i64,
+    pub rating: Option<u8>,
+    pub favorite: bool,
+}
+
+impl From<wcd::ImageStatsInfo> for ImageStats {
+    fn from(proto: wcd::ImageStatsInfo) -> ImageStats {
+        ImageStats {
+            filename: proto.filename,
+            total_displays: proto.total_displays,
+            total_skips: proto.total_skips,
+            total_display_time: proto.total_display_time,
+            rating: if proto.rating == 0 { None } else { Some(proto.rating as u8) },
+            favorite: proto.favorite,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct RelinkResult {
+    pub merged: u32,
+    pub relinked: u32,
+}
+
+impl From<wcd::RelinkResult> for RelinkResult {
+    fn from(proto: wcd::RelinkResult) -> RelinkResult {
+        RelinkResult {
+            merged: proto.merged,
+            relinked: proto.relinked,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct GcResult {
+    pub images: Vec<String>,
+    pub paths: u32,
+    pub preferences: u32,
+    pub dry_run: bool,
+}
+
+impl From<wcd::GcResult> for GcResult {
+    fn from(proto: wcd::GcResult) -> GcResult {
+        GcResult {
+            images: proto.images.into_vec(),
+            paths: proto.paths,
+            preferences: proto.preferences,
+            dry_run: proto.dry_run,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct RenameResult {
+    pub paths: u32,
+    pub preferences: u32,
+    pub merged: u32,
+}
+
+impl From<wcd::RenameResult> for RenameResult {
+    fn from(proto: wcd::RenameResult) -> RenameResult {
+        RenameResult {
+            paths: proto.paths,
+            preferences: proto.preferences,
+            merged: proto.merged,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ImportResult {
+    pub images: u32,
+    pub events: u32,
+    pub duplicate_events: u32,
+}
+
+impl From<wcd::ImportResult> for ImportResult {
+    fn from(proto: wcd::ImportResult) -> ImportResult {
+        ImportResult {
+            images: proto.images,
+            events: proto.events,
+            duplicate_events: proto.duplicate_events,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportQuery {
+    pub since: Option<i64>,
+    pub group_by: ReportGrouping,
+}
+
+impl From<wcd::ReportQuery> for ReportQuery {
+    fn from(proto: wcd::ReportQuery) -> ReportQuery {
+        ReportQuery {
+            since: if proto.since == 0 { None } else { Some(proto.since) },
+            group_by: proto.group_by.into(),
+        }
+    }
+}
+
+impl Into<wcd::ReportQuery> for ReportQuery {
+    fn into(self) -> wcd::ReportQuery {
+        let mut proto = wcd::ReportQuery::new();
+        proto.set_since(self.since.unwrap_or(0));
+        proto.set_group_by(self.group_by.into());
+        proto
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ReportGrouping {
+    Playlist,
+    Day,
+    Directory,
+}
+
+impl ReportGrouping {
+    // the names used by `wcd stats report --by`
+    pub fn from_name(name: &str) -> Option<ReportGrouping> {
+        match name {
+            "playlist" => Some(ReportGrouping::Playlist),
+            "day" => Some(ReportGrouping::Day),
+            "directory" => Some(ReportGrouping::Directory),
+            _ => None,
+        }
+    }
+}
+
+impl From<wcd::ReportGrouping> for ReportGrouping {
+    fn from(proto: wcd::ReportGrouping) -> ReportGrouping {
+        match proto {
+            wcd::ReportGrouping::PLAYLIST => ReportGrouping::Playlist,
+            wcd::ReportGrouping::DAY => ReportGrouping::Day,
+            wcd::ReportGrouping::DIRECTORY => ReportGrouping::Directory,
+        }
+    }
+}
+
+impl Into<wcd::ReportGrouping> for ReportGrouping {
+    fn into(self) -> wcd::ReportGrouping {
+        match self {
+            ReportGrouping::Playlist => wcd::ReportGrouping::PLAYLIST,
+            ReportGrouping::Day => wcd::ReportGrouping::DAY,
+            ReportGrouping::Directory => wcd::ReportGrouping::DIRECTORY,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub groups: Vec<ReportGroup>,
+}
+
+impl From<wcd::Report> for Report {
+    fn from(proto: wcd::Report) -> Report {
+        Report {
+            groups: proto.groups.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ReportGroup {
+    pub name: String,
+    pub display_time: i64,
+    pub displays: i64,
+    pub skips: i64,
+    // the share of displays which were skipped
+    pub skip_rate: f64,
+}
+
+impl From<wcd::ReportGroup> for ReportGroup {
+    fn from(proto: wcd::ReportGroup) -> ReportGroup {
+        ReportGroup {
+            skip_rate: if proto.displays > 0 { proto.skips as f64 / proto.displays as f64 } else { 0.0 },
+            name: proto.name,
+            display_time: proto.display_time,
+            displays: proto.displays,
+            skips: proto.skips,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct BanList {
+    pub bans: Vec<BannedImage>,
+}
+
+impl From<wcd::BanList> for BanList {
+    fn from(proto: wcd::BanList) -> BanList {
+        BanList {
+            bans: proto.bans.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct BannedImage {
+    pub filename: String,
+    pub banned_at: i64,
+}
+
+impl From<wcd::BannedImage> for BannedImage {
+    fn from(proto: wcd::BannedImage) -> BannedImage {
+        BannedImage {
+            filename: proto.filename,
+            banned_at: proto.banned_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListQuery {
+    pub channel: Option<String>,
+    pub playlist: Option<String>,
+    pub upcoming: bool,
+}
+
+impl From<wcd::ListQuery> for ListQuery {
+    fn from(proto: wcd::ListQuery) -> ListQuery {
+        ListQuery {
+            channel: if proto.channel.is_empty() { None } else { Some(proto.channel) },
+            playlist: if proto.playlist.is_empty() { None } else { Some(proto.playlist) },
+            upcoming: proto.upcoming,
+        }
+    }
+}
+
+impl Into<wcd::ListQuery> for ListQuery {
+    fn into(self) -> wcd::ListQuery {
+        let mut proto = wcd::ListQuery::new();
+        proto.set_channel(self.channel.unwrap_or_default());
+        proto.set_playlist(self.playlist.unwrap_or_default());
+        proto.set_upcoming(self.upcoming);
+        proto
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ImageList {
+    pub playlist: String,
+    pub mode: ChangeMode,
+    pub images: Vec<ListedImage>,
+}
+
+impl From<wcd::ImageList> for ImageList {
+    fn from(proto: wcd::ImageList) -> ImageList {
+        let weighted = proto.mode == wcd::ChangeMode::WEIGHTED;
+        ImageList {
+            playlist: proto.playlist,
+            mode: proto.mode.into(),
+            images: proto.images.into_iter()
+                .map(|image| ListedImage {
+                    path: image.path,
+                    weight: if weighted { Some(image.weight) } else { None },
+                    override_name: if image.override_name.is_empty() { None } else { Some(image.override_name) },
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Into<wcd::ImageList> for ImageList {
+    fn into(self) -> wcd::ImageList {
+        let mut proto = wcd::ImageList::new();
+        proto.set_playlist(self.playlist);
+        proto.set_mode(self.mode.into());
+        proto.set_images(self.images.into_iter()
+            .map(|image| {
+                let mut listed = wcd::ListedImage::new();
+                listed.set_path(image.path);
+                listed.set_weight(image.weight.unwrap_or(0.0));
+                listed.set_override_name(image.override_name.unwrap_or_default());
+                listed
+            })
+            .collect());
+        proto
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ListedImage {
+    pub path: String,
+    pub weight: Option<f64>,
+    pub override_name: Option<String>,
+}
+
+// failures reported by the daemon become responses, errors of the transport are kept
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct VersionInfo {
+    pub version: String,
+    pub protocol_version: u32,
+    pub stats_enabled: bool,
+    // `None` if playlists are not rescanned periodically
+    pub watch_interval: Option<i64>,
+    pub rpcs: Vec<String>,
+}
+
+impl From<wcd::VersionInfo> for VersionInfo {
+    fn from(proto: wcd::VersionInfo) -> VersionInfo {
+        VersionInfo {
+            version: proto.version,
+            protocol_version: proto.protocol_version,
+            stats_enabled: proto.stats_enabled,
+            watch_interval: if proto.watch_interval > 0 { Some(proto.watch_interval) } else { None },
+            rpcs: proto.rpcs.into_vec(),
+        }
+    }
+}
+
+impl Into<wcd::VersionInfo> for VersionInfo {
+    fn into(self) -> wcd::VersionInfo {
+        let mut proto = wcd::VersionInfo::new();
+        proto.set_version(self.version);
+        proto.set_protocol_version(self.protocol_version);
+        proto.set_stats_enabled(self.stats_enabled);
+        proto.set_watch_interval(self.watch_interval.unwrap_or(0));
+        proto.set_rpcs(::protobuf::RepeatedField::from_vec(self.rpcs));
+        proto
+    }
+}
+
+// names are the ones of the standard health checking protocol
+#[derive(Serialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum HealthStatus {
+    Unknown,
+    Serving,
+    NotServing,
+    ServiceUnknown,
+}
+
+impl From<health::HealthCheckResponse_ServingStatus> for HealthStatus {
+    fn from(proto: health::HealthCheckResponse_ServingStatus) -> HealthStatus {
+        match proto {
+            health::HealthCheckResponse_ServingStatus::UNKNOWN => HealthStatus::Unknown,
+            health::HealthCheckResponse_ServingStatus::SERVING => HealthStatus::Serving,
+            health::HealthCheckResponse_ServingStatus::NOT_SERVING => HealthStatus::NotServing,
+            health::HealthCheckResponse_ServingStatus::SERVICE_UNKNOWN => HealthStatus::ServiceUnknown,
         }
     }
 }
 
 pub trait GrpcResponseExt<T> {
-    fn fold<F1, F2, U>(self, success: F1, failure: F2) -> ControlResponse
+    fn fold<F1, F2, U>(self, success: F1, failure: F2) -> Result<ControlResponse, ::grpc::Error>
         where F1: FnOnce(U) -> ControlResponse,
-              F2: FnOnce(String) -> ControlResponse,
+              F2: FnOnce(Failure) -> ControlResponse,
               T: Into<U>;
 }
 
-impl<T> GrpcResponseExt<T> for (::grpc::Metadata, T, ::grpc::Metadata) {
-    fn fold<F1, F2, U>(self, success: F1, failure: F2) -> ControlResponse
+impl<T> GrpcResponseExt<T> for ::grpc::Result<T> {
+    fn fold<F1, F2, U>(self, success: F1, failure: F2) -> Result<ControlResponse, ::grpc::Error>
         where F1: FnOnce(U) -> ControlResponse,
-              F2: FnOnce(String) -> ControlResponse,
+              F2: FnOnce(Failure) -> ControlResponse,
               T: Into<U>
     {
-        let (md, v, _) = self;
-        match md.get("error") {
-            Some(msg_bytes) => failure(String::from_utf8(msg_bytes.into()).unwrap()),
-            None => success(v.into()),
+        match self {
+            Ok(v) => Ok(success(v.into())),
+            Err(::grpc::Error::GrpcMessage(e)) => Ok(failure(e.into())),
+            Err(e) => Err(e),
         }
     }
 }
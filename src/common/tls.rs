@@ -0,0 +1,244 @@
+// a backend of the tls-api version used by grpc, implemented over the openssl crate; the published backends of that
+// version do not build with current OpenSSL releases
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::result;
+
+use openssl::ssl::{self, AlpnError, SslAcceptor, SslConnector, SslFiletype, SslMethod};
+use openssl::x509::X509;
+use tls_api::{self, Certificate, CertificateFormat, Error};
+
+use crate::common::config::ValidatedTls;
+
+// gRPC runs over HTTP/2, which is negotiated during the handshake
+const ALPN_PROTOCOLS: &[&[u8]] = &[b"h2"];
+
+pub struct TlsConnectorBuilder {
+    builder: ssl::SslConnectorBuilder,
+    verify_hostname: bool,
+}
+
+pub struct TlsConnector {
+    connector: SslConnector,
+    verify_hostname: bool,
+}
+
+pub struct TlsAcceptorBuilder(ssl::SslAcceptorBuilder);
+
+pub struct TlsAcceptor(SslAcceptor);
+
+impl TlsConnector {
+    // the system certificate authorities are trusted if the configuration does not name one
+    pub fn from_config(config: &ValidatedTls) -> tls_api::Result<TlsConnector> {
+        let mut builder = <TlsConnector as tls_api::TlsConnector>::builder()?;
+        if let Some(ref ca_file) = config.ca_file {
+            builder.builder.set_ca_file(ca_file).map_err(|e| file_error("CA certificate", ca_file, e))?;
+        }
+        tls_api::TlsConnectorBuilder::set_alpn_protocols(&mut builder, ALPN_PROTOCOLS)?;
+        tls_api::TlsConnectorBuilder::build(builder)
+    }
+}
+
+impl TlsAcceptor {
+    // the certificate file may contain the whole chain, starting with the daemon's certificate
+    pub fn from_config(config: &ValidatedTls) -> tls_api::Result<TlsAcceptor> {
+        let (cert_file, key_file) = (&config.cert_file, &config.key_file);
+        let mut builder = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls()).map_err(Error::new)?;
+        builder.set_certificate_chain_file(cert_file).map_err(|e| file_error("certificate", cert_file, e))?;
+        builder.set_private_key_file(key_file, SslFiletype::PEM).map_err(|e| file_error("private key", key_file, e))?;
+        builder.check_private_key().map_err(Error::new)?;
+
+        let mut builder = TlsAcceptorBuilder(builder);
+        tls_api::TlsAcceptorBuilder::set_alpn_protocols(&mut builder, ALPN_PROTOCOLS)?;
+        tls_api::TlsAcceptorBuilder::build(builder)
+    }
+}
+
+fn file_error(what: &str, path: &Path, e: openssl::error::ErrorStack) -> Error {
+    Error::new_other(&format!("cannot load {} from {}: {}", what, path.display(), e))
+}
+
+fn encode_alpn_protocols(protocols: &[&[u8]]) -> tls_api::Result<Vec<u8>> {
+    let mut result = Vec::new();
+    for protocol in protocols {
+        if protocol.len() > 255 {
+            return Err(Error::new_other("ALPN protocol name is too long"));
+        }
+        result.push(protocol.len() as u8);
+        result.extend_from_slice(protocol);
+    }
+    Ok(result)
+}
+
+impl tls_api::TlsConnectorBuilder for TlsConnectorBuilder {
+    type Connector = TlsConnector;
+    type Underlying = ssl::SslConnectorBuilder;
+
+    fn underlying_mut(&mut self) -> &mut ssl::SslConnectorBuilder {
+        &mut self.builder
+    }
+
+    fn supports_alpn() -> bool {
+        true
+    }
+
+    fn set_alpn_protocols(&mut self, protocols: &[&[u8]]) -> tls_api::Result<()> {
+        self.builder.set_alpn_protos(&encode_alpn_protocols(protocols)?).map_err(Error::new)
+    }
+
+    fn set_verify_hostname(&mut self, verify: bool) -> tls_api::Result<()> {
+        self.verify_hostname = verify;
+        Ok(())
+    }
+
+    fn add_root_certificate(&mut self, cert: Certificate) -> tls_api::Result<&mut Self> {
+        let cert = match cert.format {
+            CertificateFormat::DER => X509::from_der(&cert.bytes),
+            CertificateFormat::PEM => X509::from_pem(&cert.bytes),
+        };
+        self.builder.cert_store_mut().add_cert(cert.map_err(Error::new)?).map_err(Error::new)?;
+        Ok(self)
+    }
+
+    fn build(self) -> tls_api::Result<TlsConnector> {
+        Ok(TlsConnector { connector: self.builder.build(), verify_hostname: self.verify_hostname, })
+    }
+}
+
+impl tls_api::TlsConnector for TlsConnector {
+    type Builder = TlsConnectorBuilder;
+
+    fn builder() -> tls_api::Result<TlsConnectorBuilder> {
+        let builder = SslConnector::builder(SslMethod::tls()).map_err(Error::new)?;
+        Ok(TlsConnectorBuilder { builder, verify_hostname: true, })
+    }
+
+    fn connect<S>(&self, domain: &str, stream: S) -> result::Result<tls_api::TlsStream<S>, tls_api::HandshakeError<S>>
+        where S: Read + Write + fmt::Debug + Send + Sync + 'static
+    {
+        self.connector.configure()
+            .map_err(|e| tls_api::HandshakeError::Failure(Error::new(e)))?
+            .verify_hostname(self.verify_hostname)
+            .connect(domain, stream)
+            .map(|stream| tls_api::TlsStream::new(TlsStream(stream)))
+            .map_err(handshake_error)
+    }
+}
+
+impl tls_api::TlsAcceptorBuilder for TlsAcceptorBuilder {
+    type Acceptor = TlsAcceptor;
+    type Underlying = ssl::SslAcceptorBuilder;
+
+    fn supports_alpn() -> bool {
+        true
+    }
+
+    fn set_alpn_protocols(&mut self, protocols: &[&[u8]]) -> tls_api::Result<()> {
+        let protocols: Vec<Vec<u8>> = protocols.iter().map(|protocol| protocol.to_vec()).collect();
+        // the selected protocol must be a part of the client's list
+        self.0.set_alpn_select_callback(move |_, mut client_protocols| {
+            while let Some((&len, rest)) = client_protocols.split_first() {
+                let len = (len as usize).min(rest.len());
+                let (protocol, rest) = rest.split_at(len);
+                if protocols.iter().any(|p| p == protocol) {
+                    return Ok(protocol);
+                }
+                client_protocols = rest;
+            }
+            Err(AlpnError::NOACK)
+        });
+        Ok(())
+    }
+
+    fn underlying_mut(&mut self) -> &mut ssl::SslAcceptorBuilder {
+        &mut self.0
+    }
+
+    fn build(self) -> tls_api::Result<TlsAcceptor> {
+        Ok(TlsAcceptor(self.0.build()))
+    }
+}
+
+impl tls_api::TlsAcceptor for TlsAcceptor {
+    type Builder = TlsAcceptorBuilder;
+
+    fn accept<S>(&self, stream: S) -> result::Result<tls_api::TlsStream<S>, tls_api::HandshakeError<S>>
+        where S: Read + Write + fmt::Debug + Send + Sync + 'static
+    {
+        self.0.accept(stream)
+            .map(|stream| tls_api::TlsStream::new(TlsStream(stream)))
+            .map_err(handshake_error)
+    }
+}
+
+#[derive(Debug)]
+struct TlsStream<S>(ssl::SslStream<S>);
+
+impl<S: Read + Write> Read for TlsStream<S> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+}
+
+impl<S: Read + Write> Write for TlsStream<S> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+impl<S: Read + Write + fmt::Debug + Send + Sync + 'static> tls_api::TlsStreamImpl<S> for TlsStream<S> {
+    fn get_alpn_protocol(&self) -> Option<Vec<u8>> {
+        self.0.ssl().selected_alpn_protocol().map(Vec::from)
+    }
+
+    // the peer may close the connection first, which is not a failure
+    fn shutdown(&mut self) -> io::Result<()> {
+        match self.0.shutdown() {
+            Ok(_) => Ok(()),
+            Err(ref e) if e.code() == ssl::ErrorCode::ZERO_RETURN => Ok(()),
+            Err(e) => Err(io::Error::other(e)),
+        }
+    }
+
+    fn get_mut(&mut self) -> &mut S {
+        self.0.get_mut()
+    }
+
+    fn get_ref(&self) -> &S {
+        self.0.get_ref()
+    }
+}
+
+// the handshake of non-blocking streams is resumed when they become ready
+struct MidHandshakeTlsStream<S>(Option<ssl::MidHandshakeSslStream<S>>);
+
+impl<S> fmt::Debug for MidHandshakeTlsStream<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MidHandshakeTlsStream").finish()
+    }
+}
+
+impl<S: Read + Write + fmt::Debug + Send + Sync + 'static> tls_api::MidHandshakeTlsStreamImpl<S> for MidHandshakeTlsStream<S> {
+    fn handshake(&mut self) -> result::Result<tls_api::TlsStream<S>, tls_api::HandshakeError<S>> {
+        // the handshake is only resumed once, afterwards the stream is replaced by a new one
+        self.0.take().expect("Handshake is resumed twice").handshake()
+            .map(|stream| tls_api::TlsStream::new(TlsStream(stream)))
+            .map_err(handshake_error)
+    }
+}
+
+fn handshake_error<S>(e: ssl::HandshakeError<S>) -> tls_api::HandshakeError<S>
+    where S: Read + Write + fmt::Debug + Send + Sync + 'static
+{
+    match e {
+        ssl::HandshakeError::SetupFailure(e) => tls_api::HandshakeError::Failure(Error::new(e)),
+        ssl::HandshakeError::Failure(e) => tls_api::HandshakeError::Failure(Error::new(e.into_error())),
+        ssl::HandshakeError::WouldBlock(stream) =>
+            tls_api::HandshakeError::Interrupted(tls_api::MidHandshakeTlsStream::new(MidHandshakeTlsStream(Some(stream)))),
+    }
+}
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::fs;
 use std::path::{Path, PathBuf};
 
 use chrono::{Duration, DateTime, Utc};
@@ -51,6 +52,29 @@ pub fn parse_duration(s: &str) -> Option<Duration> {
     Some(mk_duration(n))
 }
 
+// resolves symlinks and relative components of the path, leaving the path as is if it cannot be resolved
+pub fn canonical_path(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_owned())
+}
+
+// formats a number of seconds like "1h 2m 3s"
+pub fn format_display_time(total_seconds: i64) -> String {
+    let total_minutes = total_seconds / 60;
+    let total_hours = total_minutes / 60;
+
+    let hours = total_hours;
+    let minutes = total_minutes % 60;
+    let seconds = total_seconds % 60;
+
+    if hours > 0 {
+        format!("{}h {}m {}s", hours, minutes, seconds)
+    } else if minutes > 0 {
+        format!("{}m {}s", minutes, seconds)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
 // returns an instant most likely located in the past
 pub fn past_timestamp() -> DateTime<Utc> {
     DateTime::parse_from_str("0+0000", "%s%z").unwrap().with_timezone(&Utc)
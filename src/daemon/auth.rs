@@ -0,0 +1,82 @@
+use crate::common::auth;
+use crate::common::config::{Scope, ValidatedAccess};
+use crate::daemon::error::RequestError;
+
+// the token of the request decides its scope, which must include the one required by the method; the same rules
+// apply to every interface of the daemon
+pub fn authorize(access: &ValidatedAccess, token: Option<&[u8]>, required: Scope, method: &str) -> Result<(), RequestError> {
+    let scope = match token {
+        Some(token) => {
+            // all tokens are compared, so the response time does not reveal which one matched; a token
+            // configured several times has the widest of its scopes
+            let matched = access.tokens.iter()
+                .filter(|(expected, _)| auth::tokens_equal(token, expected.as_bytes()))
+                .map(|&(_, scope)| scope)
+                .max();
+            match matched {
+                Some(scope) => scope,
+                None => {
+                    warn!("Rejected {} with an invalid authentication token", method);
+                    return Err(RequestError::Unauthenticated("Invalid authentication token".into()));
+                }
+            }
+        }
+        None => match access.anonymous_scope {
+            Some(scope) => scope,
+            None => {
+                warn!("Rejected {} without an authentication token", method);
+                return Err(RequestError::Unauthenticated("The daemon requires an authentication token".into()));
+            }
+        },
+    };
+
+    if scope < required {
+        warn!("Denied {} to a client with the {} scope", method, scope);
+        return Err(RequestError::PermissionDenied(
+            format!("{} requires the {} scope, the client only has the {} scope", method, required, scope).into()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn access(anonymous_scope: Option<Scope>) -> ValidatedAccess {
+        ValidatedAccess {
+            tokens: vec![
+                ("reader".into(), Scope::Read),
+                ("controller".into(), Scope::Control),
+                ("controller".into(), Scope::Read),
+            ],
+            anonymous_scope,
+        }
+    }
+
+    #[test]
+    fn tokens_give_their_widest_scope() {
+        let access = access(None);
+        assert!(authorize(&access, Some(b"reader"), Scope::Read, "test").is_ok());
+        match authorize(&access, Some(b"reader"), Scope::Control, "test") {
+            Err(RequestError::PermissionDenied(_)) => {}
+            other => panic!("unexpected result {:?}", other),
+        }
+        assert!(authorize(&access, Some(b"controller"), Scope::Control, "test").is_ok());
+        match authorize(&access, Some(b"stranger"), Scope::Read, "test") {
+            Err(RequestError::Unauthenticated(_)) => {}
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn requests_without_tokens_get_the_anonymous_scope() {
+        match authorize(&access(None), None, Scope::Read, "test") {
+            Err(RequestError::Unauthenticated(_)) => {}
+            other => panic!("unexpected result {:?}", other),
+        }
+        let access = access(Some(Scope::Read));
+        assert!(authorize(&access, None, Scope::Read, "test").is_ok());
+        assert!(authorize(&access, None, Scope::Control, "test").is_err());
+    }
+}
@@ -1,21 +1,43 @@
+use std::error::Error;
 use std::thread::{self, JoinHandle};
-use std::sync::{Arc, Barrier};
 use std::iter::FromIterator;
+use std::time::Duration;
 
+use crate::common::auth;
+use crate::common::config::{Scope, ValidatedAccess, ValidatedTls, WatchMode};
 use crate::common::grpc::wcd;
+use crate::common::proto;
 use crate::common::grpc::wcd_grpc::{WcdServer, Wcd};
+use crate::common::grpc::health_grpc::HealthServer;
+use crate::common::tls::TlsAcceptor;
+use futures::{future, stream, Stream};
+
+use crate::daemon::auth as daemon_auth;
+use crate::daemon::error::{self, RequestError};
+use crate::daemon::events::Events;
+use crate::daemon::health::HealthServerImpl;
+use crate::daemon::preferences;
 use crate::daemon::processor::Processor;
-use crate::daemon::stats::Stats;
+use crate::daemon::stats::{self, Stats};
+
+const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_millis(200);
 
 pub struct Control {
     endpoint: String,
+    tls: Option<ValidatedTls>,
+    access: ValidatedAccess,
+    watch: WatchMode,
     daemon: super::Daemon,
 }
 
 impl Control {
-    pub fn new(endpoint: String, daemon: super::Daemon) -> Control {
+    pub fn new(endpoint: String, tls: Option<ValidatedTls>, access: ValidatedAccess, watch: WatchMode,
+               daemon: super::Daemon) -> Control {
         Control {
             endpoint: endpoint,
+            tls: tls,
+            access: access,
+            watch: watch,
             daemon: daemon,
         }
     }
@@ -32,100 +54,459 @@ impl Control {
     fn prepare_and_loop(&self) {
         info!("Starting control server on {}", self.endpoint);
 
-        let mut server = ::grpc::ServerBuilder::new_plain();
+        let mut server = ::grpc::ServerBuilder::<TlsAcceptor>::new();
         if let Err(e) = server.http.set_addr(&self.endpoint) {
             error!("Setting listen address failed: {}", e);
             return;
         }
+        if let Some(ref tls) = self.tls {
+            match TlsAcceptor::from_config(tls) {
+                Ok(acceptor) => server.http.set_tls(acceptor),
+                Err(e) => {
+                    error!("Setting up TLS failed: {}", e);
+                    return;
+                }
+            }
+        }
         server.http.set_cpu_pool_threads(1);
 
-        let stop_barrier = Arc::new(Barrier::new(2));
-
         server.add_service(WcdServer::new_service_def(ControlServerImpl {
             processor: self.daemon.processor(),
             stats: self.daemon.stats(),
-            stop_barrier: stop_barrier.clone(),
+            events: self.daemon.events(),
+            access: self.access.clone(),
+            watch: self.watch.clone(),
+            daemon: self.daemon.clone(),
         }));
+        server.add_service(HealthServer::new_service_def(HealthServerImpl::new(self.daemon.clone())));
 
         let _server = server.build().expect("Creating the control server failed");
 
         info!("Control server started, waiting for requests");
-        stop_barrier.wait();
+        self.daemon.wait_for_termination();
 
         info!("Stopping control server");  // will stop upon drop
+        self.daemon.events().close();
+        // the termination request and subscribers need some time to receive their responses before connections close
+        thread::sleep(SHUTDOWN_GRACE_PERIOD);
 
         struct ControlServerImpl {
             processor: Processor,
             stats: Option<Stats>,
-            stop_barrier: Arc<Barrier>,
+            events: Events,
+            access: ValidatedAccess,
+            watch: WatchMode,
+            // only used to request termination
+            daemon: super::Daemon,
+        }
+
+        impl ControlServerImpl {
+            fn authorize(&self, o: &::grpc::RequestOptions, required: Scope, method: &str) -> Result<(), RequestError> {
+                let token = o.metadata.get(auth::METADATA_KEY).and_then(auth::token_from_metadata);
+                daemon_auth::authorize(&self.access, token, required, method).map_err(|e| match e {
+                    // the command line client sends the token from the configured file
+                    RequestError::Unauthenticated(message) if token.is_none() => RequestError::Unauthenticated(
+                        format!("{}, set auth_token_file in the configuration", message).into()
+                    ),
+                    e => e,
+                })
+            }
+        }
+
+        // returns the failure from the request handler if the client may not call the method
+        macro_rules! authorize {
+            ($server:expr, $options:expr, $scope:expr, $method:expr) => {
+                if let Err(e) = $server.authorize(&$options, $scope, $method) {
+                    return error(e);
+                }
+            };
         }
 
         fn completed<T: Send + 'static>(t: T) -> ::grpc::SingleResponse<T> {
             ::grpc::SingleResponse::completed(t)
         }
 
-        fn error<T: protobuf::Message + 'static>(msg: String) -> ::grpc::SingleResponse<T> {
-            let mut md = ::grpc::Metadata::new();
-            md.add(::grpc::MetadataKey::from("error"), msg.into_bytes().into());
-            ::grpc::SingleResponse::completed_with_metadata(md, T::new())
+        // the failure is sent in trailers after the headers, which is where clients expect the status
+        fn error<T: Send + 'static, E: Into<Box<dyn Error>>>(e: E) -> ::grpc::SingleResponse<T> {
+            ::grpc::SingleResponse::no_metadata(future::err(grpc_error(e)))
+        }
+
+        fn grpc_error<E: Into<Box<dyn Error>>>(e: E) -> ::grpc::Error {
+            let e = e.into();
+            let status = error::status_of(&*e);
+            ::grpc::Error::GrpcMessage(::grpc::GrpcMessageError {
+                grpc_status: status as i32,
+                grpc_message: e.to_string(),
+            })
+        }
+
+        fn channel_name(channel: &str) -> Option<&str> {
+            if channel.is_empty() { None } else { Some(channel) }
+        }
+
+        // an empty image name means the images currently displayed in the channel
+        fn image_name(image: &str) -> Option<&str> {
+            if image.is_empty() { None } else { Some(image) }
         }
 
         impl Wcd for ControlServerImpl {
-            fn trigger_change(&self, _: ::grpc::RequestOptions, _: wcd::Empty) -> ::grpc::SingleResponse<wcd::Empty> {
-                match self.processor.trigger(true) {
+            fn trigger_change(&self, o: ::grpc::RequestOptions, c: wcd::ChannelName) -> ::grpc::SingleResponse<wcd::Empty> {
+                authorize!(self, o, Scope::Control, "TriggerChange");
+
+                match self.processor.trigger(channel_name(c.get_channel()), true) {
                     Ok(_) => completed(wcd::Empty::new()),
-                    Err(e) => error(e.to_string()),
+                    Err(e) => error(e),
                 }
             }
 
-            fn trigger_update(&self, _: ::grpc::RequestOptions, _: wcd::Empty) -> ::grpc::SingleResponse<wcd::Empty> {
-                self.processor.apply_current_image();
-                completed(wcd::Empty::new())
+            fn trigger_update(&self, o: ::grpc::RequestOptions, c: wcd::ChannelName) -> ::grpc::SingleResponse<wcd::Empty> {
+                authorize!(self, o, Scope::Control, "TriggerUpdate");
+
+                match self.processor.apply_current_image(channel_name(c.get_channel())) {
+                    Ok(_) => completed(wcd::Empty::new()),
+                    Err(e) => error(e),
+                }
             }
 
-            fn refresh_playlists(&self, _: ::grpc::RequestOptions, _: wcd::Empty) -> ::grpc::SingleResponse<wcd::Empty> {
+            fn refresh_playlists(&self, o: ::grpc::RequestOptions, _: wcd::Empty) -> ::grpc::SingleResponse<wcd::Empty> {
+                authorize!(self, o, Scope::Control, "RefreshPlaylists");
+
                 match self.processor.refresh_playlists(true) {
                     Ok(_) => completed(wcd::Empty::new()),
-                    Err(e) => error(e.to_string()),
+                    Err(e) => error(e),
                 }
             }
 
-            fn terminate(&self, _: ::grpc::RequestOptions, _: wcd::Empty) -> ::grpc::SingleResponse<wcd::Empty> {
-                self.stop_barrier.wait();
+            fn terminate(&self, o: ::grpc::RequestOptions, _: wcd::Empty) -> ::grpc::SingleResponse<wcd::Empty> {
+                authorize!(self, o, Scope::Admin, "Terminate");
+
+                self.daemon.terminate();
                 completed(wcd::Empty::new())
             }
 
-            fn get_status(&self, _: ::grpc::RequestOptions, _: wcd::Empty) -> ::grpc::SingleResponse<wcd::StatusInfo> {
-                match self.processor.get_status() {
+            fn get_status(&self, o: ::grpc::RequestOptions, c: wcd::ChannelName) -> ::grpc::SingleResponse<wcd::StatusInfo> {
+                authorize!(self, o, Scope::Read, "GetStatus");
+
+                match self.processor.get_status(channel_name(c.get_channel())) {
                     Ok(status_info) => completed(status_info.into()),
-                    Err(e) => error(e.to_string()),
+                    Err(e) => error(e),
                 }
             }
 
-            fn change_playlist(&self, _: ::grpc::RequestOptions, p: wcd::PlaylistName) -> ::grpc::SingleResponse<wcd::Empty> {
-                match self.processor.change_playlist(p.get_name()) {
+            fn change_playlist(&self, o: ::grpc::RequestOptions, p: wcd::PlaylistName) -> ::grpc::SingleResponse<wcd::Empty> {
+                authorize!(self, o, Scope::Control, "ChangePlaylist");
+
+                match self.processor.change_playlist(channel_name(p.get_channel()), p.get_name()) {
                     Ok(_) => completed(wcd::Empty::new()),
-                    Err(e) => error(e.to_string()),
+                    Err(e) => error(e),
                 }
             }
 
-            fn get_statistics(&self, _: ::grpc::RequestOptions, _: wcd::Empty) -> ::grpc::SingleResponse<wcd::StatsInfo> {
+            fn show_image(&self, o: ::grpc::RequestOptions, i: wcd::ImageName) -> ::grpc::SingleResponse<wcd::Empty> {
+                authorize!(self, o, Scope::Control, "ShowImage");
+
+                if i.get_image().is_empty() {
+                    return error(RequestError::InvalidArgument("Image must be specified".into()));
+                }
+
+                match self.processor.show_image(channel_name(i.get_channel()), i.get_image()) {
+                    Ok(_) => completed(wcd::Empty::new()),
+                    Err(e) => error(e),
+                }
+            }
+
+            fn show_previous(&self, o: ::grpc::RequestOptions, c: wcd::ChannelName) -> ::grpc::SingleResponse<wcd::Empty> {
+                authorize!(self, o, Scope::Control, "ShowPrevious");
+
+                match self.processor.show_previous(channel_name(c.get_channel())) {
+                    Ok(_) => completed(wcd::Empty::new()),
+                    Err(e) => error(e),
+                }
+            }
+
+            fn pause(&self, o: ::grpc::RequestOptions, c: wcd::ChannelName) -> ::grpc::SingleResponse<wcd::Empty> {
+                authorize!(self, o, Scope::Control, "Pause");
+
+                match self.processor.pause(channel_name(c.get_channel())) {
+                    Ok(_) => completed(wcd::Empty::new()),
+                    Err(e) => error(e),
+                }
+            }
+
+            fn resume(&self, o: ::grpc::RequestOptions, c: wcd::ChannelName) -> ::grpc::SingleResponse<wcd::Empty> {
+                authorize!(self, o, Scope::Control, "Resume");
+
+                match self.processor.resume(channel_name(c.get_channel())) {
+                    Ok(_) => completed(wcd::Empty::new()),
+                    Err(e) => error(e),
+                }
+            }
+
+            fn subscribe(&self, o: ::grpc::RequestOptions, _: wcd::Empty) -> ::grpc::StreamingResponse<wcd::Event> {
+                if let Err(e) = self.authorize(&o, Scope::Read, "Subscribe") {
+                    return ::grpc::StreamingResponse::no_metadata(stream::once(Err(grpc_error(e))));
+                }
+
+                // the receiver never fails, it only ends when the daemon shuts down
+                let events = self.events.subscribe()
+                    .map_err(|_| ::grpc::Error::Other("event subscription failed"));
+                ::grpc::StreamingResponse::no_metadata(events)
+            }
+
+            fn get_statistics(&self, o: ::grpc::RequestOptions, q: wcd::StatsQuery) -> ::grpc::SingleResponse<wcd::StatsInfo> {
+                authorize!(self, o, Scope::Read, "GetStatistics");
+
                 let stats = match self.stats {
                     Some(ref stats) => stats,
-                    None => return error("Statistics collection is disabled".into())
+                    None => return error(RequestError::stats_disabled())
+                };
+
+                let query: proto::StatsQuery = q.into();
+                let playlist_images = match query.playlist {
+                    Some(ref playlist) => match self.processor.playlist_images(playlist) {
+                        Ok(images) => Some(images),
+                        Err(e) => return error(e),
+                    },
+                    None => None,
                 };
 
                 match stats.load() {
                     Ok(image_stats) => {
+                        let image_stats = stats::select(image_stats, &query, playlist_images.as_ref());
                         let mut proto = wcd::StatsInfo::new();
                         proto.set_image_stats(::protobuf::RepeatedField::from_iter(
                             image_stats.into_iter().map(Into::into)
                         ));
+                        proto.set_dropped_writes(stats.dropped_writes());
                         completed(proto)
                     }
-                    Err(e) => error(e.to_string()),
+                    Err(e) => error(e),
                 }
             }
+
+            fn rebuild_statistics(&self, o: ::grpc::RequestOptions, _: wcd::Empty) -> ::grpc::SingleResponse<wcd::Empty> {
+                authorize!(self, o, Scope::Admin, "RebuildStatistics");
+
+                let stats = match self.stats {
+                    Some(ref stats) => stats,
+                    None => return error(RequestError::stats_disabled())
+                };
+
+                match stats.rebuild_aggregates() {
+                    Ok(_) => completed(wcd::Empty::new()),
+                    Err(e) => error(e),
+                }
+            }
+
+            fn relink_statistics(&self, o: ::grpc::RequestOptions, _: wcd::Empty) -> ::grpc::SingleResponse<wcd::RelinkResult> {
+                authorize!(self, o, Scope::Admin, "RelinkStatistics");
+
+                let stats = match self.stats {
+                    Some(ref stats) => stats,
+                    None => return error(RequestError::stats_disabled())
+                };
+
+                let result = match stats.relink(&self.processor.all_images()) {
+                    Ok(result) => result,
+                    Err(e) => return error(e),
+                };
+
+                // preferences may have moved to other files
+                match self.processor.refresh_playlists(false) {
+                    Ok(_) => completed(result.into()),
+                    Err(e) => error(e),
+                }
+            }
+
+            fn collect_statistics_garbage(&self, o: ::grpc::RequestOptions, q: wcd::GcQuery) -> ::grpc::SingleResponse<wcd::GcResult> {
+                authorize!(self, o, Scope::Admin, "CollectStatisticsGarbage");
+
+                let stats = match self.stats {
+                    Some(ref stats) => stats,
+                    None => return error(RequestError::stats_disabled())
+                };
+
+                let result = match stats.gc(q.get_dry_run()) {
+                    Ok(result) => result,
+                    Err(e) => return error(e),
+                };
+                if q.get_dry_run() {
+                    return completed(result.into());
+                }
+
+                // preferences and bans of missing files may have been removed
+                match self.processor.refresh_playlists(false) {
+                    Ok(_) => completed(result.into()),
+                    Err(e) => error(e),
+                }
+            }
+
+            fn rename_statistics_prefix(&self, o: ::grpc::RequestOptions, r: wcd::RenamePrefix) -> ::grpc::SingleResponse<wcd::RenameResult> {
+                authorize!(self, o, Scope::Admin, "RenameStatisticsPrefix");
+
+                let stats = match self.stats {
+                    Some(ref stats) => stats,
+                    None => return error(RequestError::stats_disabled())
+                };
+
+                if r.get_old_prefix().is_empty() || r.get_new_prefix().is_empty() {
+                    return error(RequestError::InvalidArgument("Path prefixes must not be empty".into()));
+                }
+
+                let result = match stats.rename_prefix(r.get_old_prefix(), r.get_new_prefix()) {
+                    Ok(result) => result,
+                    Err(e) => return error(e),
+                };
+
+                // preferences may have moved to other files
+                match self.processor.refresh_playlists(false) {
+                    Ok(_) => completed(result.into()),
+                    Err(e) => error(e),
+                }
+            }
+
+            fn export_statistics(&self, o: ::grpc::RequestOptions, _: wcd::Empty) -> ::grpc::SingleResponse<wcd::StatsExport> {
+                authorize!(self, o, Scope::Control, "ExportStatistics");
+
+                let stats = match self.stats {
+                    Some(ref stats) => stats,
+                    None => return error(RequestError::stats_disabled())
+                };
+
+                match stats.export() {
+                    Ok(data) => {
+                        let mut proto = wcd::StatsExport::new();
+                        proto.set_data(data);
+                        completed(proto)
+                    }
+                    Err(e) => error(e),
+                }
+            }
+
+            fn import_statistics(&self, o: ::grpc::RequestOptions, i: wcd::StatsImport) -> ::grpc::SingleResponse<wcd::ImportResult> {
+                authorize!(self, o, Scope::Admin, "ImportStatistics");
+
+                let stats = match self.stats {
+                    Some(ref stats) => stats,
+                    None => return error(RequestError::stats_disabled())
+                };
+
+                let result = match stats.import(i.get_data(), i.get_merge()) {
+                    Ok(result) => result,
+                    Err(e) => return error(e),
+                };
+
+                // imported preferences and bans may affect playlists
+                match self.processor.refresh_playlists(false) {
+                    Ok(_) => completed(result.into()),
+                    Err(e) => error(e),
+                }
+            }
+
+            fn get_report(&self, o: ::grpc::RequestOptions, q: wcd::ReportQuery) -> ::grpc::SingleResponse<wcd::Report> {
+                authorize!(self, o, Scope::Read, "GetReport");
+
+                let stats = match self.stats {
+                    Some(ref stats) => stats,
+                    None => return error(RequestError::stats_disabled())
+                };
+
+                match stats.report(&q.into()) {
+                    Ok(groups) => {
+                        let mut proto = wcd::Report::new();
+                        proto.set_groups(::protobuf::RepeatedField::from_iter(groups.into_iter().map(Into::into)));
+                        completed(proto)
+                    }
+                    Err(e) => error(e),
+                }
+            }
+
+            fn rate_image(&self, o: ::grpc::RequestOptions, r: wcd::ImageRating) -> ::grpc::SingleResponse<wcd::Empty> {
+                authorize!(self, o, Scope::Control, "RateImage");
+
+                match preferences::rate(
+                    &self.processor, self.stats.as_ref(), channel_name(r.get_channel()), image_name(r.get_image()), r.get_rating()
+                ) {
+                    Ok(_) => completed(wcd::Empty::new()),
+                    Err(e) => error(e),
+                }
+            }
+
+            fn set_favorite(&self, o: ::grpc::RequestOptions, f: wcd::ImageFavorite) -> ::grpc::SingleResponse<wcd::Empty> {
+                authorize!(self, o, Scope::Control, "SetFavorite");
+
+                match preferences::set_favorite(
+                    &self.processor, self.stats.as_ref(), channel_name(f.get_channel()), image_name(f.get_image()), f.get_favorite()
+                ) {
+                    Ok(_) => completed(wcd::Empty::new()),
+                    Err(e) => error(e),
+                }
+            }
+
+            fn ban_image(&self, o: ::grpc::RequestOptions, i: wcd::ImageName) -> ::grpc::SingleResponse<wcd::Empty> {
+                authorize!(self, o, Scope::Control, "BanImage");
+
+                match preferences::ban(&self.processor, self.stats.as_ref(), channel_name(i.get_channel()), image_name(i.get_image())) {
+                    Ok(_) => completed(wcd::Empty::new()),
+                    Err(e) => error(e),
+                }
+            }
+
+            fn unban_image(&self, o: ::grpc::RequestOptions, i: wcd::ImageName) -> ::grpc::SingleResponse<wcd::Empty> {
+                authorize!(self, o, Scope::Control, "UnbanImage");
+
+                match preferences::unban(&self.processor, self.stats.as_ref(), i.get_image()) {
+                    Ok(_) => completed(wcd::Empty::new()),
+                    Err(e) => error(e),
+                }
+            }
+
+            fn list_images(&self, o: ::grpc::RequestOptions, q: wcd::ListQuery) -> ::grpc::SingleResponse<wcd::ImageList> {
+                authorize!(self, o, Scope::Read, "ListImages");
+
+                match self.processor.list_images(&q.into()) {
+                    Ok(image_list) => completed(image_list.into()),
+                    Err(e) => error(e),
+                }
+            }
+
+            fn list_bans(&self, o: ::grpc::RequestOptions, _: wcd::Empty) -> ::grpc::SingleResponse<wcd::BanList> {
+                authorize!(self, o, Scope::Read, "ListBans");
+
+                let stats = match self.stats {
+                    Some(ref stats) => stats,
+                    None => return error(RequestError::stats_disabled())
+                };
+
+                match stats.load_bans() {
+                    Ok(bans) => {
+                        let mut proto = wcd::BanList::new();
+                        proto.set_bans(::protobuf::RepeatedField::from_iter(bans.into_iter().map(Into::into)));
+                        completed(proto)
+                    }
+                    Err(e) => error(e),
+                }
+            }
+
+            fn get_version(&self, o: ::grpc::RequestOptions, _: wcd::Empty) -> ::grpc::SingleResponse<wcd::VersionInfo> {
+                authorize!(self, o, Scope::Read, "GetVersion");
+
+                // the service descriptor lists exactly the methods implemented here
+                let rpcs = wcd::file_descriptor_proto().get_service().iter()
+                    .flat_map(|service| service.get_method())
+                    .map(|method| method.get_name().to_owned())
+                    .collect();
+                completed(proto::VersionInfo {
+                    version: env!("CARGO_PKG_VERSION").into(),
+                    protocol_version: proto::PROTOCOL_VERSION,
+                    stats_enabled: self.stats.is_some(),
+                    watch_interval: match self.watch {
+                        WatchMode::Poll(interval) => Some(interval.num_seconds()),
+                        WatchMode::Disabled => None,
+                    },
+                    rpcs,
+                }.into())
+            }
         }
     }
 }
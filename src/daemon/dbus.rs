@@ -0,0 +1,481 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::thread::{self, JoinHandle};
+
+use futures::Stream;
+use parking_lot::Mutex;
+use zbus::blocking::connection;
+use zbus::fdo;
+use zbus::message::Header;
+use zbus::names::{BusName, UniqueName};
+use zbus::object_server::SignalEmitter;
+use zbus::proxy::CacheProperties;
+use zbus::zvariant::{OwnedValue, Value};
+
+use crate::common::config::{Scope, ValidatedAccess, ValidatedDbus, WatchMode};
+use crate::common::proto::{self, DisplayReason, EventKind, ReportGrouping, ReportQuery, StatsQuery, StatsSort};
+use crate::daemon::auth as daemon_auth;
+use crate::daemon::error::RequestError;
+use crate::daemon::preferences;
+use crate::daemon::processor::Processor;
+use crate::daemon::stats::{self, Stats};
+
+const OBJECT_PATH: &str = "/io/github/netvl/Wcd";
+
+pub struct Dbus {
+    config: ValidatedDbus,
+    common_token: Option<String>,
+    access: ValidatedAccess,
+    watch: WatchMode,
+    daemon: super::Daemon,
+}
+
+impl Dbus {
+    pub fn new(config: ValidatedDbus, common_token: Option<String>, access: ValidatedAccess, watch: WatchMode,
+               daemon: super::Daemon) -> Dbus {
+        Dbus { config, common_token, access, watch, daemon, }
+    }
+
+    // like the HTTP thread, the D-Bus thread is not joined and stops along with the daemon process
+    pub fn start(self) -> JoinHandle<()> {
+        info!("Starting D-Bus thread");
+
+        thread::spawn(move || {
+            if let Err(e) = self.connect_and_loop() {
+                error!("D-Bus interface failed: {}", e);
+            }
+        })
+    }
+
+    fn connect_and_loop(&self) -> Result<(), Box<dyn Error>> {
+        let builder = match self.config.address {
+            Some(ref address) => connection::Builder::address(&**address)?,
+            None => connection::Builder::session()?,
+        };
+        let connection = builder.build()?;
+
+        // the bus tells the user of every caller, which is compared to the daemon's own user
+        let bus = fdo::DBusProxy::builder(connection.inner()).cache_properties(CacheProperties::No).build();
+        let bus = zbus::block_on(bus)?;
+        let owner_uid = match connection.unique_name() {
+            Some(name) => zbus::block_on(bus.get_connection_unix_user(name.as_ref().into())).ok(),
+            None => None,
+        };
+        let service = Service {
+            processor: self.daemon.processor(),
+            stats: self.daemon.stats(),
+            access: self.access.clone(),
+            common_token: self.common_token.clone(),
+            owner_uid,
+            sessions: Mutex::new(HashMap::new()),
+            bus,
+            watch: self.watch.clone(),
+            daemon: self.daemon.clone(),
+        };
+        // calls are handled in order, like the calls of the other interfaces; the name is only taken when the
+        // object is ready to answer them
+        connection.object_server().at(OBJECT_PATH, service)?;
+        connection.request_name(&*self.config.name)?;
+        info!("D-Bus interface is available as {}", self.config.name);
+
+        let service = connection.object_server().interface::<_, Service>(OBJECT_PATH)?;
+        let emitter = service.signal_emitter();
+
+        // the stream ends when the daemon shuts down
+        for event in self.daemon.events().subscribe().wait() {
+            let event: proto::Event = match event {
+                Ok(event) => event.into(),
+                Err(_) => break,
+            };
+            zbus::block_on(service.get().announce(emitter, event))?;
+        }
+
+        Ok(())
+    }
+}
+
+// failures have the same names as the gRPC status codes
+#[derive(Debug, zbus::DBusError)]
+#[zbus(prefix = "io.github.netvl.Wcd.Error")]
+enum CallError {
+    #[zbus(error)]
+    ZBus(zbus::Error),
+    InvalidArgument(String),
+    NotFound(String),
+    FailedPrecondition(String),
+    Unavailable(String),
+    Unauthenticated(String),
+    PermissionDenied(String),
+    Failed(String),
+}
+
+impl From<RequestError> for CallError {
+    fn from(e: RequestError) -> CallError {
+        let message = e.to_string();
+        match e {
+            RequestError::InvalidArgument(_) => CallError::InvalidArgument(message),
+            RequestError::NotFound(_) => CallError::NotFound(message),
+            RequestError::FailedPrecondition(_) => CallError::FailedPrecondition(message),
+            RequestError::Unavailable(_) => CallError::Unavailable(message),
+            RequestError::Unauthenticated(_) => CallError::Unauthenticated(message),
+            RequestError::PermissionDenied(_) => CallError::PermissionDenied(message),
+        }
+    }
+}
+
+impl From<Box<dyn Error>> for CallError {
+    fn from(e: Box<dyn Error>) -> CallError {
+        match e.downcast::<RequestError>() {
+            Ok(e) => (*e).into(),
+            Err(e) => CallError::Failed(e.to_string()),
+        }
+    }
+}
+
+// empty strings mean the default channel or the current images, like in the gRPC interface
+fn optional(value: &str) -> Option<&str> {
+    Some(value).filter(|s| !s.is_empty())
+}
+
+// `(path, displays, skips, display time, rating, favorite)`, unrated images have zero rating
+type ImageStatistics = (String, i64, i64, i64, u32, bool);
+
+struct Service {
+    processor: Processor,
+    stats: Option<Stats>,
+    access: ValidatedAccess,
+    common_token: Option<String>,
+    // `None` if the bus does not know users, then no caller is treated as the daemon's user
+    owner_uid: Option<u32>,
+    // tokens given by callers to `Authenticate`, by their unique bus names
+    sessions: Mutex<HashMap<String, String>>,
+    bus: fdo::DBusProxy<'static>,
+    watch: WatchMode,
+    // only used to request termination
+    daemon: super::Daemon,
+}
+
+impl Service {
+    fn stats(&self) -> Result<&Stats, CallError> {
+        self.stats.as_ref().ok_or_else(|| RequestError::stats_disabled().into())
+    }
+
+    // messages carry no metadata, so the token is the one the caller gave to `Authenticate`; processes of the
+    // daemon's user may read the common token file anyway, so they have the common token without authenticating
+    async fn authorize(&self, header: &Header<'_>, required: Scope, method: &str) -> Result<(), RequestError> {
+        let token = match header.sender() {
+            Some(sender) => {
+                let session_token = self.sessions.lock().get(sender.as_str()).cloned();
+                match session_token {
+                    Some(token) => Some(token),
+                    None if self.is_owner(sender).await => self.common_token.clone(),
+                    None => None,
+                }
+            }
+            None => None,
+        };
+        daemon_auth::authorize(&self.access, token.as_ref().map(|token| token.as_bytes()), required, method)
+    }
+
+    async fn is_owner(&self, sender: &UniqueName<'_>) -> bool {
+        match self.owner_uid {
+            Some(owner_uid) => {
+                let uid = self.bus.get_connection_unix_user(BusName::Unique(sender.clone())).await;
+                uid.ok() == Some(owner_uid)
+            }
+            None => false,
+        }
+    }
+
+    // properties are also read to announce their changes, without a caller
+    async fn authorize_property(&self, header: Option<Header<'_>>, property: &str) -> fdo::Result<()> {
+        let header = match header {
+            Some(header) => header,
+            None => return Ok(()),
+        };
+        self.authorize(&header, Scope::Read, property).await.map_err(|e| match e {
+            RequestError::Unauthenticated(message) => fdo::Error::AuthFailed(message.into_owned()),
+            other => fdo::Error::AccessDenied(other.to_string()),
+        })
+    }
+
+    // sends the signals for the event; properties describe the default channel
+    async fn announce(&self, emitter: &SignalEmitter<'_>, event: proto::Event) -> zbus::Result<()> {
+        let default_channel = match self.processor.get_status(None) {
+            Ok(status) => status.channel,
+            Err(_) => return Ok(()),
+        };
+        let channel = event.channel.unwrap_or_default();
+        let is_default = channel == default_channel;
+
+        match event.kind {
+            EventKind::ImageChanged { playlist, new_images, reason, .. } => {
+                Service::wallpaper_changed(emitter, &channel, &playlist, &new_images, reason_name(reason)).await?;
+                if is_default {
+                    self.current_image_changed(emitter).await?;
+                    self.current_images_changed(emitter).await?;
+                }
+            }
+            EventKind::PlaylistChanged { .. } if is_default => self.current_playlist_changed(emitter).await?,
+            EventKind::Paused | EventKind::Resumed if is_default => self.paused_changed(emitter).await?,
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+#[zbus::interface(name = "io.github.netvl.Wcd", spawn = false)]
+impl Service {
+    // the token is used for the following calls of the same connection
+    async fn authenticate(&self, #[zbus(header)] header: Header<'_>, token: &str) -> Result<(), CallError> {
+        let sender = header.sender()
+            .ok_or_else(|| CallError::InvalidArgument("Calls without a sender cannot be authenticated".into()))?;
+        daemon_auth::authorize(&self.access, Some(token.as_bytes()), Scope::Read, "Authenticate")?;
+
+        // tokens of connections which are gone are forgotten
+        let names: Vec<String> = self.sessions.lock().keys().cloned().collect();
+        for name in names {
+            let gone = match BusName::try_from(&*name) {
+                Ok(bus_name) => !self.bus.name_has_owner(bus_name).await.unwrap_or(true),
+                Err(_) => true,
+            };
+            if gone {
+                self.sessions.lock().remove(&name);
+            }
+        }
+        self.sessions.lock().insert(sender.to_string(), token.to_owned());
+        Ok(())
+    }
+
+    async fn trigger_change(&self, #[zbus(header)] header: Header<'_>, channel: &str) -> Result<(), CallError> {
+        self.authorize(&header, Scope::Control, "TriggerChange").await?;
+        Ok(self.processor.trigger(optional(channel), true)?)
+    }
+
+    async fn trigger_update(&self, #[zbus(header)] header: Header<'_>, channel: &str) -> Result<(), CallError> {
+        self.authorize(&header, Scope::Control, "TriggerUpdate").await?;
+        Ok(self.processor.apply_current_image(optional(channel))?)
+    }
+
+    async fn refresh_playlists(&self, #[zbus(header)] header: Header<'_>) -> Result<(), CallError> {
+        self.authorize(&header, Scope::Control, "RefreshPlaylists").await?;
+        Ok(self.processor.refresh_playlists(true)?)
+    }
+
+    async fn change_playlist(
+        &self, #[zbus(header)] header: Header<'_>, channel: &str, playlist: &str,
+    ) -> Result<(), CallError> {
+        self.authorize(&header, Scope::Control, "ChangePlaylist").await?;
+        Ok(self.processor.change_playlist(optional(channel), playlist)?)
+    }
+
+    async fn show_image(
+        &self, #[zbus(header)] header: Header<'_>, channel: &str, image: &str,
+    ) -> Result<(), CallError> {
+        self.authorize(&header, Scope::Control, "ShowImage").await?;
+        match optional(image) {
+            Some(image) => Ok(self.processor.show_image(optional(channel), image)?),
+            None => Err(CallError::InvalidArgument("Image must be specified".into())),
+        }
+    }
+
+    async fn show_previous(&self, #[zbus(header)] header: Header<'_>, channel: &str) -> Result<(), CallError> {
+        self.authorize(&header, Scope::Control, "ShowPrevious").await?;
+        Ok(self.processor.show_previous(optional(channel))?)
+    }
+
+    async fn pause(&self, #[zbus(header)] header: Header<'_>, channel: &str) -> Result<(), CallError> {
+        self.authorize(&header, Scope::Control, "Pause").await?;
+        Ok(self.processor.pause(optional(channel))?)
+    }
+
+    async fn resume(&self, #[zbus(header)] header: Header<'_>, channel: &str) -> Result<(), CallError> {
+        self.authorize(&header, Scope::Control, "Resume").await?;
+        Ok(self.processor.resume(optional(channel))?)
+    }
+
+    #[zbus(out_args("status"))]
+    async fn get_status(
+        &self, #[zbus(header)] header: Header<'_>, channel: &str,
+    ) -> Result<HashMap<&'static str, OwnedValue>, CallError> {
+        self.authorize(&header, Scope::Read, "GetStatus").await?;
+        let channel = optional(channel);
+        let status = self.processor.get_status(channel)?;
+        let current_images = self.processor.current_images(channel)?;
+        let mut playlists: Vec<_> = status.playlists.keys().cloned().collect();
+        playlists.sort();
+
+        Ok(dict(vec![
+            ("channel", Value::from(status.channel)),
+            ("channels", Value::from(status.channels)),
+            ("current_playlist", Value::from(status.current_playlist)),
+            ("playlists", Value::from(playlists)),
+            ("current_images", Value::from(current_images)),
+            ("paused", Value::from(status.paused)),
+            ("last_update", Value::from(status.last_update)),
+        ]))
+    }
+
+    #[zbus(out_args("images"))]
+    async fn list_images(
+        &self, #[zbus(header)] header: Header<'_>, channel: &str, playlist: &str, upcoming: bool,
+    ) -> Result<Vec<String>, CallError> {
+        self.authorize(&header, Scope::Read, "ListImages").await?;
+        let query = proto::ListQuery {
+            channel: optional(channel).map(ToOwned::to_owned),
+            playlist: optional(playlist).map(ToOwned::to_owned),
+            upcoming,
+        };
+        let list = self.processor.list_images(&query)?;
+        Ok(list.images.into_iter().map(|image| image.path).collect())
+    }
+
+    #[zbus(out_args("images"))]
+    async fn get_statistics(
+        &self, #[zbus(header)] header: Header<'_>, playlist: &str, sort: &str, top: u32,
+    ) -> Result<Vec<ImageStatistics>, CallError> {
+        self.authorize(&header, Scope::Read, "GetStatistics").await?;
+        let stats = self.stats()?;
+        let query = StatsQuery {
+            playlist: optional(playlist).map(ToOwned::to_owned),
+            sort: match optional(sort) {
+                None => StatsSort::Displays,
+                Some(sort) => StatsSort::from_name(sort)
+                    .ok_or_else(|| CallError::InvalidArgument(format!("Unknown sort order {}", sort)))?,
+            },
+            top: if top == 0 { None } else { Some(top) },
+            missing_only: false,
+        };
+
+        let playlist_images = match query.playlist {
+            Some(ref playlist) => Some(self.processor.playlist_images(playlist)?),
+            None => None,
+        };
+        Ok(stats::select(stats.load()?, &query, playlist_images.as_ref()).into_iter()
+            .map(|image| (
+                image.filename, image.stats.total_displays, image.stats.total_skips, image.stats.total_display_time,
+                image.rating.unwrap_or(0) as u32, image.favorite,
+            ))
+            .collect())
+    }
+
+    // groups are `(name, display time, displays, skips)`; zero `since` means all the time
+    #[zbus(out_args("groups"))]
+    async fn get_report(
+        &self, #[zbus(header)] header: Header<'_>, group_by: &str, since: i64,
+    ) -> Result<Vec<(String, i64, i64, i64)>, CallError> {
+        self.authorize(&header, Scope::Read, "GetReport").await?;
+        let stats = self.stats()?;
+        let query = ReportQuery {
+            since: if since == 0 { None } else { Some(since) },
+            group_by: match optional(group_by) {
+                None => ReportGrouping::Playlist,
+                Some(group_by) => ReportGrouping::from_name(group_by)
+                    .ok_or_else(|| CallError::InvalidArgument(format!("Unknown grouping {}", group_by)))?,
+            },
+        };
+
+        Ok(stats.report(&query)?.into_iter()
+            .map(|group| (group.name, group.display_time, group.displays, group.skips))
+            .collect())
+    }
+
+    // `watch_interval` is only present if playlists are rescanned periodically
+    #[zbus(out_args("version"))]
+    async fn get_version(
+        &self, #[zbus(header)] header: Header<'_>,
+    ) -> Result<HashMap<&'static str, OwnedValue>, CallError> {
+        self.authorize(&header, Scope::Read, "GetVersion").await?;
+        let mut version = vec![
+            ("version", Value::from(env!("CARGO_PKG_VERSION"))),
+            ("protocol_version", Value::from(proto::PROTOCOL_VERSION)),
+            ("stats_enabled", Value::from(self.stats.is_some())),
+        ];
+        if let WatchMode::Poll(interval) = self.watch {
+            version.push(("watch_interval", Value::from(interval.num_seconds())));
+        }
+        Ok(dict(version))
+    }
+
+    async fn rate_image(
+        &self, #[zbus(header)] header: Header<'_>, channel: &str, image: &str, rating: u32,
+    ) -> Result<(), CallError> {
+        self.authorize(&header, Scope::Control, "RateImage").await?;
+        Ok(preferences::rate(&self.processor, self.stats.as_ref(), optional(channel), optional(image), rating)?)
+    }
+
+    async fn set_favorite(
+        &self, #[zbus(header)] header: Header<'_>, channel: &str, image: &str, favorite: bool,
+    ) -> Result<(), CallError> {
+        self.authorize(&header, Scope::Control, "SetFavorite").await?;
+        let stats = self.stats.as_ref();
+        Ok(preferences::set_favorite(&self.processor, stats, optional(channel), optional(image), favorite)?)
+    }
+
+    async fn ban_image(&self, #[zbus(header)] header: Header<'_>, channel: &str, image: &str) -> Result<(), CallError> {
+        self.authorize(&header, Scope::Control, "BanImage").await?;
+        Ok(preferences::ban(&self.processor, self.stats.as_ref(), optional(channel), optional(image))?)
+    }
+
+    async fn unban_image(&self, #[zbus(header)] header: Header<'_>, image: &str) -> Result<(), CallError> {
+        self.authorize(&header, Scope::Control, "UnbanImage").await?;
+        Ok(preferences::unban(&self.processor, self.stats.as_ref(), image)?)
+    }
+
+    async fn terminate(&self, #[zbus(header)] header: Header<'_>) -> Result<(), CallError> {
+        self.authorize(&header, Scope::Admin, "Terminate").await?;
+        self.daemon.terminate();
+        Ok(())
+    }
+
+    #[zbus(signal)]
+    async fn wallpaper_changed(
+        emitter: &SignalEmitter<'_>, channel: &str, playlist: &str, images: &[String], reason: &str,
+    ) -> zbus::Result<()>;
+
+    #[zbus(property)]
+    async fn current_image(&self, #[zbus(header)] header: Option<Header<'_>>) -> fdo::Result<String> {
+        self.authorize_property(header, "CurrentImage").await?;
+        Ok(self.processor.current_images(None).map_err(property_failed)?.into_iter().next().unwrap_or_default())
+    }
+
+    #[zbus(property)]
+    async fn current_images(&self, #[zbus(header)] header: Option<Header<'_>>) -> fdo::Result<Vec<String>> {
+        self.authorize_property(header, "CurrentImages").await?;
+        self.processor.current_images(None).map_err(property_failed)
+    }
+
+    #[zbus(property)]
+    async fn current_playlist(&self, #[zbus(header)] header: Option<Header<'_>>) -> fdo::Result<String> {
+        self.authorize_property(header, "CurrentPlaylist").await?;
+        self.processor.get_status(None).map(|status| status.current_playlist).map_err(property_failed)
+    }
+
+    #[zbus(property)]
+    async fn paused(&self, #[zbus(header)] header: Option<Header<'_>>) -> fdo::Result<bool> {
+        self.authorize_property(header, "Paused").await?;
+        self.processor.get_status(None).map(|status| status.paused).map_err(property_failed)
+    }
+}
+
+fn property_failed(e: Box<dyn Error>) -> fdo::Error {
+    fdo::Error::Failed(e.to_string())
+}
+
+// values of `a{sv}` dictionaries never contain file descriptors, so they are always converted
+fn dict(entries: Vec<(&'static str, Value<'_>)>) -> HashMap<&'static str, OwnedValue> {
+    entries.into_iter()
+        .map(|(key, value)| (key, value.try_to_owned().expect("Value without file descriptors")))
+        .collect()
+}
+
+// the same names as in the JSON events of `wcd watch`
+fn reason_name(reason: DisplayReason) -> &'static str {
+    match reason {
+        DisplayReason::Scheduled => "scheduled",
+        DisplayReason::Manual => "manual",
+        DisplayReason::Select => "select",
+        DisplayReason::Restore => "restore",
+    }
+}
@@ -0,0 +1,80 @@
+use std::borrow::Cow;
+use std::error::Error;
+
+use grpc::GrpcStatus;
+
+quick_error! {
+    // failures caused by the request rather than by the daemon, reported to clients with a matching status
+    #[derive(Debug)]
+    pub enum RequestError {
+        InvalidArgument(msg: Cow<'static, str>) {
+            description("invalid argument")
+            display("{}", msg)
+        }
+        NotFound(msg: Cow<'static, str>) {
+            description("not found")
+            display("{}", msg)
+        }
+        FailedPrecondition(msg: Cow<'static, str>) {
+            description("failed precondition")
+            display("{}", msg)
+        }
+        Unavailable(msg: Cow<'static, str>) {
+            description("unavailable")
+            display("{}", msg)
+        }
+        Unauthenticated(msg: Cow<'static, str>) {
+            description("unauthenticated")
+            display("{}", msg)
+        }
+        PermissionDenied(msg: Cow<'static, str>) {
+            description("permission denied")
+            display("{}", msg)
+        }
+    }
+}
+
+impl RequestError {
+    pub fn stats_disabled() -> RequestError {
+        RequestError::Unavailable("Statistics collection is disabled".into())
+    }
+
+    pub fn status(&self) -> GrpcStatus {
+        match *self {
+            RequestError::InvalidArgument(_) => GrpcStatus::Argument,
+            RequestError::NotFound(_) => GrpcStatus::NotFound,
+            RequestError::FailedPrecondition(_) => GrpcStatus::FailedPrecondition,
+            RequestError::Unavailable(_) => GrpcStatus::Unavailable,
+            RequestError::Unauthenticated(_) => GrpcStatus::Unauthenticated,
+            RequestError::PermissionDenied(_) => GrpcStatus::PermissionDenied,
+        }
+    }
+
+    #[cfg(feature = "http")]
+    pub fn http_status(&self) -> u16 {
+        match *self {
+            RequestError::InvalidArgument(_) => 400,
+            RequestError::NotFound(_) => 404,
+            RequestError::FailedPrecondition(_) => 409,
+            RequestError::Unavailable(_) => 503,
+            RequestError::Unauthenticated(_) => 401,
+            RequestError::PermissionDenied(_) => 403,
+        }
+    }
+}
+
+// any other error is a failure of the daemon itself
+pub fn status_of(e: &(dyn Error + 'static)) -> GrpcStatus {
+    match e.downcast_ref::<RequestError>() {
+        Some(e) => e.status(),
+        None => GrpcStatus::Internal,
+    }
+}
+
+#[cfg(feature = "http")]
+pub fn http_status_of(e: &(dyn Error + 'static)) -> u16 {
+    match e.downcast_ref::<RequestError>() {
+        Some(e) => e.http_status(),
+        None => 500,
+    }
+}
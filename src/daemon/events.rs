@@ -0,0 +1,151 @@
+use chrono::Utc;
+use futures::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use crate::common::grpc::wcd;
+use crate::daemon::stats::DisplayReason;
+
+// changes in the daemon which are reported to subscribed clients
+#[derive(Debug, Clone)]
+pub enum Event {
+    ImageChanged {
+        channel: String,
+        playlist: String,
+        old_images: Vec<String>,
+        new_images: Vec<String>,
+        reason: DisplayReason,
+    },
+    PlaylistChanged {
+        channel: String,
+        old_playlist: String,
+        new_playlist: String,
+    },
+    PlaylistsRefreshed {
+        added: u64,
+        removed: u64,
+    },
+    Paused {
+        channel: String,
+    },
+    Resumed {
+        channel: String,
+    },
+    CommandFailed {
+        channel: String,
+        playlist: String,
+        images: Vec<String>,
+    },
+    ShuttingDown,
+}
+
+impl Into<wcd::Event> for Event {
+    fn into(self) -> wcd::Event {
+        let mut proto = wcd::Event::new();
+        proto.set_timestamp(Utc::now().timestamp());
+        match self {
+            Event::ImageChanged { channel, playlist, old_images, new_images, reason } => {
+                let mut image_changed = wcd::ImageChanged::new();
+                image_changed.set_playlist(playlist);
+                image_changed.set_old_images(::protobuf::RepeatedField::from_vec(old_images));
+                image_changed.set_new_images(::protobuf::RepeatedField::from_vec(new_images));
+                image_changed.set_reason(reason.into());
+                proto.set_channel(channel);
+                proto.set_image_changed(image_changed);
+            }
+            Event::PlaylistChanged { channel, old_playlist, new_playlist } => {
+                let mut playlist_changed = wcd::PlaylistChanged::new();
+                playlist_changed.set_old_playlist(old_playlist);
+                playlist_changed.set_new_playlist(new_playlist);
+                proto.set_channel(channel);
+                proto.set_playlist_changed(playlist_changed);
+            }
+            Event::PlaylistsRefreshed { added, removed } => {
+                let mut playlists_refreshed = wcd::PlaylistsRefreshed::new();
+                playlists_refreshed.set_added(added);
+                playlists_refreshed.set_removed(removed);
+                proto.set_playlists_refreshed(playlists_refreshed);
+            }
+            Event::Paused { channel } => {
+                proto.set_channel(channel);
+                proto.set_paused(wcd::Empty::new());
+            }
+            Event::Resumed { channel } => {
+                proto.set_channel(channel);
+                proto.set_resumed(wcd::Empty::new());
+            }
+            Event::CommandFailed { channel, playlist, images } => {
+                let mut command_failed = wcd::CommandFailed::new();
+                command_failed.set_playlist(playlist);
+                command_failed.set_images(::protobuf::RepeatedField::from_vec(images));
+                proto.set_channel(channel);
+                proto.set_command_failed(command_failed);
+            }
+            Event::ShuttingDown => proto.set_shutting_down(wcd::Empty::new()),
+        }
+        proto
+    }
+}
+
+pub struct State {
+    subscribers: Vec<UnboundedSender<wcd::Event>>,
+    // no subscriptions are accepted after the daemon has started shutting down
+    closed: bool,
+}
+
+impl State {
+    pub fn new() -> State {
+        State {
+            subscribers: Vec::new(),
+            closed: false,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Events {
+    daemon: super::Daemon,
+}
+
+impl Events {
+    pub fn new(daemon: super::Daemon) -> Events {
+        Events { daemon, }
+    }
+
+    // the stream ends when the daemon shuts down
+    pub fn subscribe(&self) -> UnboundedReceiver<wcd::Event> {
+        let state = self.daemon.state.lock();
+        let mut events = state.events.borrow_mut();
+
+        let (sender, receiver) = mpsc::unbounded();
+        if !events.closed {
+            debug!("Adding event subscriber, {} subscribers in total", events.subscribers.len() + 1);
+            events.subscribers.push(sender);
+        }
+        receiver
+    }
+
+    pub fn emit(&self, event: Event) {
+        let state = self.daemon.state.lock();
+        let mut events = state.events.borrow_mut();
+
+        if events.subscribers.is_empty() {
+            return;
+        }
+
+        trace!("Emitting event {:?}", event);
+        let proto: wcd::Event = event.into();
+        // subscribers which have disconnected are forgotten
+        events.subscribers.retain(|s| s.unbounded_send(proto.clone()).is_ok());
+    }
+
+    // reports the shutdown to subscribers and ends their streams; returns false if there were no subscribers
+    pub fn close(&self) -> bool {
+        self.emit(Event::ShuttingDown);
+
+        let state = self.daemon.state.lock();
+        let mut events = state.events.borrow_mut();
+        let had_subscribers = !events.subscribers.is_empty();
+        events.subscribers.clear();
+        events.closed = true;
+        had_subscribers
+    }
+}
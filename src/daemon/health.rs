@@ -0,0 +1,84 @@
+use futures::{future, stream, Stream};
+
+use crate::common::grpc::health::{HealthCheckRequest, HealthCheckResponse, HealthCheckResponse_ServingStatus};
+use crate::common::grpc::health_grpc::Health;
+use crate::daemon::error::RequestError;
+
+// the empty name stands for the daemon as a whole
+const SERVICES: &[&str] = &["", "wcd.Wcd"];
+
+// health checks are answered without authentication, as they only reveal whether the daemon works
+pub struct HealthServerImpl {
+    daemon: super::Daemon,
+}
+
+impl HealthServerImpl {
+    pub fn new(daemon: super::Daemon) -> HealthServerImpl {
+        HealthServerImpl { daemon, }
+    }
+}
+
+// the daemon does not serve if it cannot change images in some channel or cannot record statistics
+fn serving_status(daemon: &super::Daemon, service: &str) -> HealthCheckResponse_ServingStatus {
+    if !SERVICES.contains(&service) {
+        return HealthCheckResponse_ServingStatus::SERVICE_UNKNOWN;
+    }
+    if daemon.stats_failed() {
+        debug!("Not serving, the statistics storage failed to open");
+        return HealthCheckResponse_ServingStatus::NOT_SERVING;
+    }
+    let empty_channels = daemon.processor().empty_channels();
+    if !empty_channels.is_empty() {
+        debug!("Not serving, current playlists of channels {} have no images", empty_channels.join(", "));
+        return HealthCheckResponse_ServingStatus::NOT_SERVING;
+    }
+    HealthCheckResponse_ServingStatus::SERVING
+}
+
+fn response(status: HealthCheckResponse_ServingStatus) -> HealthCheckResponse {
+    let mut response = HealthCheckResponse::new();
+    response.set_status(status);
+    response
+}
+
+impl Health for HealthServerImpl {
+    fn check(&self, _: ::grpc::RequestOptions, r: HealthCheckRequest) -> ::grpc::SingleResponse<HealthCheckResponse> {
+        match serving_status(&self.daemon, r.get_service()) {
+            HealthCheckResponse_ServingStatus::SERVICE_UNKNOWN => {
+                let e = RequestError::NotFound(format!("Unknown service {}", r.get_service()).into());
+                ::grpc::SingleResponse::no_metadata(future::err(::grpc::Error::GrpcMessage(::grpc::GrpcMessageError {
+                    grpc_status: e.status() as i32,
+                    grpc_message: e.to_string(),
+                })))
+            }
+            status => ::grpc::SingleResponse::completed(response(status)),
+        }
+    }
+
+    // the status is sent again whenever an event changes it, and the stream ends with NOT_SERVING when the
+    // daemon shuts down
+    fn watch(&self, _: ::grpc::RequestOptions, r: HealthCheckRequest) -> ::grpc::StreamingResponse<HealthCheckResponse> {
+        // subscribing before checking the status makes sure that no change is missed in between
+        let events = self.daemon.events().subscribe();
+
+        let daemon = self.daemon.clone();
+        let service = r.service;
+        let status = serving_status(&daemon, &service);
+        let mut last_status = status;
+        let updates = events
+            .map(move |event| if event.has_shutting_down() {
+                HealthCheckResponse_ServingStatus::NOT_SERVING
+            } else {
+                serving_status(&daemon, &service)
+            })
+            .filter(move |&status| {
+                let changed = status != last_status;
+                last_status = status;
+                changed
+            })
+            .map(response)
+            .map_err(|_| ::grpc::Error::Other("event subscription failed"));
+
+        ::grpc::StreamingResponse::no_metadata(stream::once(Ok(response(status))).chain(updates))
+    }
+}
@@ -0,0 +1,422 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+
+use serde::Serialize;
+use tiny_http::{Header, Server, SslConfig};
+
+use crate::common::auth;
+use crate::common::config::{Scope, ValidatedAccess, ValidatedTls};
+use crate::common::grpc::wcd;
+use crate::common::proto::{self, StatsQuery, StatsSort};
+use crate::daemon::auth as daemon_auth;
+use crate::daemon::error::{self, RequestError};
+use crate::daemon::processor::Processor;
+use crate::daemon::stats::{self, Stats};
+
+const INDEX_PAGE: &str = include_str!("index.html");
+
+// requests are handled by a fixed number of workers, so that many clients cannot exhaust the daemon's threads
+const WORKER_THREADS: usize = 4;
+
+pub struct Http {
+    listen: SocketAddr,
+    tls: Option<ValidatedTls>,
+    access: ValidatedAccess,
+    daemon: super::Daemon,
+}
+
+impl Http {
+    pub fn new(listen: SocketAddr, tls: Option<ValidatedTls>, access: ValidatedAccess, daemon: super::Daemon) -> Http {
+        Http { listen, tls, access, daemon, }
+    }
+
+    // the thread is not joined, it stops along with the daemon process
+    pub fn start(self) -> JoinHandle<()> {
+        info!("Starting HTTP thread");
+
+        thread::spawn(move || self.listen_and_loop())
+    }
+
+    fn listen_and_loop(&self) {
+        // the page and the API are served over HTTPS with the certificate of the control server
+        let server = match self.tls {
+            Some(ref tls) => ssl_config(tls).and_then(|config| Server::https(self.listen, config)),
+            None => Server::http(self.listen),
+        };
+        let server = match server {
+            Ok(server) => Arc::new(server),
+            Err(e) => {
+                error!("Binding the HTTP server to {} failed: {}", self.listen, e);
+                return;
+            }
+        };
+
+        info!("HTTP server started on {}", self.listen);
+        let workers: Vec<_> = (0..WORKER_THREADS)
+            .map(|_| {
+                let server = server.clone();
+                let handler = Handler {
+                    processor: self.daemon.processor(),
+                    stats: self.daemon.stats(),
+                    access: self.access.clone(),
+                };
+                thread::spawn(move || handler.serve(&server))
+            })
+            .collect();
+        for worker in workers {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn ssl_config(tls: &ValidatedTls) -> Result<SslConfig, Box<dyn Error + Send + Sync>> {
+    Ok(SslConfig {
+        certificate: fs::read(&tls.cert_file)
+            .map_err(|e| format!("cannot read certificate from {}: {}", tls.cert_file.display(), e))?,
+        private_key: fs::read(&tls.key_file)
+            .map_err(|e| format!("cannot read private key from {}: {}", tls.key_file.display(), e))?,
+    })
+}
+
+struct Request {
+    method: String,
+    path: String,
+    query: HashMap<String, String>,
+    // names are in lower case
+    headers: HashMap<String, String>,
+}
+
+impl Request {
+    fn from_http(request: &tiny_http::Request) -> Request {
+        let target = request.url();
+        let (path, query) = match target.find('?') {
+            Some(idx) => (&target[..idx], &target[idx + 1..]),
+            None => (target, ""),
+        };
+        let query = query.split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| match pair.find('=') {
+                Some(idx) => (percent_decode(&pair[..idx]), percent_decode(&pair[idx + 1..])),
+                None => (percent_decode(pair), String::new()),
+            })
+            .collect();
+        let headers = request.headers().iter()
+            .map(|header| (header.field.as_str().as_str().to_ascii_lowercase(), header.value.as_str().to_owned()))
+            .collect();
+
+        Request {
+            method: request.method().as_str().to_owned(),
+            path: percent_decode(path),
+            query,
+            headers,
+        }
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(|value| &**value)
+    }
+
+    fn token(&self) -> Option<&[u8]> {
+        self.header(auth::METADATA_KEY).and_then(|value| auth::token_from_metadata(value.as_bytes()))
+    }
+
+    // empty parameters are the same as missing ones
+    fn param(&self, name: &str) -> Option<&str> {
+        self.query.get(name).map(|value| &**value).filter(|value| !value.is_empty())
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        match self.param(name) {
+            Some(value) => value == "true" || value == "1",
+            None => false,
+        }
+    }
+}
+
+// `+` means a space as in forms; the page encodes literal plus signs in parameters as `%2B`
+fn percent_decode(s: &str) -> String {
+    fn hex(b: u8) -> Option<u8> {
+        match b {
+            b'0'..=b'9' => Some(b - b'0'),
+            b'a'..=b'f' => Some(b - b'a' + 10),
+            b'A'..=b'F' => Some(b - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    let bytes = s.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' if i + 2 < bytes.len() => {
+                match (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+                    (Some(hi), Some(lo)) => {
+                        result.push(hi << 4 | lo);
+                        i += 3;
+                        continue;
+                    }
+                    _ => result.push(b'%'),
+                }
+            }
+            b'+' => result.push(b' '),
+            b => result.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&result).into_owned()
+}
+
+struct Response {
+    status: u16,
+    content_type: &'static str,
+    body: Vec<u8>,
+}
+
+#[derive(Serialize)]
+struct ErrorBody<'a> {
+    error: &'a str,
+}
+
+#[derive(Serialize)]
+struct EmptyBody {}
+
+impl Response {
+    fn json<T: Serialize>(value: &T) -> Response {
+        match serde_json::to_vec(value) {
+            Ok(body) => Response { status: 200, content_type: "application/json", body, },
+            Err(e) => Response::error(500, &format!("Failed to serialize the response: {}", e)),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Response {
+        Response {
+            status,
+            content_type: "application/json",
+            // serializing a string never fails
+            body: serde_json::to_vec(&ErrorBody { error: message, }).unwrap(),
+        }
+    }
+
+    fn into_http(self) -> tiny_http::Response<io::Cursor<Vec<u8>>> {
+        // the content types are constant and valid header values
+        let content_type = Header::from_bytes(&b"Content-Type"[..], self.content_type.as_bytes()).unwrap();
+        tiny_http::Response::from_data(self.body)
+            .with_status_code(self.status)
+            .with_header(content_type)
+    }
+}
+
+// browsers send requests to other sites along with forms and links, so requests coming from other pages are refused;
+// requests of scripts and command line tools carry neither header
+fn check_same_origin(request: &Request) -> Result<(), RequestError> {
+    let cross_site = match request.header("sec-fetch-site") {
+        Some(site) => site != "same-origin" && site != "none",
+        None => false,
+    };
+    let cross_origin = match request.header("origin") {
+        Some(origin) => {
+            let host = origin.trim_start_matches("http://").trim_start_matches("https://");
+            origin == host || !request.header("host").is_some_and(|expected| host.eq_ignore_ascii_case(expected))
+        }
+        None => false,
+    };
+    if cross_site || cross_origin {
+        return Err(RequestError::PermissionDenied("Cross-origin requests are not allowed".into()));
+    }
+    Ok(())
+}
+
+// HTML forms can only send these content types, so POST requests with them are refused
+fn is_form_content(request: &Request) -> bool {
+    let content_type = match request.header("content-type") {
+        Some(content_type) => content_type.split(';').next().unwrap_or("").trim().to_ascii_lowercase(),
+        None => return false,
+    };
+    matches!(&*content_type, "application/x-www-form-urlencoded" | "multipart/form-data" | "text/plain")
+}
+
+fn image_content_type(path: &Path) -> &'static str {
+    let extension = path.extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    match &*extension {
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "bmp" => "image/bmp",
+        "svg" => "image/svg+xml",
+        _ => "application/octet-stream",
+    }
+}
+
+type HandlerResult = Result<Response, Box<dyn Error>>;
+
+struct Handler {
+    processor: Processor,
+    stats: Option<Stats>,
+    access: ValidatedAccess,
+}
+
+impl Handler {
+    fn serve(&self, server: &Server) {
+        for http_request in server.incoming_requests() {
+            let request = Request::from_http(&http_request);
+            debug!("Received HTTP request {} {}", request.method, request.path);
+            let response = self.handle(&request)
+                .unwrap_or_else(|e| Response::error(error::http_status_of(&*e), &e.to_string()));
+            if let Err(e) = http_request.respond(response.into_http()) {
+                debug!("Sending HTTP response failed: {}", e);
+            }
+        }
+    }
+
+    fn handle(&self, request: &Request) -> HandlerResult {
+        // the page itself holds no data, the token is entered there and sent along with API requests
+        if request.path == "/" {
+            return Ok(match &*request.method {
+                "GET" => Response {
+                    status: 200,
+                    content_type: "text/html; charset=utf-8",
+                    body: INDEX_PAGE.as_bytes().to_vec(),
+                },
+                _ => Response::error(405, "Method not allowed"),
+            });
+        }
+
+        let required = match &*request.method {
+            "GET" => Scope::Read,
+            "POST" => Scope::Control,
+            _ => return Ok(Response::error(405, "Method not allowed")),
+        };
+        check_same_origin(request)?;
+        if request.method == "POST" && is_form_content(request) {
+            return Ok(Response::error(415, "Form submissions are not accepted"));
+        }
+        daemon_auth::authorize(&self.access, request.token(), required, &format!("{} {}", request.method, request.path))?;
+
+        let channel = request.param("channel");
+        match (&*request.method, &*request.path) {
+            ("GET", "/api/status") => Ok(Response::json(&self.processor.get_status(channel)?)),
+            ("POST", "/api/trigger") => {
+                self.processor.trigger(channel, true)?;
+                Ok(Response::json(&EmptyBody {}))
+            }
+            ("POST", "/api/playlist") => {
+                let name = request.param("name")
+                    .ok_or_else(|| RequestError::InvalidArgument("Playlist name must be specified".into()))?;
+                self.processor.change_playlist(channel, name)?;
+                Ok(Response::json(&EmptyBody {}))
+            }
+            ("GET", "/api/images") => {
+                let query = proto::ListQuery {
+                    channel: channel.map(ToOwned::to_owned),
+                    playlist: request.param("playlist").map(ToOwned::to_owned),
+                    upcoming: request.flag("upcoming"),
+                };
+                Ok(Response::json(&self.processor.list_images(&query)?))
+            }
+            ("GET", "/api/stats") => self.statistics(request),
+            ("GET", "/api/image") => self.image(request),
+            (_, "/api/status") | (_, "/api/trigger") | (_, "/api/playlist") | (_, "/api/images") |
+            (_, "/api/stats") | (_, "/api/image") => Ok(Response::error(405, "Method not allowed")),
+            _ => Err(RequestError::NotFound("Requested page does not exist".into()).into()),
+        }
+    }
+
+    fn statistics(&self, request: &Request) -> HandlerResult {
+        let stats = self.stats.as_ref().ok_or_else(RequestError::stats_disabled)?;
+
+        let sort = match request.param("sort") {
+            None | Some("displays") => StatsSort::Displays,
+            Some("skips") => StatsSort::Skips,
+            Some("time") => StatsSort::DisplayTime,
+            Some("name") => StatsSort::Filename,
+            Some(other) => return Err(RequestError::InvalidArgument(format!("Unknown sort order {}", other).into()).into()),
+        };
+        let top = match request.param("top") {
+            Some(top) => Some(top.parse().map_err(|_| RequestError::InvalidArgument("Invalid number of images".into()))?),
+            None => None,
+        };
+        let query = StatsQuery {
+            playlist: request.param("playlist").map(ToOwned::to_owned),
+            sort,
+            top,
+            missing_only: false,
+        };
+
+        let playlist_images = match query.playlist {
+            Some(ref playlist) => Some(self.processor.playlist_images(playlist)?),
+            None => None,
+        };
+        let image_stats = stats::select(stats.load()?, &query, playlist_images.as_ref());
+
+        Ok(Response::json(&proto::StatsInfo {
+            image_stats: image_stats.into_iter()
+                .map(|image| {
+                    let proto: wcd::ImageStatsInfo = image.into();
+                    proto.into()
+                })
+                .collect(),
+            dropped_writes: stats.dropped_writes(),
+        }))
+    }
+
+    fn image(&self, request: &Request) -> HandlerResult {
+        let path = request.param("path")
+            .map(Path::new)
+            .ok_or_else(|| RequestError::InvalidArgument("Image path must be specified".into()))?;
+
+        // only images of playlists are served, never arbitrary files
+        if !self.processor.all_images().iter().any(|image| image == path) {
+            return Err(RequestError::NotFound("Specified image does not belong to any playlist".into()).into());
+        }
+
+        Ok(Response {
+            status: 200,
+            content_type: image_content_type(path),
+            body: fs::read(path)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(method: &str, headers: &[(&str, &str)]) -> Request {
+        Request {
+            method: method.into(),
+            path: "/api/trigger".into(),
+            query: HashMap::new(),
+            headers: headers.iter().map(|&(name, value)| (name.into(), value.into())).collect(),
+        }
+    }
+
+    #[test]
+    fn cross_origin_requests_are_rejected() {
+        assert!(check_same_origin(&request("POST", &[("host", "wcd:8080")])).is_ok());
+        assert!(check_same_origin(&request("POST", &[("host", "wcd:8080"), ("origin", "http://wcd:8080")])).is_ok());
+        assert!(check_same_origin(&request("GET", &[("sec-fetch-site", "same-origin")])).is_ok());
+
+        assert!(check_same_origin(&request("POST", &[("host", "wcd:8080"), ("origin", "http://evil:8080")])).is_err());
+        assert!(check_same_origin(&request("POST", &[("host", "wcd:8080"), ("origin", "null")])).is_err());
+        assert!(check_same_origin(&request("GET", &[("sec-fetch-site", "cross-site")])).is_err());
+    }
+
+    #[test]
+    fn form_content_is_detected() {
+        assert!(!is_form_content(&request("POST", &[])));
+        assert!(!is_form_content(&request("POST", &[("content-type", "application/json")])));
+        assert!(is_form_content(&request("POST", &[("content-type", "application/x-www-form-urlencoded")])));
+        assert!(is_form_content(&request("POST", &[("content-type", "Multipart/Form-Data; boundary=x")])));
+        assert!(is_form_content(&request("POST", &[("content-type", "text/plain;charset=UTF-8")])));
+    }
+}
@@ -1,17 +1,33 @@
+use std::fmt;
+use std::net::SocketAddr;
 use std::path::Path;
 use std::process;
 use std::sync::Arc;
 use std::cell::RefCell;
+use std::thread;
 
 use chrono::Duration;
-use parking_lot::ReentrantMutex;
+use parking_lot::{Condvar, Mutex, ReentrantMutex};
+use signal_hook::consts::{SIGINT, SIGTERM};
+use signal_hook::iterator::Signals;
 
 use crate::common::config;
 
 pub mod stats;
+mod auth;
 mod control;
+#[cfg(feature = "dbus")]
+mod dbus;
+mod error;
+mod events;
+mod health;
+#[cfg(feature = "http")]
+mod http;
+mod preferences;
 mod scheduler;
 mod processor;
+#[cfg(test)]
+mod test_util;
 
 pub fn main(config_path: &Path) {
     let config = config::load(&config_path).unwrap_or_else(|e| {
@@ -22,45 +38,28 @@ pub fn main(config_path: &Path) {
     debug!("Loaded configuration: {:#?}", config);
 
     let endpoint = config.common.endpoint;
+    let tls = config.common.tls;
+    let auth_token = config.common.auth_token;
+    let access = config.server.access.clone();
+    if let (Some(scope), false) = (access.anonymous_scope, is_loopback(&endpoint)) {
+        warn!("Control server is reachable from other hosts and gives the {} scope to requests without a token", scope);
+    }
     let watch_mode = config.server.watch.clone();
-    let config = config.server;
-
-    let stats_state = match config.stats_db.as_ref() {
-        Some(p) => match stats::State::new(p) {
-            Ok(state) => {
-                info!("Collecting statistics is enabled, statistics database is {}", p.display());
-                Some(state)
-            },
-            Err(e) => {
-                warn!("Failed to initialize the statistics database in {}: {}", p.display(), e);
-                None
-            }
-        },
-        None => {
-            info!("Collecting statistics is disabled");
-            None
-        },
-    };
-    let scheduler_state = scheduler::State::new();
-    let processor_state = processor::State::new(config);
-
-    let daemon = Daemon {
-        state: Arc::new(ReentrantMutex::new(State {
-            processor: RefCell::new(processor_state),
-            scheduler: RefCell::new(scheduler_state),
-            stats: stats_state.map(RefCell::new),
-        }))
-    };
+    let http_listen = config.server.http;
+    let dbus_config = config.server.dbus.clone();
 
-    let control = control::Control::new(endpoint, daemon.clone());
+    let daemon = Daemon::new(config.server);
+    let control = control::Control::new(endpoint, tls.clone(), access.clone(), watch_mode.clone(), daemon.clone());
 
-    {
+    for channel in daemon.processor().channel_names() {
         let d = daemon.clone();
-        daemon.scheduler().prepare(processor::TRIGGER_JOB_NAME, move || {
+        let job_name = processor::trigger_job_name(&channel);
+        daemon.scheduler().prepare(&job_name, move || {
             let daemon = d.clone();
+            let channel = channel.clone();
             move || {
-                if let Err(_) = daemon.processor().trigger(false) {
-                    warn!("Failed to run scheduled wallpaper change");
+                if let Err(_) = daemon.processor().trigger(Some(&channel), false) {
+                    warn!("Failed to run scheduled wallpaper change in channel {}", channel);
                 }
             }
         });
@@ -81,40 +80,169 @@ pub fn main(config_path: &Path) {
 
     if daemon.stats().is_some() {
         let d = daemon.clone();
-        daemon.scheduler().prepare(processor::UPDATE_STATS_JOB_NAME, move || {
+        daemon.scheduler().prepare(processor::CHECKPOINT_STATS_JOB_NAME, move || {
             let daemon = d.clone();
             move || {
-                if let Err(_) = daemon.processor().update_stats() {
-                    warn!("Failed to update statistics");
+                if let Err(_) = daemon.processor().checkpoint_stats() {
+                    warn!("Failed to save statistics checkpoint");
                 }
             }
         });
         daemon.scheduler().schedule(
-            processor::UPDATE_STATS_JOB_NAME,
-            Duration::seconds(processor::UPDATE_STATS_INTERVAL_SECS)
+            processor::CHECKPOINT_STATS_JOB_NAME,
+            Duration::seconds(processor::CHECKPOINT_STATS_INTERVAL_SECS)
         );
     }
 
     daemon.processor().start();
     let scheduler_handle = daemon.scheduler().start();
     let control_handle = control.start();
+    if let Some(listen) = http_listen {
+        start_http(listen, tls, access.clone(), daemon.clone());
+    }
+    if let Some(dbus_config) = dbus_config {
+        start_dbus(dbus_config, auth_token, access.clone(), watch_mode, daemon.clone());
+    }
+    handle_signals(daemon.clone());
 
     let _ = control_handle.join();
     let _ = scheduler_handle.join();
+
+    daemon.processor().shutdown();
+    if let Some(stats) = daemon.stats() {
+        stats.stop_writer();
+    }
+}
+
+// termination signals stop the daemon the same way as a termination request, so pending statistics are
+// written; a second signal exits right away
+fn handle_signals(daemon: Daemon) {
+    let mut signals = match Signals::new([SIGTERM, SIGINT]) {
+        Ok(signals) => signals,
+        Err(e) => {
+            warn!("Failed to install signal handlers: {}", e);
+            return;
+        }
+    };
+
+    thread::spawn(move || {
+        let mut signals = signals.forever();
+        if let Some(signal) = signals.next() {
+            info!("Received signal {}, terminating", signal);
+            daemon.terminate();
+        }
+        if let Some(signal) = signals.next() {
+            warn!("Received signal {} again, exiting without waiting for the shutdown", signal);
+            process::exit(1);
+        }
+    });
+}
+
+// endpoints which are not socket addresses, like host names, are assumed to be reachable from other hosts
+fn is_loopback(endpoint: &str) -> bool {
+    match endpoint.parse::<SocketAddr>() {
+        Ok(addr) => addr.ip().is_loopback(),
+        Err(_) => endpoint.starts_with("localhost:"),
+    }
+}
+
+#[cfg(feature = "http")]
+fn start_http(listen: SocketAddr, tls: Option<config::ValidatedTls>, access: config::ValidatedAccess, daemon: Daemon) {
+    http::Http::new(listen, tls, access, daemon).start();
+}
+
+#[cfg(not(feature = "http"))]
+fn start_http(_listen: SocketAddr, _tls: Option<config::ValidatedTls>, _access: config::ValidatedAccess, _daemon: Daemon) {
+    warn!("HTTP API is configured, but wcd was built without HTTP support");
+}
+
+#[cfg(feature = "dbus")]
+fn start_dbus(config: config::ValidatedDbus, common_token: Option<String>, access: config::ValidatedAccess,
+              watch_mode: config::WatchMode, daemon: Daemon) {
+    dbus::Dbus::new(config, common_token, access, watch_mode, daemon).start();
+}
+
+#[cfg(not(feature = "dbus"))]
+fn start_dbus(_config: config::ValidatedDbus, _common_token: Option<String>, _access: config::ValidatedAccess,
+              _watch_mode: config::WatchMode, _daemon: Daemon) {
+    warn!("D-Bus interface is configured, but wcd was built without D-Bus support");
 }
 
 struct State {
     processor: RefCell<processor::State>,
     scheduler: RefCell<scheduler::State>,
-    stats: Option<RefCell<stats::State>>,
+    stats_writer: Option<RefCell<stats::Writer>>,
+    // whether the configured statistics storage failed to open
+    stats_failed: bool,
+    events: RefCell<events::State>,
+}
+
+// kept outside of the daemon state, so the control thread can wait for it without holding the state lock
+#[derive(Default)]
+struct Termination {
+    requested: Mutex<bool>,
+    condvar: Condvar,
 }
 
 #[derive(Clone)]
 pub struct Daemon {
     state: Arc<ReentrantMutex<State>>,
+    // kept outside of the daemon state as well, since some operations on statistics take long
+    stats: Option<Arc<Mutex<stats::State>>>,
+    termination: Arc<Termination>,
 }
 
 impl Daemon {
+    // opens the statistics and scans the playlists; nothing is started yet
+    fn new(config: config::ValidatedServerConfig) -> Daemon {
+        let (stats_state, stats_writer) = match config.stats.as_ref() {
+            Some(storage) => match stats::State::new(storage) {
+                Ok((state, writer)) => {
+                    info!("Collecting statistics is enabled, statistics are stored in {}", StorageDisplay(storage));
+                    (Some(state), Some(writer))
+                },
+                Err(e) => {
+                    warn!("Failed to initialize the statistics storage in {}: {}", StorageDisplay(storage), e);
+                    (None, None)
+                }
+            },
+            None => {
+                info!("Collecting statistics is disabled");
+                (None, None)
+            },
+        };
+        // the daemon keeps working without statistics, but reports itself as unhealthy
+        let stats_failed = config.stats.is_some() && stats_state.is_none();
+        let scheduler_state = scheduler::State::new();
+        let preferences = stats_writer.as_ref().map(|w| w.preferences()).unwrap_or_default();
+        let processor_state = processor::State::new(config, &preferences);
+
+        Daemon {
+            state: Arc::new(ReentrantMutex::new(State {
+                processor: RefCell::new(processor_state),
+                scheduler: RefCell::new(scheduler_state),
+                stats_writer: stats_writer.map(RefCell::new),
+                stats_failed,
+                events: RefCell::new(events::State::new()),
+            })),
+            stats: stats_state.map(|s| Arc::new(Mutex::new(s))),
+            termination: Arc::new(Termination::default()),
+        }
+    }
+
+    // asks the control thread to stop the daemon; does not wait for it
+    fn terminate(&self) {
+        *self.termination.requested.lock() = true;
+        self.termination.condvar.notify_all();
+    }
+
+    fn wait_for_termination(&self) {
+        let mut requested = self.termination.requested.lock();
+        while !*requested {
+            self.termination.condvar.wait(&mut requested);
+        }
+    }
+
     fn scheduler(&self) -> scheduler::Scheduler {
         scheduler::Scheduler::new(self.clone())
     }
@@ -123,8 +251,27 @@ impl Daemon {
         processor::Processor::new(self.clone())
     }
 
+    fn events(&self) -> events::Events {
+        events::Events::new(self.clone())
+    }
+
     fn stats(&self) -> Option<stats::Stats> {
-        self.state.lock().stats.as_ref()
-            .map(|_| stats::Stats::new(self.clone()))
+        self.stats.as_ref().map(|_| stats::Stats::new(self.clone()))
+    }
+
+    fn stats_failed(&self) -> bool {
+        self.state.lock().stats_failed
+    }
+}
+
+struct StorageDisplay<'a>(&'a config::StatsStorage);
+
+impl<'a> fmt::Display for StorageDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self.0 {
+            config::StatsStorage::Sqlite(ref path) => write!(f, "SQLite database {}", path.display()),
+            config::StatsStorage::JsonLines(ref path) => write!(f, "JSON lines file {}", path.display()),
+            config::StatsStorage::Memory => f.write_str("memory"),
+        }
     }
 }
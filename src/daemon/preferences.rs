@@ -0,0 +1,81 @@
+use std::error::Error;
+
+use chrono::Utc;
+
+use crate::daemon::error::RequestError;
+use crate::daemon::processor::Processor;
+use crate::daemon::stats::{self, Stats};
+
+// changes of image preferences requested by clients; `image` is `None` for the images currently
+// displayed in the channel
+
+type Result<T> = ::std::result::Result<T, Box<dyn Error>>;
+
+// zero removes the rating
+pub fn rate(processor: &Processor, stats: Option<&Stats>,
+            channel: Option<&str>, image: Option<&str>, rating: u32) -> Result<()> {
+    let rating = match rating {
+        0 => None,
+        rating if rating >= stats::MIN_RATING.into() && rating <= stats::MAX_RATING.into() => Some(rating as u8),
+        _ => return Err(RequestError::InvalidArgument(format!(
+            "Rating must be between {} and {}", stats::MIN_RATING, stats::MAX_RATING
+        ).into()).into()),
+    };
+
+    update(processor, stats, channel, image, |stats, image| stats.set_rating(image, rating))?;
+    Ok(())
+}
+
+pub fn set_favorite(processor: &Processor, stats: Option<&Stats>,
+                    channel: Option<&str>, image: Option<&str>, favorite: bool) -> Result<()> {
+    update(processor, stats, channel, image, |stats, image| stats.set_favorite(image, favorite))?;
+    Ok(())
+}
+
+pub fn ban(processor: &Processor, stats: Option<&Stats>, channel: Option<&str>, image: Option<&str>) -> Result<()> {
+    let now = Utc::now();
+    let images = update(processor, stats, channel, image, |stats, image| stats.ban(image, now))?;
+
+    // banned images must not stay on screen
+    processor.replace_displayed_images(&images)
+}
+
+pub fn unban(processor: &Processor, stats: Option<&Stats>, image: &str) -> Result<()> {
+    let stats = stats.ok_or_else(RequestError::stats_disabled)?;
+
+    if !stats.unban(image)? {
+        return Err(RequestError::NotFound("Specified image is not banned".into()).into());
+    }
+
+    processor.refresh_playlists(false)
+}
+
+fn target_images(processor: &Processor, channel: Option<&str>, image: Option<&str>) -> Result<Vec<String>> {
+    if let Some(image) = image {
+        return Ok(vec![image.to_owned()]);
+    }
+
+    match processor.current_images(channel) {
+        Ok(ref images) if images.is_empty() =>
+            Err(RequestError::FailedPrecondition("No image is currently displayed".into()).into()),
+        result => result,
+    }
+}
+
+// returns the updated images
+fn update<F>(processor: &Processor, stats: Option<&Stats>,
+             channel: Option<&str>, image: Option<&str>, f: F) -> Result<Vec<String>>
+    where F: Fn(&Stats, &str) -> stats::Result<()>
+{
+    let stats = stats.ok_or_else(RequestError::stats_disabled)?;
+
+    let images = target_images(processor, channel, image)?;
+    for image in &images {
+        f(stats, image)?;
+    }
+
+    // playlists may include or exclude images depending on their preferences
+    processor.refresh_playlists(false)?;
+
+    Ok(images)
+}
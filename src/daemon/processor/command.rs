@@ -1,72 +1,165 @@
 use std::process::Command;
 use std::fmt::Write;
 use std::ffi::OsStr;
+use std::path::Path;
 
 use lazy_scoped::Lazy;
 
 use crate::daemon::processor::playlist::Image;
-use crate::common::config::ValidatedPlaylist;
+use crate::common::config::{ValidatedPlaylist, ValidatedOverride, ImageMatcher, CommandArg};
 
 pub struct ChangeCommand<'a> {
-    name: &'a str,
-    args: &'a [String]
+    config: &'a ValidatedPlaylist,
 }
 
 impl<'a> ChangeCommand<'a> {
     pub fn from_config(config: &'a ValidatedPlaylist) -> ChangeCommand<'a> {
-        ChangeCommand {
-            name: &config.command,
-            args: &config.command_args
+        ChangeCommand { config, }
+    }
+
+    // returns the override used for each of the images
+    pub fn overrides_for(&self, images: &[Image]) -> Vec<Option<&'a ValidatedOverride>> {
+        if images.is_empty() {
+            return Vec::new();
+        }
+
+        if self.config.command_args.contains(&CommandArg::Output) {
+            images.iter().map(|image| find_override(self.config, &image.path)).collect()
+        } else {
+            // the command is run once, so the first image determines the override for all of them
+            vec![find_override(self.config, &images[0].path); images.len()]
         }
     }
 
-    pub fn execute(&self, image: &Image) -> bool {
+    pub fn execute(&self, images: &[Image]) -> bool {
         // race condition, but whatever
-        if !image.check() {
+        if !images.iter().all(Image::check) {
+            return false;
+        }
+
+        let overrides = self.overrides_for(images);
+        for (image, ovr) in images.iter().zip(&overrides) {
+            if let Some(ovr) = ovr {
+                debug!("Using command override {} for {}", ovr.name, image.path.display());
+            }
+        }
+
+        if self.config.command_args.contains(&CommandArg::Output) {
+            // one invocation per output
+            for (idx, (image, ovr)) in images.iter().zip(overrides).enumerate() {
+                let (name, args, fit) = self.resolve(ovr);
+                let output = self.config.outputs.name(idx);
+                let args: Vec<&OsStr> = args.iter()
+                    .map(|a| match *a {
+                        CommandArg::Literal(ref s) => s.as_ref(),
+                        CommandArg::Path | CommandArg::IndexedPath(_) => image.path.as_os_str(),
+                        CommandArg::Output => output.as_ref(),
+                        CommandArg::Fit => fit.as_ref(),
+                    })
+                    .collect();
+                run(name, &args);
+            }
+        } else {
+            let (name, args, fit) = self.resolve(overrides[0]);
+            let args: Vec<&OsStr> = args.iter()
+                .map(|a| match *a {
+                    CommandArg::Literal(ref s) => s.as_ref(),
+                    CommandArg::Path => images[0].path.as_os_str(),
+                    CommandArg::IndexedPath(idx) => images[idx].path.as_os_str(),
+                    CommandArg::Output => unreachable!(),
+                    CommandArg::Fit => fit.as_ref(),
+                })
+                .collect();
+            run(name, &args);
+        }
+
+        true
+    }
+
+    // returns command name, arguments and fit mode, taking the override into account
+    fn resolve(&self, ovr: Option<&'a ValidatedOverride>) -> (&'a str, &'a [CommandArg], &'a str) {
+        let (name, args) = match ovr.and_then(|o| o.command.as_ref()) {
+            Some((name, args)) => (name, args),
+            None => (&self.config.command, &self.config.command_args),
+        };
+        // configuration validation ensures that fit mode is set if it is used
+        let fit = ovr.and_then(|o| o.fit.as_ref())
+            .or(self.config.fit.as_ref())
+            .map(|f| f.as_str())
+            .unwrap_or("");
+        (name, args, fit)
+    }
+}
+
+pub fn find_override<'a>(config: &'a ValidatedPlaylist, path: &Path) -> Option<&'a ValidatedOverride> {
+    config.overrides.iter().find(|o| matches(&o.matcher, path))
+}
+
+fn matches(matcher: &ImageMatcher, path: &Path) -> bool {
+    if let Some(ref glob) = matcher.glob {
+        if !glob.matches_path(path) {
             return false;
         }
+    }
+
+    if let Some(ref extension) = matcher.extension {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.to_lowercase() == *extension => {}
+            _ => return false,
+        }
+    }
+
+    if let Some(aspect) = matcher.aspect {
+        match imagesize::size(path) {
+            Ok(size) if size.height > 0 => if !aspect.matches(size.width, size.height) {
+                return false;
+            },
+            Ok(_) => return false,
+            Err(e) => {
+                debug!("Failed to determine dimensions of image {}: {}", path.display(), e);
+                return false;
+            }
+        }
+    }
 
-        let args: Vec<&OsStr> = self.args.iter()
-            .map(|a| if a == "{}" { image.path.as_os_str() } else { a.as_ref() })
-            .collect();
+    true
+}
 
-        let command_str = Lazy::new(|| {
-            let mut result_str = if self.name.contains(char::is_whitespace) {
-                format!("\"{}\"", self.name)
+fn run(name: &str, args: &[&OsStr]) {
+    let command_str = Lazy::new(|| {
+        let mut result_str = if name.contains(char::is_whitespace) {
+            format!("\"{}\"", name)
+        } else {
+            name.to_owned()
+        };
+
+        for a in args {
+            let s = a.to_string_lossy();
+            let _ = if s.contains(char::is_whitespace) {
+                write!(&mut result_str, " \"{}\"", a.to_string_lossy())
             } else {
-                self.name.to_owned()
+                write!(&mut result_str, " {}", a.to_string_lossy())
             };
+        }
 
-            for a in &args {
-                let s = a.to_string_lossy();
-                let _ = if s.contains(char::is_whitespace) {
-                    write!(&mut result_str, " \"{}\"", a.to_string_lossy())
-                } else {
-                    write!(&mut result_str, " {}", a.to_string_lossy())
-                };
-            }
+        result_str
+    });
+
+    debug!("Executing command: {}", *command_str);
 
-            result_str
-        });
-
-        debug!("Executing command: {}", *command_str);
-
-        let status = Command::new(&self.name).args(&args).status();
-        match status {
-            Ok(ref status) if status.success() => {},
-            status => {
-                match status {
-                    Ok(status) => match status.code() {
-                        Some(code) =>
-                            warn!("Command '{}' has exited with code {}", *command_str, code),
-                        None =>
-                            warn!("Command '{}' has exited without status code", *command_str),
-                    },
-                    Err(e) => warn!("Failed to start command '{}': {}", *command_str, e),
-                }
+    let status = Command::new(name).args(args).status();
+    match status {
+        Ok(ref status) if status.success() => {},
+        status => {
+            match status {
+                Ok(status) => match status.code() {
+                    Some(code) =>
+                        warn!("Command '{}' has exited with code {}", *command_str, code),
+                    None =>
+                        warn!("Command '{}' has exited without status code", *command_str),
+                },
+                Err(e) => warn!("Failed to start command '{}': {}", *command_str, e),
             }
         }
-
-        true
     }
 }
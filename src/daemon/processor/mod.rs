@@ -1,35 +1,81 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::cmp;
 use std::error::Error;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 
 use crate::common::proto;
-use crate::common::config::ValidatedServerConfig;
+use crate::common::util;
+use crate::common::config::{ValidatedServerConfig, ValidatedChannel, ChangeMode};
 use crate::daemon::processor::playlist::Playlist;
-use crate::daemon::stats::Stats;
+use crate::daemon::error::RequestError;
+use crate::daemon::events::Event;
+use crate::daemon::stats::{DisplayContext, DisplayReason, EventId, Preferences};
 
 mod playlist;
 mod command;
 
 pub const TRIGGER_JOB_NAME: &'static str = "trigger";
 pub const REFRESH_JOB_NAME: &'static str = "refresh";
-pub const UPDATE_STATS_JOB_NAME: &'static str = "update_stats";
+pub const CHECKPOINT_STATS_JOB_NAME: &'static str = "checkpoint_stats";
 
-pub const UPDATE_STATS_INTERVAL_SECS: i64 = 5;
+pub const CHECKPOINT_STATS_INTERVAL_SECS: i64 = 60;
 pub const SKIP_INTERVAL_SECS: i64 = 10;
 
 pub type ProcessorResult<T> = Result<T, Box<dyn Error>>;
 
+// each channel has its own trigger job
+pub fn trigger_job_name(channel: &str) -> String {
+    format!("{}:{}", TRIGGER_JOB_NAME, channel)
+}
+
 pub struct State {
+    channels: Vec<Channel>,
+    channel_indices: HashMap<String, usize>,
+    default_channel: usize,
+}
+
+impl State {
+    pub fn new(config: ValidatedServerConfig, preferences: &Preferences) -> State {
+        // will contain runtime channels
+        let mut channels = Vec::new();
+        // channel name -> channel index in the above vector
+        let mut channel_indices = HashMap::new();
+
+        for (name, channel) in config.channels {
+            channel_indices.insert(name.clone(), channels.len());
+            channels.push(Channel::new(name, channel, preferences));
+        }
+
+        // always unwraps
+        let default_channel = channel_indices[&config.default_channel];
+        if channels.len() > 1 {
+            info!("Default channel is {}", config.default_channel);
+        }
+
+        State { channels, channel_indices, default_channel, }
+    }
+}
+
+struct Channel {
+    name: String,
     playlists: Vec<Playlist>,
     playlist_indices: HashMap<String, usize>,
     current_playlist: usize,
     last_trigger_time: Option<DateTime<Utc>>,
-    last_used_image: Option<String>,
+    // images which were applied last, i.e. which are currently on screen
+    displayed: Vec<DisplayedImage>,
+    // the moment since which the display time of the above images is not accounted yet
+    displayed_since: Option<DateTime<Utc>>,
+    // scheduled changes are skipped while the channel is paused
+    paused: bool,
 }
 
-impl State {
-    pub fn new(config: ValidatedServerConfig) -> State {
+impl Channel {
+    fn new(name: String, config: ValidatedChannel, preferences: &Preferences) -> Channel {
         // will contain runtime playlists
         let mut playlists = Vec::new();
         // playlist name -> playlist index in the above vector
@@ -45,26 +91,48 @@ impl State {
             scanned_playlists += 1;
 
             playlist_indices.insert(name, playlists.len());
-            playlists.push(Playlist::from_config(playlist));
+            playlists.push(Playlist::from_config(playlist, preferences));
         }
 
         // obtain final bits of statistics and print them
         let total_files: usize = playlists.iter().map(|pl| pl.len()).sum();
         info!(
-            "Scanned {} files and {} directories across {} playlists, found {} valid image files",
-            scanned_files, scanned_directories, scanned_playlists, total_files
+            "Scanned {} files and {} directories across {} playlists in channel {}, found {} valid image files",
+            scanned_files, scanned_directories, scanned_playlists, name, total_files
         );
 
         // set the default playlist
         // always unwraps
         let current_playlist = playlist_indices[&config.default_playlist];
-        info!("Current playlist is {}", config.default_playlist);
+        info!("Current playlist in channel {} is {}", name, config.default_playlist);
 
-        State {
-            playlists, playlist_indices, current_playlist,
-            last_trigger_time: None, last_used_image: None,
+        Channel {
+            name, playlists, playlist_indices, current_playlist,
+            last_trigger_time: None, displayed: Vec::new(), displayed_since: None, paused: false,
         }
     }
+
+    fn current_playlist(&self) -> &Playlist {
+        &self.playlists[self.current_playlist]
+    }
+
+    fn current_playlist_mut(&mut self) -> &mut Playlist {
+        &mut self.playlists[self.current_playlist]
+    }
+
+    fn current_playlist_name(&self) -> &str {
+        // always unwraps
+        self.playlist_indices.iter()
+            .find(|&(_, idx)| *idx == self.current_playlist)
+            .unwrap().0
+    }
+}
+
+#[derive(Clone)]
+struct DisplayedImage {
+    path: String,
+    // the statistics event of this display, if statistics are enabled
+    event: Option<EventId>,
 }
 
 #[derive(Clone)]
@@ -77,103 +145,482 @@ impl Processor {
         Processor { daemon, }
     }
 
-    fn with_actions<F, T>(&self, f: F) -> T where F: FnOnce(StateActions) -> T {
+    // `channel` is the name of the channel to act on, or `None` for the default channel
+    fn with_actions<F, T>(&self, channel: Option<&str>, f: F) -> ProcessorResult<T>
+        where F: FnOnce(StateActions) -> ProcessorResult<T>
+    {
         let state = self.daemon.state.lock();
         let mut processor_state = state.processor.borrow_mut();
+
+        let channel = match channel {
+            Some(name) => match processor_state.channel_indices.get(name) {
+                Some(&idx) => idx,
+                None => {
+                    warn!("Requested channel {} does not exist", name);
+                    return Err(RequestError::NotFound("Specified channel does not exist".into()).into());
+                }
+            },
+            None => processor_state.default_channel,
+        };
+
         f(StateActions {
             state: &mut *processor_state,
+            channel,
             daemon: self.daemon.clone(),
         })
     }
 
+    fn with_all_actions<F>(&self, mut f: F) -> ProcessorResult<()>
+        where F: FnMut(StateActions) -> ProcessorResult<()>
+    {
+        let state = self.daemon.state.lock();
+        let mut processor_state = state.processor.borrow_mut();
+
+        for channel in 0..processor_state.channels.len() {
+            f(StateActions {
+                state: &mut *processor_state,
+                channel,
+                daemon: self.daemon.clone(),
+            })?;
+        }
+
+        Ok(())
+    }
+
+    pub fn channel_names(&self) -> Vec<String> {
+        let state = self.daemon.state.lock();
+        let processor_state = state.processor.borrow();
+        processor_state.channels.iter().map(|c| c.name.clone()).collect()
+    }
+
+    // names of the channels whose current playlist has no images
+    pub fn empty_channels(&self) -> Vec<String> {
+        let state = self.daemon.state.lock();
+        let processor_state = state.processor.borrow();
+        processor_state.channels.iter()
+            .filter(|c| c.current_playlist().images().is_empty())
+            .map(|c| c.name.clone())
+            .collect()
+    }
+
+    // paths of all images of the given playlist across all channels which have it
+    pub fn playlist_images(&self, playlist_name: &str) -> ProcessorResult<HashSet<String>> {
+        let state = self.daemon.state.lock();
+        let processor_state = state.processor.borrow();
+
+        let mut found = false;
+        let mut result = HashSet::new();
+        for channel in &processor_state.channels {
+            if let Some(&idx) = channel.playlist_indices.get(playlist_name) {
+                found = true;
+                result.extend(
+                    channel.playlists[idx].images().iter()
+                        .map(|image| image.path.to_string_lossy().into_owned())
+                );
+            }
+        }
+
+        if found {
+            Ok(result)
+        } else {
+            warn!("Requested playlist {} does not exist", playlist_name);
+            Err(RequestError::NotFound("Specified playlist does not exist".into()).into())
+        }
+    }
+
+    // paths of the images currently displayed in the given channel
+    pub fn current_images(&self, channel: Option<&str>) -> ProcessorResult<Vec<String>> {
+        self.with_actions(channel, |a| {
+            Ok(a.channel().displayed.iter().map(|image| image.path.clone()).collect())
+        })
+    }
+
+    // changes images in all channels which currently display any of the given images; the given paths
+    // are resolved first, since images of playlists are known by their canonical paths
+    pub fn replace_displayed_images(&self, images: &[String]) -> ProcessorResult<()> {
+        let images: Vec<_> = images.iter().map(|image| util::canonical_path(Path::new(image))).collect();
+        self.with_all_actions(|mut a| {
+            if a.channel().displayed.iter().any(|d| images.iter().any(|image| image == Path::new(&d.path))) {
+                info!("Replacing images displayed in channel {}", a.channel().name);
+                a.trigger(true, DisplayReason::Manual)
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    // images of all playlists in all channels
+    pub fn all_images(&self) -> Vec<PathBuf> {
+        let state = self.daemon.state.lock();
+        let processor_state = state.processor.borrow();
+
+        let images: HashSet<_> = processor_state.channels.iter()
+            .flat_map(|c| c.playlists.iter())
+            .flat_map(|p| p.images().iter().map(|image| image.path.clone()))
+            .collect();
+        images.into_iter().collect()
+    }
+
     pub fn start(&self) {
-        self.with_actions(|a| a.start());
+        let _ = self.with_all_actions(|a| {
+            a.start();
+            Ok(())
+        });
+    }
+
+    pub fn apply_current_image(&self, channel: Option<&str>) -> ProcessorResult<()> {
+        self.with_actions(channel, |mut a| {
+            a.check_not_empty()?;
+            a.apply_current_image(DisplayReason::Manual, false);
+            Ok(())
+        })
+    }
+
+    pub fn trigger(&self, channel: Option<&str>, simulate: bool) -> ProcessorResult<()> {
+        let reason = if simulate { DisplayReason::Manual } else { DisplayReason::Scheduled };
+        self.with_actions(channel, |mut a| {
+            // manual changes are still possible in paused channels
+            if reason == DisplayReason::Scheduled && a.channel().paused {
+                debug!("Channel {} is paused, skipping scheduled change", a.channel().name);
+                return Ok(());
+            }
+            // only explicit requests fail for empty playlists, scheduled changes just do nothing
+            if reason == DisplayReason::Manual {
+                a.check_not_empty()?;
+            }
+            a.trigger(simulate, reason)
+        })
+    }
+
+    pub fn pause(&self, channel: Option<&str>) -> ProcessorResult<()> {
+        self.with_actions(channel, |mut a| {
+            a.set_paused(true);
+            Ok(())
+        })
+    }
+
+    pub fn resume(&self, channel: Option<&str>) -> ProcessorResult<()> {
+        self.with_actions(channel, |mut a| {
+            a.set_paused(false);
+            Ok(())
+        })
     }
 
-    pub fn apply_current_image(&self) {
-        self.with_actions(|mut a| a.apply_current_image());
+    // displays the given image of the current playlist right away
+    pub fn show_image(&self, channel: Option<&str>, image: &str) -> ProcessorResult<()> {
+        self.with_actions(channel, |mut a| a.show_image(image))
     }
 
-    pub fn trigger(&self, simulate: bool) -> ProcessorResult<()> {
-        self.with_actions(|mut a| a.trigger(simulate))
+    // displays the images which were displayed before the current ones in the current playlist
+    pub fn show_previous(&self, channel: Option<&str>) -> ProcessorResult<()> {
+        self.with_actions(channel, |mut a| a.show_previous())
     }
 
     pub fn refresh_playlists(&self, simulate: bool) -> ProcessorResult<()> {
-        self.with_actions(|mut a| a.refresh_playlists(simulate))
+        self.with_actions(None, |mut a| a.refresh_playlists(simulate))
     }
 
-    pub fn get_status(&self) -> ProcessorResult<proto::StatusInfo> {
-        self.with_actions(|a| a.get_status())
+    pub fn get_status(&self, channel: Option<&str>) -> ProcessorResult<proto::StatusInfo> {
+        self.with_actions(channel, |a| a.get_status())
     }
 
-    pub fn change_playlist(&self, playlist_name: &str) -> ProcessorResult<()> {
-        self.with_actions(|mut a| a.change_playlist(playlist_name))
+    pub fn list_images(&self, query: &proto::ListQuery) -> ProcessorResult<proto::ImageList> {
+        self.with_actions(query.channel.as_deref(), |a| a.list_images(query.playlist.as_deref(), query.upcoming))
     }
 
-    pub fn update_stats(&self) -> ProcessorResult<()> {
-        self.with_actions(|a| a.update_stats())
+    pub fn change_playlist(&self, channel: Option<&str>, playlist_name: &str) -> ProcessorResult<()> {
+        self.with_actions(channel, |mut a| a.change_playlist(playlist_name))
+    }
+
+    pub fn checkpoint_stats(&self) -> ProcessorResult<()> {
+        self.with_all_actions(|mut a| {
+            a.flush_display_time(Utc::now());
+            Ok(())
+        })
+    }
+
+    pub fn shutdown(&self) {
+        info!("Saving display time of current images");
+        let _ = self.checkpoint_stats();
     }
 }
 
 struct StateActions<'a> {
     state: &'a mut State,
+    // index of the channel this instance acts on
+    channel: usize,
     daemon: super::Daemon,
 }
 
 impl<'a> StateActions<'a> {
+    fn channel(&self) -> &Channel {
+        &self.state.channels[self.channel]
+    }
+
+    fn channel_mut(&mut self) -> &mut Channel {
+        &mut self.state.channels[self.channel]
+    }
+
+    fn trigger_job_name(&self) -> String {
+        trigger_job_name(&self.channel().name)
+    }
+
     fn start(&self) {
         self.daemon.scheduler().schedule(
-            TRIGGER_JOB_NAME,
-            self.state.playlists[self.state.current_playlist].config().change_every
+            &self.trigger_job_name(),
+            self.channel().current_playlist().config().change_every
         );
     }
 
-    fn apply_current_image(&mut self) {
-        let playlist = &mut self.state.playlists[self.state.current_playlist];
-        playlist.apply_current_image();
+    // `skipped` determines whether the previously displayed images are considered skipped
+    fn apply_current_image(&mut self, reason: DisplayReason, skipped: bool) -> bool {
+        let applied = self.try_apply_current_image(reason, skipped);
+        if !applied {
+            self.emit_command_failed();
+        }
+        applied
     }
-    
-    fn trigger(&mut self, simulate: bool) -> ProcessorResult<()> {
-        self.state.last_used_image = self.state.playlists[self.state.current_playlist].current()
-            .map(|image| image.path.to_string_lossy().into_owned());
-
-        {
-            let playlist = &mut self.state.playlists[self.state.current_playlist];
-            while {
-                playlist.move_to_next_image();
-                !playlist.apply_current_image()
-            } { }
+
+    // like `apply_current_image`, but leaves reporting a failed command to the caller
+    fn try_apply_current_image(&mut self, reason: DisplayReason, skipped: bool) -> bool {
+        let applied = self.channel_mut().current_playlist_mut().apply_current_image();
+
+        if applied {
+            let now = Utc::now();
+            let old_images = self.displayed_paths();
+            self.flush_display_time(now);
+            self.replace_displayed(now, reason, skipped);
+            self.channel_mut().displayed_since = Some(now);
+
+            self.daemon.events().emit(Event::ImageChanged {
+                channel: self.channel().name.clone(),
+                playlist: self.channel().current_playlist_name().to_owned(),
+                old_images,
+                new_images: self.displayed_paths(),
+                reason,
+            });
         }
 
-        self.with_stats_and_current_path::<_, Box<dyn Error>>(|stats, current| {
-            stats.register_displays(current, 1)?;
+        applied
+    }
 
-            if let Some(last_trigger_time) = self.state.last_trigger_time {
-                let now = Utc::now();
+    fn emit_command_failed(&self) {
+        self.daemon.events().emit(Event::CommandFailed {
+            channel: self.channel().name.clone(),
+            playlist: self.channel().current_playlist_name().to_owned(),
+            images: self.channel().current_playlist().current().iter()
+                .map(|image| image.path.to_string_lossy().into_owned())
+                .collect(),
+        });
+    }
+
+    fn displayed_paths(&self) -> Vec<String> {
+        self.channel().displayed.iter().map(|image| image.path.clone()).collect()
+    }
+
+    fn set_paused(&mut self, paused: bool) {
+        if self.channel().paused == paused {
+            return;
+        }
+
+        let name = self.channel().name.clone();
+        self.channel_mut().paused = paused;
+        if paused {
+            info!("Pausing scheduled changes in channel {}", name);
+            self.daemon.events().emit(Event::Paused { channel: name, });
+        } else {
+            info!("Resuming scheduled changes in channel {}", name);
+            // the current image stays on screen for the full interval
+            self.daemon.scheduler().simulate(&self.trigger_job_name());
+            self.daemon.events().emit(Event::Resumed { channel: name, });
+        }
+    }
 
-                let diff = now.signed_duration_since(last_trigger_time);
-                if diff.num_seconds() <= SKIP_INTERVAL_SECS {
-                    let last_used_image = self.state.last_used_image.as_ref().unwrap();
-                    stats.register_skips(last_used_image, 1)?;
+    // ends display events of the previously displayed images and starts events for the current ones
+    fn replace_displayed(&mut self, now: DateTime<Utc>, reason: DisplayReason, skipped: bool) {
+        let stats = self.daemon.stats();
+
+        let channel = &mut self.state.channels[self.channel];
+        let mut previous: Vec<_> = mem::replace(&mut channel.displayed, Vec::new()).into_iter()
+            .map(Some)
+            .collect();
+
+        let context = DisplayContext {
+            channel: &channel.name,
+            playlist: channel.current_playlist_name(),
+            reason: reason,
+        };
+
+        let mut displayed = Vec::new();
+        for (idx, image) in channel.playlists[channel.current_playlist].current().iter().enumerate() {
+            let path = image.path.to_string_lossy().into_owned();
+
+            // the same image on the same output continues its display
+            let same = previous.get(idx).and_then(|p| p.as_ref()).map(|p| p.path == path).unwrap_or(false);
+            if same {
+                displayed.push(previous[idx].take().unwrap());
+                continue;
+            }
+
+            let event = stats.as_ref().and_then(|stats| stats.start_display(&path, context, now));
+            displayed.push(DisplayedImage { path, event, });
+        }
+
+        if let Some(ref stats) = stats {
+            for image in previous.into_iter().flatten() {
+                if let Some(event) = image.event {
+                    stats.end_display(event, skipped);
                 }
             }
+        }
 
-            Ok(())
-        });
+        channel.displayed = displayed;
+    }
+
+    // credits the time passed since the last flush to the images which are currently displayed
+    fn flush_display_time(&mut self, now: DateTime<Utc>) {
+        let since = match self.channel().displayed_since {
+            Some(since) => since,
+            None => return,
+        };
+
+        let seconds = now.signed_duration_since(since).num_seconds();
+        if seconds <= 0 {
+            return;
+        }
+
+        if let Some(ref stats) = self.daemon.stats() {
+            for image in &self.channel().displayed {
+                if let Some(event) = image.event {
+                    stats.extend_display(event, now, seconds);
+                }
+            }
+        }
 
-        self.state.last_trigger_time = Some(Utc::now());
+        // the fractional part of a second is left for the next flush
+        self.channel_mut().displayed_since = Some(since + Duration::seconds(seconds));
+    }
+    
+    fn check_not_empty(&self) -> ProcessorResult<()> {
+        if self.channel().current_playlist().images().is_empty() {
+            warn!("Current playlist of channel {} has no images", self.channel().name);
+            return Err(RequestError::FailedPrecondition("Current playlist has no images".into()).into());
+        }
+        Ok(())
+    }
+
+    // changing the image shortly after the previous change means that the previous image was skipped
+    fn is_skipped(&self, now: DateTime<Utc>) -> bool {
+        self.channel().last_trigger_time
+            .map(|t| now.signed_duration_since(t).num_seconds() <= SKIP_INTERVAL_SECS)
+            .unwrap_or(false)
+    }
+
+    fn trigger(&mut self, simulate: bool, reason: DisplayReason) -> ProcessorResult<()> {
+        simple_error!(CommandFailed, "Change command failed for all images of the current playlist");
+
+        let now = Utc::now();
+        let skipped = self.is_skipped(now);
+
+        // weights must be up to date in case the playlist restarts
+        if self.channel().current_playlist().config().mode == ChangeMode::Weighted {
+            let preferences = self.preferences();
+            self.channel_mut().current_playlist_mut().update_weights(&preferences);
+        }
+
+        // images whose command fails are passed over, but every image is tried at most once
+        let attempts = {
+            let playlist = self.channel().current_playlist();
+            cmp::max(1, playlist.len().div_ceil(playlist.config().outputs.len()))
+        };
+        let mut applied = false;
+        for _ in 0..attempts {
+            self.channel_mut().current_playlist_mut().move_to_next_image();
+            if self.try_apply_current_image(reason, skipped) {
+                applied = true;
+                break;
+            }
+        }
+
+        self.channel_mut().last_trigger_time = Some(now);
 
         if simulate {
-            self.daemon.scheduler().simulate(TRIGGER_JOB_NAME);
+            self.daemon.scheduler().simulate(&self.trigger_job_name());
         }
 
+        if applied {
+            Ok(())
+        } else {
+            warn!("Change command failed for all images of the current playlist in channel {}", self.channel().name);
+            self.emit_command_failed();
+            Err(CommandFailed.into())
+        }
+    }
+
+    fn show_image(&mut self, image: &str) -> ProcessorResult<()> {
+        // paths of images are compared as they are first, since resolving them touches the file system
+        let found = {
+            let images = self.channel().current_playlist().images();
+            let path = Path::new(image);
+            images.iter().find(|img| img.path == path)
+                .or_else(|| {
+                    let path = util::canonical_path(path);
+                    images.iter().find(|img| util::canonical_path(&img.path) == path)
+                })
+                .cloned()
+        };
+        let image = match found {
+            Some(image) => image,
+            None => return Err(RequestError::NotFound("Specified image does not belong to the current playlist".into()).into()),
+        };
+
+        info!("Showing {} in channel {}", image.path.display(), self.channel().name);
+        self.channel_mut().current_playlist_mut().move_to_image(image);
+        self.apply_selected_image();
+
         Ok(())
     }
 
+    fn show_previous(&mut self) -> ProcessorResult<()> {
+        if !self.channel_mut().current_playlist_mut().move_to_previous_image() {
+            return Err(RequestError::FailedPrecondition("No images were displayed before the current ones".into()).into());
+        }
+
+        info!("Showing previous images in channel {}", self.channel().name);
+        self.apply_selected_image();
+
+        Ok(())
+    }
+
+    // images selected by clients stay on screen for the full interval
+    fn apply_selected_image(&mut self) {
+        let now = Utc::now();
+        let skipped = self.is_skipped(now);
+
+        self.apply_current_image(DisplayReason::Manual, skipped);
+        self.channel_mut().last_trigger_time = Some(now);
+
+        self.daemon.scheduler().simulate(&self.trigger_job_name());
+    }
+
+    fn preferences(&self) -> Arc<Preferences> {
+        match self.daemon.stats() {
+            Some(stats) => stats.preferences(),
+            None => Arc::default(),
+        }
+    }
+
     fn refresh_playlists(&mut self, simulate: bool) -> ProcessorResult<()> {
-        for playlist in &mut self.state.playlists {
-            playlist.update();
+        let preferences = self.preferences();
+        let (mut added, mut removed) = (0, 0);
+        for channel in &mut self.state.channels {
+            for playlist in &mut channel.playlists {
+                let (playlist_added, playlist_removed) = playlist.update(&preferences);
+                added += playlist_added as u64;
+                removed += playlist_removed as u64;
+            }
         }
+        self.daemon.events().emit(Event::PlaylistsRefreshed { added, removed, });
 
         if simulate {
             self.daemon.scheduler().simulate(REFRESH_JOB_NAME);
@@ -185,23 +632,29 @@ impl<'a> StateActions<'a> {
     fn get_status(&self) -> ProcessorResult<proto::StatusInfo> {
         simple_error!(MissingTimestamp, "No last execution timestamp of image change trigger is available");
 
-        let last_timestamp = match self.daemon.scheduler().get_last_execution_timestamp(TRIGGER_JOB_NAME) {
+        let last_timestamp = match self.daemon.scheduler().get_last_execution_timestamp(&self.trigger_job_name()) {
             Some(timestamp) => timestamp,
             None => return Err(MissingTimestamp.into())
         };
 
-        fn playlists_by_name<'a>(state: &'a State) -> Box<dyn Iterator<Item=(&'a str, &'a Playlist)> + 'a> {
+        fn playlists_by_name<'a>(channel: &'a Channel) -> Box<dyn Iterator<Item=(&'a str, &'a Playlist)> + 'a> {
             Box::new(
-                state.playlist_indices.iter()
-                    .map(move |(name, &idx)| (&**name, &state.playlists[idx]))
+                channel.playlist_indices.iter()
+                    .map(move |(name, &idx)| (&**name, &channel.playlists[idx]))
             )
         }
 
         let mut result = HashMap::new();
-        for (name, playlist) in playlists_by_name(self.state) {
+        for (name, playlist) in playlists_by_name(self.channel()) {
             let files = playlist.len();
             let mode = playlist.config().mode.into();
-            let current_image = playlist.current().map(|img| img.path.display().to_string());
+            let current_images: Vec<_> = playlist.current().iter().zip(playlist.current_overrides()).enumerate()
+                .map(|(idx, (img, override_name))| proto::OutputImage {
+                    output: playlist.config().outputs.name(idx),
+                    image: img.path.display().to_string(),
+                    override_name,
+                })
+                .collect();
             let change_every = playlist.config().change_every;
 
             result.insert(name.to_owned(), proto::PlaylistInfo {
@@ -213,79 +666,273 @@ impl<'a> StateActions<'a> {
                     .collect(),
                 total_files: files as u64,
                 mode: mode,
-                current_image: current_image,
+                current_image: current_images.first().map(|oi| oi.image.clone()),
+                current_images: current_images,
                 next_update: (last_timestamp + change_every).timestamp(),
                 use_last_on_select: playlist.config().use_last_on_select,
                 trigger_on_select: playlist.config().trigger_on_select
             });
         }
 
-        let current_playlist = self.state.playlist_indices.iter()
-            .find(|&(_, idx)| *idx == self.state.current_playlist)
-            .unwrap().0.clone();
+        let current_playlist = self.channel().current_playlist_name().to_owned();
+
+        let mut channels: Vec<_> = self.state.channels.iter().map(|c| c.name.clone()).collect();
+        channels.sort();
 
         Ok(proto::StatusInfo {
             playlists: result,
             current_playlist: current_playlist,
             last_update: last_timestamp.timestamp(),
+            channel: self.channel().name.clone(),
+            channels: channels,
+            paused: self.channel().paused,
+        })
+    }
+
+    fn list_images(&self, playlist_name: Option<&str>, upcoming: bool) -> ProcessorResult<proto::ImageList> {
+        let channel = self.channel();
+        let (playlist_name, playlist) = match playlist_name {
+            Some(name) => match channel.playlist_indices.get(name) {
+                Some(&idx) => (name, &channel.playlists[idx]),
+                None => {
+                    warn!("Requested playlist {} does not exist in channel {}", name, channel.name);
+                    return Err(RequestError::NotFound("Specified playlist does not exist".into()).into());
+                }
+            },
+            None => (channel.current_playlist_name(), channel.current_playlist()),
+        };
+
+        let images: Box<dyn Iterator<Item=_>> = if upcoming {
+            Box::new(playlist.upcoming())
+        } else {
+            Box::new(playlist.images().iter())
+        };
+
+        Ok(proto::ImageList {
+            playlist: playlist_name.to_owned(),
+            mode: playlist.config().mode.into(),
+            images: images
+                .map(|image| proto::ListedImage {
+                    path: image.path.display().to_string(),
+                    weight: playlist.weight(&image.path),
+                    override_name: playlist.override_name(image),
+                })
+                .collect(),
         })
     }
 
     fn change_playlist(&mut self, playlist_name: &str) -> ProcessorResult<()> {
-        if let Some(&idx) = self.state.playlist_indices.get(playlist_name) {
-            info!("Changing the current playlist to {}", playlist_name);
-            self.state.current_playlist = idx;
+        if let Some(&idx) = self.channel().playlist_indices.get(playlist_name) {
+            info!("Changing the current playlist in channel {} to {}", self.channel().name, playlist_name);
+            let old_playlist = self.channel().current_playlist_name().to_owned();
+            self.channel_mut().current_playlist = idx;
+            self.daemon.events().emit(Event::PlaylistChanged {
+                channel: self.channel().name.clone(),
+                old_playlist,
+                new_playlist: playlist_name.to_owned(),
+            });
 
             macro_rules! current_playlist {
-                () => { &mut self.state.playlists[self.state.current_playlist] }
+                () => { self.channel_mut().current_playlist_mut() }
             }
-            self.daemon.scheduler().schedule(TRIGGER_JOB_NAME, current_playlist!().config().change_every);
+            let job_name = self.trigger_job_name();
+            self.daemon.scheduler().schedule(&job_name, current_playlist!().config().change_every);
 
             if current_playlist!().config().trigger_on_select {
                 if current_playlist!().config().use_last_on_select {
-                    if current_playlist!().move_to_next_image_if_first_time() {
+                    let reason = if current_playlist!().move_to_next_image_if_first_time() {
                         info!("Playlist wasn't used before, picking the first image");
-                        let _ = self.with_stats_and_current_path(|stats, current| {
-                            stats.register_displays(current, 1)
-                        });
-
+                        DisplayReason::Select
                     } else {
                         info!("Restoring the last used wallpaper in the current playlist");
-                    }
-                    current_playlist!().apply_current_image();
-                    self.daemon.scheduler().simulate(TRIGGER_JOB_NAME);
+                        DisplayReason::Restore
+                    };
+                    self.apply_current_image(reason, false);
+                    self.daemon.scheduler().simulate(&job_name);
                 } else {
                     info!("Picking the next image from the playlist");
-                    return self.trigger(false);
+                    return self.trigger(false, DisplayReason::Select);
                 }
             }
 
             Ok(())
         } else {
-            warn!("Requested playlist {} does not exist", playlist_name);
-            simple_error!(PlaylistDoesNotExist, "Specified playlist does not exist");
-            Err(PlaylistDoesNotExist.into())
+            warn!("Requested playlist {} does not exist in channel {}", playlist_name, self.channel().name);
+            Err(RequestError::NotFound("Specified playlist does not exist".into()).into())
         }
     }
+}
 
-    pub fn update_stats(&self) -> ProcessorResult<()> {
-        // TODO: change this to use timestamps
-        self.with_stats_and_current_path(|stats, current| {
-            stats.register_display_time(current, UPDATE_STATS_INTERVAL_SECS)
-        });
-        Ok(())
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use chrono::{Duration, Utc};
+    use futures::Stream;
+
+    use crate::common::proto;
+    use crate::daemon::{preferences, Daemon};
+    use crate::daemon::test_util::daemon;
+
+    use super::SKIP_INTERVAL_SECS;
+
+    fn displays(daemon: &Daemon) -> Vec<(String, i64, i64)> {
+        let stats = daemon.stats().unwrap();
+        stats.stop_writer();
+        stats.load().unwrap().into_iter()
+            .map(|s| (s.filename, s.stats.total_displays, s.stats.total_skips))
+            .collect()
     }
 
-    fn with_stats_and_current_path<F, E>(&self, f: F)
-        where F: Fn(&Stats, &str) -> Result<(), E>,
-              E: ::std::fmt::Display,
-    {
-        if let Some(ref stats) = self.daemon.stats() {
-            if let Some(current) = self.state.playlists[self.state.current_playlist].current() {
-                if let Err(e) = f(stats, &current.path.to_string_lossy()) {
-                    warn!("Failed to update statistics: {}", e);
-                }
-            }
+    #[test]
+    fn trigger_records_displays_and_skips() {
+        let dir = tempfile::tempdir().unwrap();
+        let daemon = daemon(&dir);
+        let processor = daemon.processor();
+
+        processor.trigger(None, true).unwrap();
+        let first = processor.current_images(None).unwrap();
+        // changing the image right away skips the first one
+        processor.trigger(None, true).unwrap();
+        let second = processor.current_images(None).unwrap();
+        assert_ne!(first, second);
+
+        let mut expected = vec![(first[0].clone(), 1, 1), (second[0].clone(), 1, 0)];
+        expected.sort();
+        assert_eq!(displays(&daemon), expected);
+    }
+
+    #[test]
+    fn paused_channel_skips_scheduled_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let daemon = daemon(&dir);
+        let processor = daemon.processor();
+
+        processor.trigger(None, true).unwrap();
+        let current = processor.current_images(None).unwrap();
+        processor.pause(None).unwrap();
+        processor.trigger(None, false).unwrap();
+        assert_eq!(processor.current_images(None).unwrap(), current);
+
+        // manual changes still work
+        processor.trigger(None, true).unwrap();
+        assert_ne!(processor.current_images(None).unwrap(), current);
+    }
+
+    #[test]
+    fn show_previous_restores_images_and_records_them() {
+        let dir = tempfile::tempdir().unwrap();
+        let daemon = daemon(&dir);
+        let processor = daemon.processor();
+
+        assert!(processor.show_previous(None).is_err());
+        processor.trigger(None, true).unwrap();
+        let first = processor.current_images(None).unwrap();
+        processor.trigger(None, true).unwrap();
+        processor.show_previous(None).unwrap();
+        assert_eq!(processor.current_images(None).unwrap(), first);
+
+        let first_displays = displays(&daemon).into_iter().find(|d| d.0 == first[0]).unwrap();
+        assert_eq!(first_displays.1, 2);
+    }
+
+    #[test]
+    fn unusable_images_are_tried_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let daemon = daemon(&dir);
+        let events = daemon.events().subscribe();
+        // the playlist still has the images, but they can't be displayed anymore
+        for name in &["a.jpg", "b.jpg"] {
+            fs::remove_file(dir.path().join(name)).unwrap();
         }
+
+        assert!(daemon.processor().trigger(None, true).is_err());
+        assert!(daemon.processor().current_images(None).unwrap().is_empty());
+
+        daemon.events().close();
+        let failures = events.wait()
+            .filter(|e| e.as_ref().unwrap().has_command_failed())
+            .count();
+        assert_eq!(failures, 1);
+    }
+
+    #[test]
+    fn favorites_playlist_follows_favorites() {
+        let dir = tempfile::tempdir().unwrap();
+        let daemon = daemon(&dir);
+        let processor = daemon.processor();
+        let favorites = || {
+            let query = proto::ListQuery { channel: None, playlist: Some("favorites".into()), upcoming: false, };
+            processor.list_images(&query).unwrap().images.into_iter().map(|img| img.path).collect::<Vec<_>>()
+        };
+        let a = dir.path().join("a.jpg").canonicalize().unwrap().to_string_lossy().into_owned();
+        let b = dir.path().join("b.jpg").canonicalize().unwrap().to_string_lossy().into_owned();
+
+        let stats = daemon.stats().unwrap();
+        stats.set_favorite(&a, true).unwrap();
+        stats.set_favorite(&b, true).unwrap();
+        processor.refresh_playlists(false).unwrap();
+        assert_eq!(favorites(), [a.clone(), b.clone()]);
+
+        stats.set_favorite(&a, false).unwrap();
+        stats.ban(&b, Utc::now()).unwrap();
+        processor.refresh_playlists(false).unwrap();
+        assert!(favorites().is_empty());
+    }
+
+    #[test]
+    fn banned_images_are_replaced_by_any_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let daemon = daemon(&dir);
+        let processor = daemon.processor();
+
+        processor.trigger(None, true).unwrap();
+        let banned = processor.current_images(None).unwrap();
+        // the same image, reached through the parent directory
+        let path = Path::new(&banned[0]);
+        let indirect = path.parent().unwrap()
+            .join("..").join(path.parent().unwrap().file_name().unwrap()).join(path.file_name().unwrap());
+
+        preferences::ban(&processor, daemon.stats().as_ref(), None, Some(indirect.to_str().unwrap())).unwrap();
+        assert_ne!(processor.current_images(None).unwrap(), banned);
+        assert!(!processor.all_images().contains(&PathBuf::from(&banned[0])));
+    }
+
+    #[test]
+    fn image_changes_do_not_wait_for_statistics() {
+        let dir = tempfile::tempdir().unwrap();
+        let daemon = daemon(&dir);
+
+        // stands for a long operation on the statistics, like collecting garbage
+        let _stats = daemon.stats.as_ref().unwrap().lock();
+        daemon.processor().trigger(None, true).unwrap();
+        daemon.processor().refresh_playlists(false).unwrap();
+    }
+
+    #[test]
+    fn unknown_channel_is_not_found() {
+        let dir = tempfile::tempdir().unwrap();
+        let daemon = daemon(&dir);
+
+        let e = daemon.processor().trigger(Some("nope"), true).unwrap_err();
+        assert!(e.to_string().contains("channel does not exist"));
+    }
+
+    #[test]
+    fn changes_shortly_after_the_previous_one_are_skips() {
+        let dir = tempfile::tempdir().unwrap();
+        let daemon = daemon(&dir);
+
+        daemon.processor().with_actions(None, |mut actions| {
+            let now = Utc::now();
+            assert!(!actions.is_skipped(now));
+
+            actions.channel_mut().last_trigger_time = Some(now);
+            assert!(actions.is_skipped(now));
+            assert!(actions.is_skipped(now + Duration::seconds(SKIP_INTERVAL_SECS)));
+            assert!(!actions.is_skipped(now + Duration::seconds(SKIP_INTERVAL_SECS + 1)));
+            Ok(())
+        }).unwrap();
     }
 }
@@ -1,15 +1,55 @@
 use std::io;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use crate::common::config::ValidatedPlaylist;
+use crate::common::util;
 use crate::daemon::processor::playlist::Image;
+use crate::daemon::stats::Preferences;
 
-pub fn scan_playlist(config: &ValidatedPlaylist) -> Vec<Image> {
+// decides which images may be included into a playlist based on user preferences
+pub struct ImageFilter<'a> {
+    config: &'a ValidatedPlaylist,
+    preferences: &'a Preferences,
+}
+
+impl<'a> ImageFilter<'a> {
+    pub fn new(config: &'a ValidatedPlaylist, preferences: &'a Preferences) -> ImageFilter<'a> {
+        ImageFilter { config, preferences, }
+    }
+
+    fn accepts(&self, file: &Path) -> bool {
+        if self.preferences.banned.contains(file) {
+            return false;
+        }
+
+        if self.config.favorites_only && !self.preferences.favorites.contains(file) {
+            return false;
+        }
+
+        match (self.config.min_rating, self.preferences.ratings.get(file)) {
+            (Some(min_rating), Some(&rating)) => rating >= min_rating,
+            _ => true,
+        }
+    }
+
+    // a favorites playlist without files and directories consists of all favorite images
+    fn files(&self) -> Vec<PathBuf> {
+        if self.config.favorites_only && self.config.files.is_empty() && self.config.directories.is_empty() {
+            let mut favorites: Vec<_> = self.preferences.favorites.iter().cloned().collect();
+            favorites.sort();
+            favorites
+        } else {
+            self.config.files.iter().map(|f| util::canonical_path(f)).collect()
+        }
+    }
+}
+
+pub fn scan_playlist(config: &ValidatedPlaylist, filter: &ImageFilter) -> Vec<Image> {
     let mut files: Vec<_> = Vec::new();
 
-    for file in &config.files {
-        if check_file(file) {
+    for file in &filter.files() {
+        if check_file(file) && filter.accepts(file) {
             files.push(Image {
                 path: file.clone(),
                 usable: true
@@ -18,7 +58,7 @@ pub fn scan_playlist(config: &ValidatedPlaylist) -> Vec<Image> {
     }
 
     for dir in &config.directories {
-        if let Err(e) = scan_directory(dir, &mut files) {
+        if let Err(e) = scan_directory(dir, filter, &mut files) {
             warn!("Error reading directory {}: {}", dir.display(), e);
         }
     }
@@ -37,12 +77,12 @@ pub enum SingleRescanResult {
     NotChanged,
 }
 
-pub fn rescan_playlist(config: &ValidatedPlaylist, existing_images: &[Image]) -> RescanResult {
+pub fn rescan_playlist(config: &ValidatedPlaylist, existing_images: &[Image], filter: &ImageFilter) -> RescanResult {
     let mut total_added = Vec::new();
     let mut total_removed = Vec::new();
 
-    for file in &config.files {
-        match rescan_file(file, existing_images) {
+    for file in &filter.files() {
+        match rescan_file(file, existing_images, filter) {
             SingleRescanResult::Added(image) => total_added.push(image),
             SingleRescanResult::Removed(image) => total_removed.push(image),
             SingleRescanResult::NotChanged => {}
@@ -50,7 +90,7 @@ pub fn rescan_playlist(config: &ValidatedPlaylist, existing_images: &[Image]) ->
     }
 
     for dir in &config.directories {
-        match rescan_directory(dir, existing_images) {
+        match rescan_directory(dir, existing_images, filter) {
             Ok(RescanResult { added, removed, }) => {
                 total_added.extend(added);
                 total_removed.extend(removed);
@@ -59,14 +99,23 @@ pub fn rescan_playlist(config: &ValidatedPlaylist, existing_images: &[Image]) ->
         }
     }
 
+    // images of the favorites playlist which are no longer favorites are not among the scanned files at all
+    for image in existing_images {
+        if !filter.accepts(&image.path) && !total_removed.iter().any(|img: &Image| img.path == image.path) {
+            info!("File '{}' is excluded by its rating, favorite or ban status", image.path.display());
+            total_removed.push(image.clone());
+        }
+    }
+
     RescanResult {
         added: total_added,
         removed: total_removed,
     }
 }
 
-fn rescan_file(file: &Path, existing_images: &[Image]) -> SingleRescanResult {
-    if check_file(file) {
+fn rescan_file(file: &Path, existing_images: &[Image], filter: &ImageFilter) -> SingleRescanResult {
+    let available = check_file(file);
+    if available && filter.accepts(file) {
         if existing_images.iter().any(|img| img.path == file) {
             SingleRescanResult::NotChanged
         } else {
@@ -79,7 +128,11 @@ fn rescan_file(file: &Path, existing_images: &[Image]) -> SingleRescanResult {
 
     } else {
         if let Some(image) = existing_images.iter().find(|img| img.path == file) {
-            warn!("Configured file '{}' is no longer available", file.display());
+            if available {
+                info!("Configured file '{}' is excluded by its rating, favorite or ban status", file.display());
+            } else {
+                warn!("Configured file '{}' is no longer available", file.display());
+            }
             SingleRescanResult::Removed(image.clone())
         } else {
             SingleRescanResult::NotChanged
@@ -87,7 +140,7 @@ fn rescan_file(file: &Path, existing_images: &[Image]) -> SingleRescanResult {
     }
 }
 
-fn rescan_directory(dir: &Path, existing_images: &[Image]) -> io::Result<RescanResult> {
+fn rescan_directory(dir: &Path, existing_images: &[Image], filter: &ImageFilter) -> io::Result<RescanResult> {
     let mut added = Vec::new();
     let mut removed: Vec<_> = existing_images.iter().collect();
 
@@ -100,13 +153,17 @@ fn rescan_directory(dir: &Path, existing_images: &[Image]) -> io::Result<RescanR
             }
         };
 
-        let path = entry.path();
+        let path = util::canonical_path(&entry.path());
+        let accepted = filter.accepts(&path);
 
+        // images which are no longer accepted by the filter are removed as well
         if let Some(idx) = removed.iter().position(|img| img.path == path) {
-            removed.remove(idx);
+            if accepted {
+                removed.remove(idx);
+            }
         }
 
-        if !existing_images.iter().any(|img| img.path == path) && check_file(&path) {
+        if accepted && !existing_images.iter().any(|img| img.path == path) && check_file(&path) {
             added.push(Image {
                 path: path,
                 usable: true,
@@ -130,7 +187,7 @@ fn rescan_directory(dir: &Path, existing_images: &[Image]) -> io::Result<RescanR
     })
 }
 
-fn scan_directory(dir: &Path, images: &mut Vec<Image>) -> io::Result<()> {
+fn scan_directory(dir: &Path, filter: &ImageFilter, images: &mut Vec<Image>) -> io::Result<()> {
     for entry in fs::read_dir(dir)? {
         let entry = match entry {
             Ok(entry) => entry,
@@ -140,8 +197,8 @@ fn scan_directory(dir: &Path, images: &mut Vec<Image>) -> io::Result<()> {
             }
         };
 
-        let path = entry.path();
-        if check_file(&path) {
+        let path = util::canonical_path(&entry.path());
+        if check_file(&path) && filter.accepts(&path) {
             images.push(Image {
                 path: path,
                 usable: true,
@@ -1,12 +1,21 @@
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::mem;
+use std::path::{Path, PathBuf};
 
 use rand;
 use rand::seq::SliceRandom;
 
 use crate::common::config::{ValidatedPlaylist, ChangeMode};
-use crate::daemon::processor::command::ChangeCommand;
+use crate::daemon::processor::command::{self, ChangeCommand};
+use crate::daemon::stats::Preferences;
+
+use self::files::ImageFilter;
 
 mod files;
+mod weights;
+
+// the number of previous selections remembered by each playlist
+const HISTORY_LENGTH: usize = 50;
 
 #[derive(Clone, Eq, PartialEq)]
 pub struct Image {
@@ -27,25 +36,31 @@ pub struct Playlist {
     images: Vec<Image>,
     // a sequence of of images which weren't used yet
     unused: Vec<Image>,
-    // the currently used image in this playlist
-    current: Option<Image>,
+    // the currently used images in this playlist, one per configured output
+    current: Vec<Image>,
+    // images used before the current ones, the most recent last
+    history: Vec<Vec<Image>>,
+    // selection weights of images, only computed in the weighted mode
+    weights: HashMap<PathBuf, f64>,
     // configuration
     config: ValidatedPlaylist,
 }
 
 impl Playlist {
-    pub fn from_config(config: ValidatedPlaylist) -> Playlist {
-        let images = files::scan_playlist(&config);
-        let mut unused: Vec<_> = images.clone();
-        if config.mode == ChangeMode::Random {
-            unused.shuffle(&mut rand::thread_rng());
-        }
-        Playlist {
+    pub fn from_config(config: ValidatedPlaylist, preferences: &Preferences) -> Playlist {
+        let images = files::scan_playlist(&config, &ImageFilter::new(&config, preferences));
+        let unused: Vec<_> = images.clone();
+        let mut playlist = Playlist {
             images: images,
             unused: unused,
             config: config,
-            current: None
-        }
+            current: Vec::new(),
+            history: Vec::new(),
+            weights: HashMap::new(),
+        };
+        playlist.update_weights(preferences);
+        playlist.shuffle_unused_if_needed();
+        playlist
     }
 
     pub fn config(&self) -> &ValidatedPlaylist {
@@ -54,32 +69,77 @@ impl Playlist {
 
     pub fn len(&self) -> usize { self.images.len() }
 
-    pub fn current(&self) -> Option<&Image> { self.current.as_ref() }
+    pub fn images(&self) -> &[Image] { &self.images }
+
+    pub fn current(&self) -> &[Image] { &self.current }
+
+    // images which will be used next, in order
+    pub fn upcoming(&self) -> impl Iterator<Item=&Image> { self.unused.iter().rev() }
+
+    // the selection weight of the image, if the weighted mode is used
+    pub fn weight(&self, path: &Path) -> Option<f64> {
+        if self.config.mode == ChangeMode::Weighted {
+            Some(self.weights.get(path).cloned().unwrap_or(1.0))
+        } else {
+            None
+        }
+    }
+
+    // recomputes selection weights; the new weights affect the order of images when they are shuffled next time
+    pub fn update_weights(&mut self, preferences: &Preferences) {
+        if self.config.mode == ChangeMode::Weighted {
+            self.weights = weights::compute(&self.config.weights, &self.images, preferences);
+        }
+    }
+
+    // names of command overrides used for the current images
+    pub fn current_overrides(&self) -> Vec<Option<String>> {
+        ChangeCommand::from_config(&self.config).overrides_for(&self.current).into_iter()
+            .map(|ovr| ovr.map(|o| o.name.clone()))
+            .collect()
+    }
+
+    // name of the command override which applies to the image
+    pub fn override_name(&self, image: &Image) -> Option<String> {
+        command::find_override(&self.config, &image.path).map(|o| o.name.clone())
+    }
 
     fn shuffle_unused_if_needed(&mut self) {
-        if self.config.mode == ChangeMode::Random {
-            self.unused.shuffle(&mut rand::thread_rng());
+        match self.config.mode {
+            ChangeMode::Sequential => {}
+            ChangeMode::Random => self.unused.shuffle(&mut rand::thread_rng()),
+            ChangeMode::Weighted => weights::shuffle(&mut self.unused, &self.weights),
         }
     }
 
-    pub fn update(&mut self) {
-        let files::RescanResult { added, removed } = files::rescan_playlist(&self.config, &self.images);
+    // returns the numbers of added and removed images
+    pub fn update(&mut self, preferences: &Preferences) -> (usize, usize) {
+        let filter = ImageFilter::new(&self.config, preferences);
+        let files::RescanResult { added, removed } = files::rescan_playlist(&self.config, &self.images, &filter);
         let added_new_items = !added.is_empty();
+        let counts = (added.len(), removed.len());
 
         let removed_pred = |img: &Image| !removed.iter().any(|removed_img| removed_img.path == img.path);
         self.images.retain(&removed_pred);
         self.unused.retain(&removed_pred);
+        self.history.retain(|images| images.iter().all(&removed_pred));
 
         self.images.extend(added.iter().cloned());
         self.unused.extend(added);
 
-        if added_new_items {
+        // weights depend on statistics, so the remaining images are reordered on every update
+        if self.config.mode == ChangeMode::Weighted {
+            self.update_weights(preferences);
+            self.shuffle_unused_if_needed();
+        } else if added_new_items {
             self.shuffle_unused_if_needed();
         }
+
+        counts
     }
 
     pub fn move_to_next_image_if_first_time(&mut self) -> bool {
-        if self.current.is_none() {
+        if self.current.is_empty() {
             self.move_to_next_image();
             true
         } else {
@@ -88,8 +148,94 @@ impl Playlist {
     }
 
     pub fn move_to_next_image(&mut self) {
+        self.remember_current();
+        let count = self.config.outputs.len();
+
+        let mut selected: Vec<Image> = Vec::with_capacity(count);
+        while selected.len() < count {
+            match self.next_unused_image(&selected) {
+                Some(image) => selected.push(image),
+                None => break,
+            }
+        }
+
+        if !selected.is_empty() && selected.len() < count {
+            warn!(
+                "Current playlist has only {} images for {} outputs, some images will be repeated",
+                selected.len(), count
+            );
+            let available = selected.len();
+            for i in available..count {
+                let image = selected[i % available].clone();
+                selected.push(image);
+            }
+        }
+
+        self.current = selected;
+    }
+
+    // puts the image on the first output, keeping the images on other outputs unless one of them already
+    // shows it, in which case the two outputs swap their images; the image counts as used in the current round
+    pub fn move_to_image(&mut self, image: Image) {
+        self.remember_current();
+        let count = self.config.outputs.len();
+        self.unused.retain(|img| img.path != image.path);
+
+        let mut selected = mem::replace(&mut self.current, Vec::new());
+        if selected.is_empty() {
+            selected.push(image);
+        } else if let Some(idx) = selected.iter().position(|img| img.path == image.path) {
+            // the image is already shown on another output, which gets the image of the first one instead
+            selected.swap(0, idx);
+        } else {
+            selected[0] = image;
+        }
+        while selected.len() < count {
+            match self.next_unused_image(&selected) {
+                Some(image) => selected.push(image),
+                None => {
+                    let image = selected[0].clone();
+                    selected.push(image);
+                }
+            }
+        }
+
+        self.current = selected;
+    }
+
+    // returns to the images used before the current ones, which are then used next again; returns false
+    // if there are no previous images
+    pub fn move_to_previous_image(&mut self) -> bool {
+        let previous = match self.history.pop() {
+            Some(previous) => previous,
+            None => return false,
+        };
+
+        let current = mem::replace(&mut self.current, previous);
+        // the previous images may have been returned to the unused ones when the playlist restarted
+        let selected = &self.current;
+        self.unused.retain(|img| !selected.contains(img));
+        for image in current.into_iter().rev() {
+            if !self.unused.contains(&image) && !self.current.contains(&image) {
+                self.unused.push(image);
+            }
+        }
+
+        true
+    }
+
+    fn remember_current(&mut self) {
+        if !self.current.is_empty() {
+            self.history.push(self.current.clone());
+            if self.history.len() > HISTORY_LENGTH {
+                self.history.remove(0);
+            }
+        }
+    }
+
+    fn next_unused_image(&mut self, selected: &[Image]) -> Option<Image> {
         match self.unused.pop() {
-            Some(image) => self.current = Some(image),
+            Some(image) => Some(image),
             None => {
                 let was_nonempty = !self.images.is_empty();
 
@@ -97,27 +243,38 @@ impl Playlist {
                 // TODO: maybe remove?
                 self.images.retain(|img| img.usable);
 
-                if self.images.is_empty() {
-                    if was_nonempty {
+                // images which were just selected will be shown anyway, so they start the next round as used
+                let restarted: Vec<_> = self.images.iter()
+                    .filter(|img| !selected.contains(img))
+                    .cloned()
+                    .collect();
+
+                if restarted.is_empty() {
+                    if self.images.is_empty() && was_nonempty {
                         warn!("Current playlist became empty, no further updates will be done");
                     }
-                    self.current = None;
+                    None
                 } else {
                     info!("Current playlist has ended, restarting");
-                    self.unused.extend(self.images.iter().cloned());
+                    self.unused.extend(restarted);
                     self.shuffle_unused_if_needed();
-                    self.move_to_next_image();
+                    self.unused.pop()
                 }
-
             }
         }
     }
 
     pub fn apply_current_image(&mut self) -> bool {
-        if let Some(ref current) = self.current {
+        if !self.current.is_empty() {
             let command = ChangeCommand::from_config(&self.config);
-            info!("Changing wallpaper to {}", current.path.display());
-            if !command.execute(current) {
+            for (idx, image) in self.current.iter().enumerate() {
+                if self.current.len() > 1 {
+                    info!("Changing wallpaper on output {} to {}", self.config.outputs.name(idx), image.path.display());
+                } else {
+                    info!("Changing wallpaper to {}", image.path.display());
+                }
+            }
+            if !command.execute(&self.current) {
                 warn!("Failed to execute the change ");
                 false
             } else {
@@ -130,3 +287,107 @@ impl Playlist {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::daemon::test_util;
+
+    // a sequential playlist of images named by their indices, which are used in order
+    fn playlist(outputs: usize, images: usize) -> Playlist {
+        let images: Vec<_> = (0..images)
+            .map(|i| Image { path: PathBuf::from(format!("/images/{}.jpg", i)), usable: true, })
+            .collect();
+        Playlist {
+            unused: images.iter().rev().cloned().collect(),
+            images,
+            current: Vec::new(),
+            history: Vec::new(),
+            weights: HashMap::new(),
+            config: test_util::playlist_config(outputs),
+        }
+    }
+
+    fn current(playlist: &Playlist) -> Vec<String> {
+        playlist.current().iter().map(|img| img.path.file_stem().unwrap().to_string_lossy().into_owned()).collect()
+    }
+
+    #[test]
+    fn next_image_is_selected_for_every_output() {
+        let mut playlist = playlist(2, 5);
+
+        playlist.move_to_next_image();
+        assert_eq!(current(&playlist), ["0", "1"]);
+        playlist.move_to_next_image();
+        assert_eq!(current(&playlist), ["2", "3"]);
+        // the playlist restarts in the middle of the selection without repeating the image just selected
+        playlist.move_to_next_image();
+        assert_eq!(current(&playlist), ["4", "3"]);
+        playlist.move_to_next_image();
+        assert_eq!(current(&playlist), ["2", "1"]);
+    }
+
+    #[test]
+    fn images_are_repeated_when_outputs_outnumber_them() {
+        let mut repeated = playlist(3, 2);
+        repeated.move_to_next_image();
+        assert_eq!(current(&repeated), ["0", "1", "0"]);
+
+        let mut empty = playlist(2, 0);
+        empty.move_to_next_image();
+        assert!(empty.current().is_empty());
+    }
+
+    #[test]
+    fn previous_images_are_restored_on_all_outputs() {
+        let mut playlist = playlist(2, 6);
+
+        assert!(!playlist.move_to_previous_image());
+        playlist.move_to_next_image();
+        playlist.move_to_next_image();
+        playlist.move_to_next_image();
+        assert_eq!(current(&playlist), ["4", "5"]);
+
+        assert!(playlist.move_to_previous_image());
+        assert_eq!(current(&playlist), ["2", "3"]);
+        assert!(playlist.move_to_previous_image());
+        assert_eq!(current(&playlist), ["0", "1"]);
+        assert!(!playlist.move_to_previous_image());
+
+        // images which were current before going back are used next again
+        playlist.move_to_next_image();
+        assert_eq!(current(&playlist), ["2", "3"]);
+        playlist.move_to_next_image();
+        assert_eq!(current(&playlist), ["4", "5"]);
+    }
+
+    #[test]
+    fn history_is_limited() {
+        let mut playlist = playlist(1, 3);
+
+        for _ in 0..HISTORY_LENGTH + 10 {
+            playlist.move_to_next_image();
+        }
+        let mut steps = 0;
+        while playlist.move_to_previous_image() {
+            steps += 1;
+        }
+        assert_eq!(steps, HISTORY_LENGTH);
+    }
+
+    #[test]
+    fn selected_image_is_not_shown_twice() {
+        let mut playlist = playlist(2, 5);
+        playlist.move_to_next_image();
+
+        // an image already shown on the other output swaps places with the one on the first output
+        playlist.move_to_image(playlist.images()[1].clone());
+        assert_eq!(current(&playlist), ["1", "0"]);
+
+        playlist.move_to_image(playlist.images()[3].clone());
+        assert_eq!(current(&playlist), ["3", "0"]);
+        // the selected image is not used again in the current round
+        playlist.move_to_next_image();
+        assert_eq!(current(&playlist), ["2", "4"]);
+    }
+}
@@ -0,0 +1,90 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use rand::{self, Rng};
+
+use crate::common::config::WeightFormula;
+use crate::daemon::processor::playlist::Image;
+use crate::daemon::stats::{ImageCounters, Preferences};
+
+pub fn compute(formula: &WeightFormula, images: &[Image], preferences: &Preferences) -> HashMap<PathBuf, f64> {
+    let recent_period = formula.recent_period.to_std().unwrap_or_default();
+    let now = SystemTime::now();
+
+    images.iter()
+        .map(|image| {
+            let counters = preferences.statistics.get(&image.path).cloned().unwrap_or_else(ImageCounters::default);
+            let favorite = preferences.favorites.contains(&image.path);
+            let recent = is_recent(&image.path, now, recent_period);
+            (image.path.clone(), formula.weight(counters.displays, counters.skips, favorite, recent))
+        })
+        .collect()
+}
+
+fn is_recent(path: &Path, now: SystemTime, period: Duration) -> bool {
+    match fs::metadata(path).and_then(|m| m.modified()) {
+        // files from the future are recent too
+        Ok(modified) => now.duration_since(modified).map(|age| age <= period).unwrap_or(true),
+        Err(_) => false,
+    }
+}
+
+// orders images so that taking them from the end yields a weighted random sample without replacement;
+// this is the Efraimidis-Spirakis method with keys ln(u)/w, where larger keys are taken first
+pub fn shuffle(images: &mut Vec<Image>, weights: &HashMap<PathBuf, f64>) {
+    let mut rng = rand::thread_rng();
+
+    let mut keyed: Vec<_> = images.drain(..)
+        .map(|image| {
+            let weight = weights.get(&image.path).cloned().unwrap_or(1.0);
+            (rng.gen::<f64>().ln() / weight, image)
+        })
+        .collect();
+    keyed.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+    images.extend(keyed.into_iter().map(|(_, image)| image));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(name: &str) -> Image {
+        Image { path: PathBuf::from(name), usable: true, }
+    }
+
+    #[test]
+    fn formula_penalizes_displays_and_skips() {
+        let formula = WeightFormula::default();
+
+        assert_eq!(formula.weight(0, 0, false, false), 1.0);
+        assert_eq!(formula.weight(1, 0, false, false), 0.5);
+        // half of the displays were skipped
+        assert_eq!(formula.weight(2, 1, false, false), 1.0 / (3.0 * 2.0));
+        assert_eq!(formula.weight(0, 0, true, true), 6.0);
+    }
+
+    #[test]
+    fn shuffle_prefers_heavier_images() {
+        let weights: HashMap<_, _> = vec![(PathBuf::from("heavy"), 1000.0), (PathBuf::from("light"), 0.001)]
+            .into_iter().collect();
+
+        let mut heavy_first = 0;
+        for _ in 0..100 {
+            let mut images = vec![image("light"), image("heavy"), image("other")];
+            shuffle(&mut images, &weights);
+
+            let mut names: Vec<_> = images.iter().map(|img| img.path.to_str().unwrap()).collect();
+            // images are taken from the end
+            if names.last() == Some(&"heavy") {
+                heavy_first += 1;
+            }
+            names.sort_unstable();
+            assert_eq!(names, ["heavy", "light", "other"]);
+        }
+        assert!(heavy_first >= 95, "heavy image was taken first only {} times", heavy_first);
+    }
+}
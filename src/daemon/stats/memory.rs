@@ -0,0 +1,739 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashSet};
+use std::error::Error;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+use serde_derive::{Serialize, Deserialize};
+
+use super::{model, path_hash, renamed_path, RenameResult, Result, StatsStore};
+
+// a change of the stored statistics; the JSON lines backend stores the sequence of changes
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum Change {
+    RecordPath { path: String, hash: String, last_seen: i64 },
+    AddCounters { hash: String, displays: i64, skips: i64, time_sec: i64 },
+    AddLegacyCounters { hash: String, displays: i64, skips: i64, time_sec: i64 },
+    InsertEvent { id: i64, event: model::DisplayEvent },
+    ExtendEvent { id: i64, until: i64 },
+    SkipEvent { id: i64 },
+    RebuildAggregates,
+    Merge { from: String, to: String },
+    SetRating { filename: String, rating: Option<i32> },
+    SetFavorite { filename: String, favorite: bool },
+    MovePreferences { from: String, to: String },
+    Ban { filename: String, banned_at: i64 },
+    Unban { filename: String },
+    Remove { hashes: Vec<String>, paths: Vec<String>, filenames: Vec<String> },
+    RenamePrefix { from: String, to: String },
+}
+
+// the log is rewritten as a snapshot of the data once it grows by this many records, so periodic updates
+// like display time checkpoints do not make it grow without bounds
+const COMPACT_AFTER_RECORDS: usize = 10_000;
+
+#[derive(Default, Copy, Clone)]
+struct Counters {
+    displays: i64,
+    skips: i64,
+    time_sec: i64,
+}
+
+impl Counters {
+    fn add(&mut self, other: Counters) {
+        self.displays += other.displays;
+        self.skips += other.skips;
+        self.time_sec += other.time_sec;
+    }
+}
+
+#[derive(Default, Clone)]
+struct Statistics {
+    total: Counters,
+    // the part of the total which is not backed by display events
+    legacy: Counters,
+}
+
+#[derive(Default, Clone)]
+struct Preferences {
+    rating: Option<i32>,
+    favorite: bool,
+}
+
+#[derive(Default, Clone)]
+struct Data {
+    statistics: BTreeMap<String, Statistics>,
+    // paths with the hashes of their contents and the time they were last seen
+    paths: BTreeMap<String, (String, i64)>,
+    events: BTreeMap<i64, model::DisplayEvent>,
+    preferences: BTreeMap<String, Preferences>,
+    bans: BTreeMap<String, i64>,
+}
+
+impl Data {
+    fn apply(&mut self, change: &Change) {
+        match *change {
+            Change::RecordPath { ref path, ref hash, last_seen } => {
+                self.paths.insert(path.clone(), (hash.clone(), last_seen));
+            }
+            Change::AddCounters { ref hash, displays, skips, time_sec } => {
+                self.statistics.entry(hash.clone()).or_default().total.add(Counters { displays, skips, time_sec, });
+            }
+            Change::AddLegacyCounters { ref hash, displays, skips, time_sec } => {
+                let s = self.statistics.entry(hash.clone()).or_default();
+                s.total.add(Counters { displays, skips, time_sec, });
+                s.legacy.add(Counters { displays, skips, time_sec, });
+            }
+            Change::InsertEvent { id, ref event } => {
+                self.events.insert(id, event.clone());
+            }
+            Change::ExtendEvent { id, until } => {
+                if let Some(event) = self.events.get_mut(&id) {
+                    event.ended_at = until;
+                }
+            }
+            Change::SkipEvent { id } => {
+                if let Some(event) = self.events.get_mut(&id) {
+                    event.skipped = true;
+                }
+            }
+            Change::RebuildAggregates => {
+                for s in self.statistics.values_mut() {
+                    s.total = s.legacy;
+                }
+                for event in self.events.values() {
+                    self.statistics.entry(event.hash.clone()).or_default().total.add(Counters {
+                        displays: 1,
+                        skips: event.skipped as i64,
+                        time_sec: event.ended_at - event.started_at,
+                    });
+                }
+            }
+            Change::Merge { ref from, ref to } => {
+                if let Some(merged) = self.statistics.remove(from) {
+                    let s = self.statistics.entry(to.clone()).or_default();
+                    s.total.add(merged.total);
+                    s.legacy.add(merged.legacy);
+                }
+
+                for event in self.events.values_mut().filter(|e| e.hash == *from) {
+                    event.hash = to.clone();
+                }
+                for (hash, _) in self.paths.values_mut().filter(|p| p.0 == *from) {
+                    *hash = to.clone();
+                }
+            }
+            Change::SetRating { ref filename, rating } => {
+                self.preferences.entry(filename.clone()).or_default().rating = rating;
+            }
+            Change::SetFavorite { ref filename, favorite } => {
+                self.preferences.entry(filename.clone()).or_default().favorite = favorite;
+            }
+            Change::MovePreferences { ref from, ref to } => {
+                move_key(&mut self.preferences, from, to.clone());
+                move_key(&mut self.bans, from, to.clone());
+            }
+            Change::Ban { ref filename, banned_at } => {
+                self.bans.entry(filename.clone()).or_insert(banned_at);
+            }
+            Change::Unban { ref filename } => {
+                self.bans.remove(filename);
+            }
+            Change::Remove { ref hashes, ref paths, ref filenames } => {
+                let hashes: HashSet<_> = hashes.iter().collect();
+                self.statistics.retain(|h, _| !hashes.contains(h));
+                self.events.retain(|_, e| !hashes.contains(&e.hash));
+                for path in paths {
+                    self.paths.remove(path);
+                }
+                for filename in filenames {
+                    self.preferences.remove(filename);
+                    self.bans.remove(filename);
+                }
+            }
+            Change::RenamePrefix { ref from, ref to } => {
+                self.rename_prefix(from, to);
+            }
+        }
+    }
+
+    // writes the changes which recreate the data from scratch; returns the number of written records
+    fn write_snapshot(&self, out: &mut dyn Write) -> Result<usize> {
+        let mut records = 0;
+        let mut write = |change: Change| -> Result<()> {
+            serde_json::to_writer(&mut *out, &change)?;
+            out.write_all(b"\n")?;
+            records += 1;
+            Ok(())
+        };
+
+        for (hash, s) in &self.statistics {
+            let Counters { displays, skips, time_sec } = s.legacy;
+            write(Change::AddLegacyCounters { hash: hash.clone(), displays, skips, time_sec, })?;
+            write(Change::AddCounters {
+                hash: hash.clone(),
+                displays: s.total.displays - displays,
+                skips: s.total.skips - skips,
+                time_sec: s.total.time_sec - time_sec,
+            })?;
+        }
+        for (path, &(ref hash, last_seen)) in &self.paths {
+            write(Change::RecordPath { path: path.clone(), hash: hash.clone(), last_seen, })?;
+        }
+        for (&id, event) in &self.events {
+            write(Change::InsertEvent { id, event: event.clone(), })?;
+        }
+        for (filename, p) in &self.preferences {
+            write(Change::SetRating { filename: filename.clone(), rating: p.rating, })?;
+            write(Change::SetFavorite { filename: filename.clone(), favorite: p.favorite, })?;
+        }
+        for (filename, &banned_at) in &self.bans {
+            write(Change::Ban { filename: filename.clone(), banned_at, })?;
+        }
+
+        Ok(records)
+    }
+
+    // behaves like the SQLite backend: renamed records which would replace existing ones are left as they are
+    fn rename_prefix(&mut self, from: &str, to: &str) -> RenameResult {
+        let (from_hash, to_hash) = (path_hash(from), path_hash(to));
+
+        let paths = rename_keys(&mut self.paths, from, to);
+        let preferences = rename_keys(&mut self.preferences, from, to) + rename_keys(&mut self.bans, from, to);
+        rename_keys(&mut self.statistics, &from_hash, &to_hash);
+
+        for event in self.events.values_mut() {
+            if let Some(filename) = renamed_path(&event.filename, from, to) {
+                event.filename = filename;
+            }
+            if let Some(hash) = renamed_path(&event.hash, &from_hash, &to_hash) {
+                event.hash = hash;
+            }
+        }
+        for (hash, _) in self.paths.values_mut() {
+            if let Some(renamed_hash) = renamed_path(hash, &from_hash, &to_hash) {
+                *hash = renamed_hash;
+            }
+        }
+
+        RenameResult { paths, preferences, ..RenameResult::default() }
+    }
+}
+
+// statistics kept in memory and, optionally, appended as JSON lines to a file
+#[derive(Clone)]
+pub struct MemoryStore {
+    // the lock is held for whole transactions, which other threads wait for like in a database
+    shared: Arc<ReentrantMutex<RefCell<Shared>>>,
+}
+
+struct Shared {
+    data: Data,
+    log: Option<Log>,
+    // the transaction of the thread holding the lock, if any
+    transaction: Option<Transaction>,
+}
+
+// changes of a transaction are applied to the data right away, but only appended to the log once the
+// transaction succeeds
+struct Transaction {
+    // the data before the transaction started
+    snapshot: Data,
+    changes: Vec<Change>,
+}
+
+// the file changes are appended to
+struct Log {
+    path: PathBuf,
+    file: File,
+    // records appended since the file was last compacted
+    appended: usize,
+    // only the daemon compacts the file
+    compact: bool,
+}
+
+impl Log {
+    // replaces the file with a snapshot of the data; the snapshot is written to a temporary file first,
+    // so the log stays intact if the daemon is killed meanwhile
+    fn compact(path: &Path, data: &Data) -> Result<Log> {
+        let mut tmp_path = path.as_os_str().to_owned();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+
+        let mut out = BufWriter::new(File::create(&tmp_path)?);
+        let records = data.write_snapshot(&mut out)?;
+        // further changes are appended to the snapshot through the same file
+        let file = out.into_inner().map_err(|e| e.into_error())?;
+        file.sync_all()?;
+        fs::rename(&tmp_path, path)?;
+        debug!("Compacted statistics in {} to {} records", path.display(), records);
+
+        Ok(Log { path: path.to_owned(), file, appended: 0, compact: true, })
+    }
+
+    // the changes are written at once, so they are either all stored or the last line is incomplete
+    fn append(&mut self, changes: &[Change]) -> Result<()> {
+        let mut lines = String::new();
+        for change in changes {
+            lines.push_str(&serde_json::to_string(change)?);
+            lines.push('\n');
+        }
+        self.file.write_all(lines.as_bytes())?;
+        self.appended += changes.len();
+        Ok(())
+    }
+
+    // `data` must already contain all appended changes
+    fn compact_if_needed(&mut self, data: &Data) {
+        if !self.compact || self.appended < COMPACT_AFTER_RECORDS {
+            return;
+        }
+        match Log::compact(&self.path, data) {
+            Ok(log) => *self = log,
+            Err(e) => {
+                // the complete log is still there, so compacting is just tried again later
+                warn!("Failed to compact statistics in {}: {}", self.path.display(), e);
+                self.appended = 0;
+            }
+        }
+    }
+}
+
+impl MemoryStore {
+    pub fn new() -> MemoryStore {
+        MemoryStore::with_data(Data::default(), None)
+    }
+
+    fn with_data(data: Data, log: Option<Log>) -> MemoryStore {
+        MemoryStore { shared: Arc::new(ReentrantMutex::new(RefCell::new(Shared { data, log, transaction: None, }))), }
+    }
+
+    // replays the changes stored in the file and appends further changes to it; the daemon also compacts
+    // the file, while other processes like `wcd analyze` leave it as it is
+    pub fn open_log(path: &Path, compact: bool) -> Result<MemoryStore> {
+        info!("Loading statistics from {}", path.display());
+        let mut data = Data::default();
+        if path.exists() {
+            for (n, line) in BufReader::new(File::open(path)?).lines().enumerate() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                // the last line may be incomplete if the daemon was killed while writing it
+                match serde_json::from_str::<Change>(&line) {
+                    Ok(change) => data.apply(&change),
+                    Err(e) => warn!("Skipping invalid statistics record at {}:{}: {}", path.display(), n + 1, e),
+                }
+            }
+        }
+
+        let log = if compact {
+            Log::compact(path, &data)?
+        } else {
+            let file = OpenOptions::new().create(true).append(true).open(path)?;
+            Log { path: path.to_owned(), file, appended: 0, compact: false, }
+        };
+        Ok(MemoryStore::with_data(data, Some(log)))
+    }
+
+    fn read<T, F: FnOnce(&Data) -> T>(&self, f: F) -> T {
+        f(&self.shared.lock().borrow().data)
+    }
+
+    fn change(&self, change: Change) -> Result<()> {
+        self.update(|_| Ok((change, ())))
+    }
+
+    // `f` makes the change from the data right before it is applied, and computes the result of the update;
+    // nothing is changed if `f` fails
+    fn update<T, F: FnOnce(&Data) -> Result<(Change, T)>>(&self, f: F) -> Result<T> {
+        let shared = self.shared.lock();
+        let mut shared = shared.borrow_mut();
+        let shared = &mut *shared;
+        let (change, result) = f(&shared.data)?;
+        match shared.transaction {
+            Some(ref mut transaction) => {
+                shared.data.apply(&change);
+                transaction.changes.push(change);
+            }
+            None => {
+                if let Some(ref mut log) = shared.log {
+                    log.append(std::slice::from_ref(&change))?;
+                }
+                shared.data.apply(&change);
+                if let Some(ref mut log) = shared.log {
+                    log.compact_if_needed(&shared.data);
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    // appends the changes of the finished transaction to the log
+    fn commit(shared: &mut Shared) -> Result<()> {
+        let transaction = shared.transaction.take().expect("No transaction is in progress");
+        if let Some(ref mut log) = shared.log {
+            if let Err(e) = log.append(&transaction.changes) {
+                shared.data = transaction.snapshot;
+                return Err(e);
+            }
+            log.compact_if_needed(&shared.data);
+        }
+        Ok(())
+    }
+
+    // undoes the changes made since the transaction had `kept` changes
+    fn roll_back(shared: &mut Shared, kept: usize) {
+        let transaction = shared.transaction.as_mut().expect("No transaction is in progress");
+        transaction.changes.truncate(kept);
+        shared.data = transaction.snapshot.clone();
+        for change in &transaction.changes {
+            shared.data.apply(change);
+        }
+    }
+}
+
+impl StatsStore for MemoryStore {
+    // both threads share the same data
+    fn open_writer(&self) -> Result<Box<dyn StatsStore>> {
+        Ok(Box::new(self.clone()))
+    }
+
+    // nested transactions are rolled back on their own, like savepoints
+    fn transaction(&self, f: &mut dyn FnMut() -> Result<()>) -> Result<()> {
+        let shared = self.shared.lock();
+        let (outermost, kept) = {
+            let mut shared = shared.borrow_mut();
+            let shared = &mut *shared;
+            match shared.transaction {
+                Some(ref transaction) => (false, transaction.changes.len()),
+                None => {
+                    shared.transaction = Some(Transaction { snapshot: shared.data.clone(), changes: Vec::new(), });
+                    (true, 0)
+                }
+            }
+        };
+
+        let result = f();
+
+        let mut shared = shared.borrow_mut();
+        match result {
+            Ok(()) if outermost => MemoryStore::commit(&mut shared),
+            Ok(()) => Ok(()),
+            Err(e) => {
+                MemoryStore::roll_back(&mut shared, kept);
+                if outermost {
+                    shared.transaction = None;
+                }
+                Err(e)
+            }
+        }
+    }
+
+    fn load_statistics(&self) -> Result<Vec<model::ImageStatistics>> {
+        Ok(self.read(|data| data.statistics.iter()
+            .map(|(hash, s)| model::ImageStatistics {
+                hash: hash.clone(),
+                total_displays: s.total.displays,
+                total_skips: s.total.skips,
+                total_display_time: s.total.time_sec,
+            })
+            .collect()))
+    }
+
+    fn load_paths(&self) -> Result<Vec<model::ImagePath>> {
+        let mut paths: Vec<_> = self.read(|data| data.paths.iter()
+            .map(|(path, &(ref hash, last_seen))| model::ImagePath { path: path.clone(), hash: hash.clone(), last_seen, })
+            .collect());
+        // sorting is stable, so ties stay ordered by path
+        paths.sort_by_key(|p| p.last_seen);
+        Ok(paths)
+    }
+
+    fn load_events(&self) -> Result<Vec<model::DisplayEvent>> {
+        Ok(self.read(|data| data.events.values().cloned().collect()))
+    }
+
+    fn load_preferences(&self) -> Result<Vec<model::ImagePreferences>> {
+        Ok(self.read(|data| data.preferences.iter()
+            .map(|(filename, p)| model::ImagePreferences { filename: filename.clone(), rating: p.rating, favorite: p.favorite, })
+            .collect()))
+    }
+
+    fn load_bans(&self) -> Result<Vec<model::BannedImage>> {
+        let mut bans: Vec<_> = self.read(|data| data.bans.iter()
+            .map(|(filename, &banned_at)| model::BannedImage { filename: filename.clone(), banned_at, })
+            .collect());
+        bans.sort_by_key(|b| b.banned_at);
+        Ok(bans)
+    }
+
+    fn has_event(&self, hash: &str, channel: &str, started_at: i64) -> Result<bool> {
+        Ok(self.read(|data| data.events.values()
+            .any(|e| e.hash == hash && e.channel == channel && e.started_at == started_at)))
+    }
+
+    fn record_path(&self, image_path: &model::ImagePath) -> Result<()> {
+        self.change(Change::RecordPath {
+            path: image_path.path.clone(),
+            hash: image_path.hash.clone(),
+            last_seen: image_path.last_seen,
+        })
+    }
+
+    fn add_counters(&self, hash: &str, displays: i64, skips: i64, time_sec: i64) -> Result<()> {
+        self.change(Change::AddCounters { hash: hash.to_owned(), displays, skips, time_sec, })
+    }
+
+    fn add_legacy_counters(&self, hash: &str, displays: i64, skips: i64, time_sec: i64) -> Result<()> {
+        self.change(Change::AddLegacyCounters { hash: hash.to_owned(), displays, skips, time_sec, })
+    }
+
+    fn insert_event(&self, event: &model::NewDisplayEvent) -> Result<i64> {
+        let event = model::DisplayEvent {
+            filename: event.filename.to_owned(),
+            channel: event.channel.to_owned(),
+            playlist: event.playlist.to_owned(),
+            started_at: event.started_at,
+            ended_at: event.ended_at,
+            reason: event.reason.to_owned(),
+            skipped: event.skipped,
+            hash: event.hash.to_owned(),
+        };
+        self.update(|data| {
+            let id = data.events.keys().next_back().map(|id| id + 1).unwrap_or(1);
+            Ok((Change::InsertEvent { id, event, }, id))
+        })
+    }
+
+    fn extend_event(&self, id: i64, until: i64) -> Result<String> {
+        self.update(|data| Ok((Change::ExtendEvent { id, until, }, event_hash(data, id)?)))
+    }
+
+    fn skip_event(&self, id: i64) -> Result<String> {
+        self.update(|data| Ok((Change::SkipEvent { id, }, event_hash(data, id)?)))
+    }
+
+    fn rebuild_aggregates(&self) -> Result<()> {
+        self.change(Change::RebuildAggregates)
+    }
+
+    fn merge(&self, from: &str, to: &str) -> Result<()> {
+        self.change(Change::Merge { from: from.to_owned(), to: to.to_owned(), })
+    }
+
+    fn set_rating(&self, filename: &str, rating: Option<i32>) -> Result<()> {
+        self.change(Change::SetRating { filename: filename.to_owned(), rating, })
+    }
+
+    fn set_favorite(&self, filename: &str, favorite: bool) -> Result<()> {
+        self.change(Change::SetFavorite { filename: filename.to_owned(), favorite, })
+    }
+
+    fn move_preferences(&self, from: &str, to: &str) -> Result<()> {
+        self.change(Change::MovePreferences { from: from.to_owned(), to: to.to_owned(), })
+    }
+
+    fn ban(&self, banned_image: &model::BannedImage) -> Result<()> {
+        self.change(Change::Ban { filename: banned_image.filename.clone(), banned_at: banned_image.banned_at, })
+    }
+
+    fn unban(&self, filename: &str) -> Result<bool> {
+        self.update(|data| Ok((Change::Unban { filename: filename.to_owned(), }, data.bans.contains_key(filename))))
+    }
+
+    fn remove(&self, hashes: &[String], paths: &[String], filenames: &[String]) -> Result<()> {
+        self.change(Change::Remove { hashes: hashes.to_vec(), paths: paths.to_vec(), filenames: filenames.to_vec(), })
+    }
+
+    fn rename_prefix(&self, from: &str, to: &str) -> Result<RenameResult> {
+        // the counts are taken from renaming a copy of the affected data
+        self.update(|data| {
+            let mut copy = Data {
+                paths: data.paths.clone(),
+                preferences: data.preferences.keys().map(|k| (k.clone(), Preferences::default())).collect(),
+                bans: data.bans.clone(),
+                ..Data::default()
+            };
+            Ok((Change::RenamePrefix { from: from.to_owned(), to: to.to_owned(), }, copy.rename_prefix(from, to)))
+        })
+    }
+}
+
+fn event_hash(data: &Data, id: i64) -> Result<String> {
+    simple_error!(UnknownEvent, "Display event does not exist");
+
+    match data.events.get(&id) {
+        Some(event) => Ok(event.hash.clone()),
+        None => Err(UnknownEvent.into()),
+    }
+}
+
+// returns the number of renamed keys
+fn rename_keys<V>(map: &mut BTreeMap<String, V>, from: &str, to: &str) -> u32 {
+    let keys: Vec<_> = map.keys()
+        .filter_map(|k| renamed_path(k, from, to).map(|r| (k.clone(), r)))
+        .filter(|(_, r)| !map.contains_key(r))
+        .collect();
+    for (key, renamed_key) in &keys {
+        move_key(map, key, renamed_key.clone());
+    }
+    keys.len() as u32
+}
+
+// moves the value unless the target key is taken
+fn move_key<V>(map: &mut BTreeMap<String, V>, from: &str, to: String) {
+    if !map.contains_key(&to) {
+        if let Some(value) = map.remove(from) {
+            map.insert(to, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::daemon::test_util::display_event;
+
+    fn line_count(path: &Path) -> usize {
+        BufReader::new(File::open(path).unwrap()).lines().count()
+    }
+
+    #[test]
+    fn log_is_replayed_on_open() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stats.jsonl");
+
+        {
+            let store = MemoryStore::open_log(&path, true).unwrap();
+            store.add_legacy_counters("abc", 5, 1, 50).unwrap();
+            let id = store.insert_event(&display_event("abc", 1000, 0, false)).unwrap();
+            store.add_counters("abc", 1, 0, 0).unwrap();
+            store.extend_event(id, 1030).unwrap();
+            store.add_counters("abc", 0, 0, 30).unwrap();
+            let path = model::ImagePath { path: "/images/a.jpg".into(), hash: "abc".into(), last_seen: 7, };
+            store.record_path(&path).unwrap();
+            store.set_rating("/images/a.jpg", Some(4)).unwrap();
+            store.ban(&model::BannedImage { filename: "/images/b.jpg".into(), banned_at: 8, }).unwrap();
+        }
+
+        let store = MemoryStore::open_log(&path, true).unwrap();
+        let stats = store.load_statistics().unwrap();
+        assert_eq!(stats.len(), 1);
+        assert_eq!((stats[0].total_displays, stats[0].total_skips, stats[0].total_display_time), (6, 1, 80));
+        let events = store.load_events().unwrap();
+        assert_eq!((events.len(), events[0].ended_at), (1, 1030));
+        assert_eq!(store.load_paths().unwrap()[0].hash, "abc");
+        assert_eq!(store.load_preferences().unwrap()[0].rating, Some(4));
+        assert_eq!(store.load_bans().unwrap()[0].filename, "/images/b.jpg");
+
+        // legacy counters survive the snapshot
+        store.rebuild_aggregates().unwrap();
+        let stats = store.load_statistics().unwrap();
+        assert_eq!((stats[0].total_displays, stats[0].total_skips, stats[0].total_display_time), (6, 1, 80));
+    }
+
+    #[test]
+    fn log_is_compacted_on_open() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stats.jsonl");
+
+        {
+            let store = MemoryStore::open_log(&path, true).unwrap();
+            let id = store.insert_event(&display_event("abc", 1000, 0, false)).unwrap();
+            for until in 1001..1101 {
+                store.extend_event(id, until).unwrap();
+            }
+        }
+        assert_eq!(line_count(&path), 101);
+
+        let store = MemoryStore::open_log(&path, true).unwrap();
+        assert_eq!(line_count(&path), 1);
+        assert_eq!(store.load_events().unwrap()[0].ended_at, 1100);
+    }
+
+    #[test]
+    fn log_is_compacted_while_growing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stats.jsonl");
+
+        let store = MemoryStore::open_log(&path, true).unwrap();
+        let id = store.insert_event(&display_event("abc", 0, 0, false)).unwrap();
+        for until in 1..COMPACT_AFTER_RECORDS as i64 + 10 {
+            store.extend_event(id, until).unwrap();
+        }
+
+        assert!(line_count(&path) < 20);
+        drop(store);
+        let store = MemoryStore::open_log(&path, true).unwrap();
+        assert_eq!(store.load_events().unwrap()[0].ended_at, COMPACT_AFTER_RECORDS as i64 + 9);
+    }
+
+    #[test]
+    fn log_is_only_compacted_by_the_daemon() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stats.jsonl");
+
+        {
+            let store = MemoryStore::open_log(&path, true).unwrap();
+            let id = store.insert_event(&display_event("abc", 1000, 0, false)).unwrap();
+            store.extend_event(id, 1001).unwrap();
+        }
+
+        let store = MemoryStore::open_log(&path, false).unwrap();
+        assert_eq!(line_count(&path), 2);
+        store.set_rating("/images/a.jpg", Some(3)).unwrap();
+        assert_eq!(line_count(&path), 3);
+        assert_eq!(store.load_events().unwrap()[0].ended_at, 1001);
+    }
+
+    #[test]
+    fn transactions_are_logged_once_they_succeed() {
+        simple_error!(Failed, "Failed");
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stats.jsonl");
+        let store = MemoryStore::open_log(&path, true).unwrap();
+
+        let result = store.transaction(&mut || {
+            store.add_counters("abc", 1, 0, 0)?;
+            // changes are visible inside the transaction
+            assert_eq!(store.load_statistics()?.len(), 1);
+            Err(Failed.into())
+        });
+        assert!(result.is_err());
+        assert!(store.load_statistics().unwrap().is_empty());
+        assert_eq!(line_count(&path), 0);
+
+        // a failed nested transaction only undoes its own changes
+        store.transaction(&mut || {
+            store.add_counters("abc", 1, 0, 0)?;
+            let nested = store.transaction(&mut || {
+                store.add_counters("def", 1, 0, 0)?;
+                Err(Failed.into())
+            });
+            assert!(nested.is_err());
+            assert_eq!(line_count(&path), 0);
+            store.add_counters("abc", 0, 1, 0)
+        }).unwrap();
+        let stats = store.load_statistics().unwrap();
+        assert_eq!((stats.len(), stats[0].total_displays, stats[0].total_skips), (1, 1, 1));
+        assert_eq!(line_count(&path), 2);
+    }
+
+    #[test]
+    fn changes_of_unknown_events_are_not_logged() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stats.jsonl");
+        let store = MemoryStore::open_log(&path, true).unwrap();
+
+        assert!(store.extend_event(1, 1000).is_err());
+        assert!(store.skip_event(1).is_err());
+        assert_eq!(line_count(&path), 0);
+    }
+}
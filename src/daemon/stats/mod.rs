@@ -0,0 +1,1673 @@
+use std::path::{Path, PathBuf};
+use std::error::Error;
+use std::collections::{HashMap, HashSet};
+use std::cmp::Reverse;
+use std::cell::{Cell, RefCell};
+use std::fs::{self, File};
+use std::io;
+use std::iter;
+use std::sync::Arc;
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::thread::{self, JoinHandle};
+use std::time::SystemTime;
+
+use chrono::{DateTime, Local, TimeZone, Utc};
+use parking_lot::Mutex;
+use serde_derive::{Serialize, Deserialize};
+use sha2::{Sha256, Digest};
+
+use crate::common::config::StatsStorage;
+use crate::common::grpc::wcd;
+use crate::common::proto::{StatsQuery, StatsSort, ReportQuery, ReportGrouping};
+use crate::common::util;
+use crate::daemon::error::RequestError;
+
+mod sqlite;
+mod memory;
+
+pub mod model {
+    use serde_derive::{Serialize, Deserialize};
+
+    use crate::common::grpc::wcd;
+    use super::sqlite::schema::*;
+
+    #[derive(Queryable, Serialize, Deserialize)]
+    pub struct ImageStatistics {
+        pub hash: String,
+        pub total_displays: i64,
+        pub total_skips: i64,
+        #[serde(rename = "total_display_seconds")]
+        pub total_display_time: i64,
+    }
+
+    #[derive(Queryable, Insertable, Serialize, Deserialize)]
+    #[table_name="image_preferences"]
+    pub struct ImagePreferences {
+        pub filename: String,
+        pub rating: Option<i32>,
+        pub favorite: bool,
+    }
+
+    // statistics of an image combined with its last known path and the preferences set for it
+    pub struct ImageInfo {
+        pub filename: String,
+        pub stats: ImageStatistics,
+        pub rating: Option<i32>,
+        pub favorite: bool,
+    }
+
+    impl Into<wcd::ImageStatsInfo> for ImageInfo {
+        fn into(self) -> wcd::ImageStatsInfo {
+            let mut proto = wcd::ImageStatsInfo::new();
+            proto.set_filename(self.filename);
+            proto.set_total_displays(self.stats.total_displays);
+            proto.set_total_skips(self.stats.total_skips);
+            proto.set_total_display_time(self.stats.total_display_time);
+            proto.set_rating(self.rating.unwrap_or(0) as u32);
+            proto.set_favorite(self.favorite);
+            proto
+        }
+    }
+
+    #[derive(Insertable)]
+    #[table_name="image_statistics"]
+    pub struct NewImageStatistics<'a> {
+        pub hash: &'a str,
+    }
+
+    #[derive(Queryable, Insertable, Serialize, Deserialize)]
+    #[table_name="image_paths"]
+    pub struct ImagePath {
+        pub path: String,
+        pub hash: String,
+        pub last_seen: i64,
+    }
+
+    #[derive(Queryable, Insertable, Serialize, Deserialize)]
+    #[table_name="banned_images"]
+    pub struct BannedImage {
+        pub filename: String,
+        pub banned_at: i64,
+    }
+
+    impl Into<wcd::BannedImage> for BannedImage {
+        fn into(self) -> wcd::BannedImage {
+            let mut proto = wcd::BannedImage::new();
+            proto.set_filename(self.filename);
+            proto.set_banned_at(self.banned_at);
+            proto
+        }
+    }
+
+    #[derive(Insertable)]
+    #[table_name="image_preferences"]
+    pub struct NewImagePreferences<'a> {
+        pub filename: &'a str,
+    }
+
+    #[derive(Insertable)]
+    #[table_name="display_events"]
+    pub struct NewDisplayEvent<'a> {
+        pub filename: &'a str,
+        pub hash: &'a str,
+        pub channel: &'a str,
+        pub playlist: &'a str,
+        pub started_at: i64,
+        pub ended_at: i64,
+        pub reason: &'a str,
+        pub skipped: bool,
+    }
+
+    // row ids are local to a database, so they are not loaded
+    #[derive(Queryable, Serialize, Deserialize, Clone)]
+    pub struct DisplayEvent {
+        pub filename: String,
+        pub channel: String,
+        pub playlist: String,
+        pub started_at: i64,
+        pub ended_at: i64,
+        pub reason: String,
+        pub skipped: bool,
+        pub hash: String,
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DisplayReason {
+    // the image was changed by the scheduler
+    Scheduled,
+    // the image was changed by a client request
+    Manual,
+    // the image was changed because its playlist was selected
+    Select,
+    // the image was restored because its playlist was selected again
+    Restore,
+}
+
+impl DisplayReason {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            DisplayReason::Scheduled => "scheduled",
+            DisplayReason::Manual => "manual",
+            DisplayReason::Select => "select",
+            DisplayReason::Restore => "restore",
+        }
+    }
+}
+
+impl Into<wcd::DisplayReason> for DisplayReason {
+    fn into(self) -> wcd::DisplayReason {
+        match self {
+            DisplayReason::Scheduled => wcd::DisplayReason::SCHEDULED,
+            DisplayReason::Manual => wcd::DisplayReason::MANUAL,
+            DisplayReason::Select => wcd::DisplayReason::SELECT,
+            DisplayReason::Restore => wcd::DisplayReason::RESTORE,
+        }
+    }
+}
+
+// describes where and why an image is displayed
+#[derive(Debug, Copy, Clone)]
+pub struct DisplayContext<'a> {
+    pub channel: &'a str,
+    pub playlist: &'a str,
+    pub reason: DisplayReason,
+}
+
+// identifies display events in the daemon; events get their database ids only when they are written
+pub type EventId = u64;
+
+// number of updates which may wait for the writer thread before further updates are dropped
+const WRITE_QUEUE_SIZE: usize = 1024;
+// maximum number of updates written in one transaction
+const MAX_WRITE_BATCH: usize = 128;
+
+// updates of display events which are written by the writer thread
+enum Write {
+    Start {
+        event_id: EventId,
+        file_name: String,
+        channel: String,
+        playlist: String,
+        reason: DisplayReason,
+        at: DateTime<Utc>,
+    },
+    Extend {
+        event_id: EventId,
+        until: DateTime<Utc>,
+        time_sec: i64,
+    },
+    End {
+        event_id: EventId,
+        skipped: bool,
+    },
+}
+
+// prefix of identities of images which are identified by their paths rather than their content
+pub const PATH_HASH_PREFIX: &str = "path:";
+
+pub const MIN_RATING: u8 = 1;
+pub const MAX_RATING: u8 = 5;
+
+// user preferences which affect the selection of images, both explicit and derived from statistics
+#[derive(Debug, Clone, Default)]
+pub struct Preferences {
+    pub statistics: HashMap<PathBuf, ImageCounters>,
+    pub ratings: HashMap<PathBuf, u8>,
+    pub favorites: HashSet<PathBuf>,
+    // banned images are never displayed
+    pub banned: HashSet<PathBuf>,
+    // known paths of every image, all of which share its statistics
+    paths: HashMap<String, HashSet<PathBuf>>,
+}
+
+impl Preferences {
+    // adds to the statistics of every known path of the image; `path` becomes known if it is not yet
+    fn add_counters(&mut self, content_hash: &str, path: Option<&Path>, displays: i64, skips: i64) {
+        let statistics = &mut self.statistics;
+        let paths = self.paths.entry(content_hash.to_owned()).or_default();
+        if let Some(path) = path {
+            if !paths.contains(path) {
+                let counters = paths.iter().find_map(|p| statistics.get(p)).cloned().unwrap_or_default();
+                statistics.insert(path.to_owned(), counters);
+                paths.insert(path.to_owned());
+            }
+        }
+        for path in paths.iter() {
+            let counters = statistics.entry(path.clone()).or_default();
+            counters.displays += displays;
+            counters.skips += skips;
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ImageCounters {
+    pub displays: i64,
+    pub skips: i64,
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct RelinkResult {
+    // statistics identified by paths which got content hashes
+    pub merged: u32,
+    // files which got statistics recorded under other paths
+    pub relinked: u32,
+}
+
+impl Into<wcd::RelinkResult> for RelinkResult {
+    fn into(self) -> wcd::RelinkResult {
+        let mut proto = wcd::RelinkResult::new();
+        proto.set_merged(self.merged);
+        proto.set_relinked(self.relinked);
+        proto
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GcResult {
+    // last known paths of images whose statistics were removed
+    pub images: Vec<String>,
+    pub paths: u32,
+    pub preferences: u32,
+    // nothing was actually removed
+    pub dry_run: bool,
+}
+
+impl Into<wcd::GcResult> for GcResult {
+    fn into(self) -> wcd::GcResult {
+        let mut proto = wcd::GcResult::new();
+        proto.set_images(::protobuf::RepeatedField::from_vec(self.images));
+        proto.set_paths(self.paths);
+        proto.set_preferences(self.preferences);
+        proto.set_dry_run(self.dry_run);
+        proto
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct RenameResult {
+    pub paths: u32,
+    pub preferences: u32,
+    // renamed records which were merged with the records already existing under the new prefix
+    pub merged: u32,
+}
+
+impl Into<wcd::RenameResult> for RenameResult {
+    fn into(self) -> wcd::RenameResult {
+        let mut proto = wcd::RenameResult::new();
+        proto.set_paths(self.paths);
+        proto.set_preferences(self.preferences);
+        proto.set_merged(self.merged);
+        proto
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ImportResult {
+    pub images: u32,
+    pub events: u32,
+    // events which were already present in the database
+    pub duplicate_events: u32,
+}
+
+impl Into<wcd::ImportResult> for ImportResult {
+    fn into(self) -> wcd::ImportResult {
+        let mut proto = wcd::ImportResult::new();
+        proto.set_images(self.images);
+        proto.set_events(self.events);
+        proto.set_duplicate_events(self.duplicate_events);
+        proto
+    }
+}
+
+// display statistics of a group of display events
+#[derive(Debug, Clone, Default)]
+pub struct ReportGroup {
+    pub name: String,
+    pub display_time: i64,
+    pub displays: i64,
+    pub skips: i64,
+}
+
+impl Into<wcd::ReportGroup> for ReportGroup {
+    fn into(self) -> wcd::ReportGroup {
+        let mut proto = wcd::ReportGroup::new();
+        proto.set_name(self.name);
+        proto.set_display_time(self.display_time);
+        proto.set_displays(self.displays);
+        proto.set_skips(self.skips);
+        proto
+    }
+}
+
+// version of the statistics export format
+const EXPORT_VERSION: u32 = 1;
+
+// everything recorded in the statistics database, in a form which can be moved between machines
+#[derive(Serialize, Deserialize)]
+struct Export {
+    version: u32,
+    images: Vec<model::ImageStatistics>,
+    paths: Vec<model::ImagePath>,
+    events: Vec<model::DisplayEvent>,
+    preferences: Vec<model::ImagePreferences>,
+    bans: Vec<model::BannedImage>,
+}
+
+pub type Result<T> = ::std::result::Result<T, Box<dyn Error>>;  // unit for now
+
+// applies filters, ordering and limit of the query to statistics loaded in file name order
+pub fn select(
+    mut image_stats: Vec<model::ImageInfo>,
+    query: &StatsQuery,
+    playlist_images: Option<&HashSet<String>>,
+) -> Vec<model::ImageInfo> {
+    if let Some(images) = playlist_images {
+        image_stats.retain(|s| images.contains(&s.filename));
+    }
+
+    if query.missing_only {
+        image_stats.retain(|s| !Path::new(&s.filename).exists());
+    }
+
+    // sorting is stable, so ties stay ordered by file name
+    match query.sort {
+        StatsSort::Filename => {}
+        StatsSort::Displays => image_stats.sort_by_key(|s| Reverse(s.stats.total_displays)),
+        StatsSort::Skips => image_stats.sort_by_key(|s| Reverse(s.stats.total_skips)),
+        StatsSort::DisplayTime => image_stats.sort_by_key(|s| Reverse(s.stats.total_display_time)),
+    }
+
+    if let Some(top) = query.top {
+        image_stats.truncate(top as usize);
+    }
+
+    image_stats
+}
+
+#[derive(Clone)]
+pub struct Stats {
+    daemon: super::Daemon,
+}
+
+impl Stats {
+    pub fn new(daemon: super::Daemon) -> Stats {
+        Stats { daemon, }
+    }
+
+    // display events are written by the writer thread, so they never block the caller;
+    // returns the id of the new display event, if statistics are enabled
+    pub fn start_display(&self, file_name: &str, context: DisplayContext, at: DateTime<Utc>) -> Option<EventId> {
+        if let Some(ref writer) = self.daemon.state.lock().stats_writer {
+            let writer = writer.borrow();
+            let event_id = writer.new_event_id();
+            writer.send(Write::Start {
+                event_id,
+                file_name: file_name.to_owned(),
+                channel: context.channel.to_owned(),
+                playlist: context.playlist.to_owned(),
+                reason: context.reason,
+                at,
+            });
+            Some(event_id)
+        } else {
+            None
+        }
+    }
+
+    pub fn extend_display(&self, event_id: EventId, until: DateTime<Utc>, time_sec: i64) {
+        if let Some(ref writer) = self.daemon.state.lock().stats_writer {
+            writer.borrow().send(Write::Extend { event_id, until, time_sec, });
+        }
+    }
+
+    pub fn end_display(&self, event_id: EventId, skipped: bool) {
+        if let Some(ref writer) = self.daemon.state.lock().stats_writer {
+            writer.borrow().send(Write::End { event_id, skipped, });
+        }
+    }
+
+    // the number of updates dropped because the writer thread fell behind
+    pub fn dropped_writes(&self) -> u64 {
+        if let Some(ref writer) = self.daemon.state.lock().stats_writer {
+            writer.borrow().dropped_writes()
+        } else {
+            0
+        }
+    }
+
+    // waits until the queued updates are written; later updates are dropped
+    pub fn stop_writer(&self) {
+        if let Some(ref writer) = self.daemon.state.lock().stats_writer {
+            writer.borrow_mut().stop();
+        }
+    }
+
+    pub fn rebuild_aggregates(&self) -> Result<()> {
+        if let Some(ref stats) = self.daemon.stats {
+            stats.lock().rebuild_aggregates()
+        } else {
+            Ok(())
+        }
+    }
+
+    // `images` are the files which orphaned statistics may be reattached to
+    pub fn relink(&self, images: &[PathBuf]) -> Result<RelinkResult> {
+        if let Some(ref stats) = self.daemon.stats {
+            stats.lock().relink(images)
+        } else {
+            Ok(RelinkResult::default())
+        }
+    }
+
+    pub fn gc(&self, dry_run: bool) -> Result<GcResult> {
+        if let Some(ref stats) = self.daemon.stats {
+            stats.lock().gc(dry_run)
+        } else {
+            Ok(GcResult::default())
+        }
+    }
+
+    pub fn rename_prefix(&self, old_prefix: &str, new_prefix: &str) -> Result<RenameResult> {
+        if let Some(ref stats) = self.daemon.stats {
+            stats.lock().rename_prefix(old_prefix, new_prefix)
+        } else {
+            Ok(RenameResult::default())
+        }
+    }
+
+    // returns the exported statistics as JSON
+    pub fn export(&self) -> Result<String> {
+        if let Some(ref stats) = self.daemon.stats {
+            stats.lock().export()
+        } else {
+            Ok(String::new())
+        }
+    }
+
+    pub fn import(&self, data: &str, merge: bool) -> Result<ImportResult> {
+        if let Some(ref stats) = self.daemon.stats {
+            stats.lock().import(data, merge)
+        } else {
+            Ok(ImportResult::default())
+        }
+    }
+
+    pub fn report(&self, query: &ReportQuery) -> Result<Vec<ReportGroup>> {
+        if let Some(ref stats) = self.daemon.stats {
+            stats.lock().report(query)
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    pub fn load(&self) -> Result<Vec<model::ImageInfo>> {
+        if let Some(ref stats) = self.daemon.stats {
+            stats.lock().load()
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    // `None` removes the rating
+    pub fn set_rating(&self, file_name: &str, rating: Option<u8>) -> Result<()> {
+        if let Some(ref stats) = self.daemon.stats {
+            stats.lock().set_rating(file_name, rating)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn set_favorite(&self, file_name: &str, favorite: bool) -> Result<()> {
+        if let Some(ref stats) = self.daemon.stats {
+            stats.lock().set_favorite(file_name, favorite)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn ban(&self, file_name: &str, at: DateTime<Utc>) -> Result<()> {
+        if let Some(ref stats) = self.daemon.stats {
+            stats.lock().ban(file_name, at)
+        } else {
+            Ok(())
+        }
+    }
+
+    // returns false if the image was not banned
+    pub fn unban(&self, file_name: &str) -> Result<bool> {
+        if let Some(ref stats) = self.daemon.stats {
+            stats.lock().unban(file_name)
+        } else {
+            Ok(false)
+        }
+    }
+
+    pub fn load_bans(&self) -> Result<Vec<model::BannedImage>> {
+        if let Some(ref stats) = self.daemon.stats {
+            stats.lock().load_bans()
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    // preferences are cached, so selecting images neither reads the whole database nor waits for other
+    // operations on the statistics
+    pub fn preferences(&self) -> Arc<Preferences> {
+        if let Some(ref writer) = self.daemon.state.lock().stats_writer {
+            writer.borrow().preferences()
+        } else {
+            Arc::new(Preferences::default())
+        }
+    }
+}
+
+// storage of statistics, preferences and bans; all methods may be called from the daemon and from
+// the writer thread, each of which has its own instance of the store
+pub trait StatsStore: Send {
+    // opens another instance of the store for the writer thread
+    fn open_writer(&self) -> Result<Box<dyn StatsStore>>;
+    // runs `f` atomically, if the store supports it
+    fn transaction(&self, f: &mut dyn FnMut() -> Result<()>) -> Result<()>;
+
+    fn load_statistics(&self) -> Result<Vec<model::ImageStatistics>>;
+    // paths are ordered by the time they were last seen
+    fn load_paths(&self) -> Result<Vec<model::ImagePath>>;
+    // events are ordered by the time they were recorded
+    fn load_events(&self) -> Result<Vec<model::DisplayEvent>>;
+    fn load_preferences(&self) -> Result<Vec<model::ImagePreferences>>;
+    // bans are ordered by the time they were made
+    fn load_bans(&self) -> Result<Vec<model::BannedImage>>;
+    fn has_event(&self, hash: &str, channel: &str, started_at: i64) -> Result<bool>;
+
+    fn record_path(&self, image_path: &model::ImagePath) -> Result<()>;
+    // creates the statistics of the image if they do not exist yet
+    fn add_counters(&self, hash: &str, displays: i64, skips: i64, time_sec: i64) -> Result<()>;
+    // adds counters which are not backed by display events, like the ones recorded before display events
+    // existed; they are kept when the statistics are rebuilt
+    fn add_legacy_counters(&self, hash: &str, displays: i64, skips: i64, time_sec: i64) -> Result<()>;
+    // returns the id of the event in the store
+    fn insert_event(&self, event: &model::NewDisplayEvent) -> Result<i64>;
+    // these return the hash of the image of the event
+    fn extend_event(&self, id: i64, until: i64) -> Result<String>;
+    fn skip_event(&self, id: i64) -> Result<String>;
+    // recomputes the statistics of all images from their display events and legacy counters
+    fn rebuild_aggregates(&self) -> Result<()>;
+    // moves all statistics of one identity to another one
+    fn merge(&self, from_hash: &str, to_hash: &str) -> Result<()>;
+    fn set_rating(&self, filename: &str, rating: Option<i32>) -> Result<()>;
+    fn set_favorite(&self, filename: &str, favorite: bool) -> Result<()>;
+    // moves preferences and the ban of an image to another path, unless that path has its own
+    fn move_preferences(&self, old_path: &str, new_path: &str) -> Result<()>;
+    fn ban(&self, banned_image: &model::BannedImage) -> Result<()>;
+    // returns false if the image was not banned
+    fn unban(&self, filename: &str) -> Result<bool>;
+    // removes statistics and events of the hashes, the paths, and preferences and bans of the files
+    fn remove(&self, hashes: &[String], paths: &[String], filenames: &[String]) -> Result<()>;
+    // rewrites all paths equal to `old_prefix` or lying under it, including identities of images
+    // identified by their paths; records which would conflict with existing ones are left as they are
+    fn rename_prefix(&self, old_prefix: &str, new_prefix: &str) -> Result<RenameResult>;
+}
+
+pub struct State {
+    store: Box<dyn StatsStore>,
+    // content hashes of files along with their sizes and modification times at the moment of hashing
+    hashes: RefCell<HashMap<PathBuf, (u64, SystemTime, String)>>,
+    // shared with the writer thread, which updates the statistics in them after writing display events
+    preferences: Arc<Mutex<Arc<Preferences>>>,
+}
+
+impl State {
+    // also starts the writer thread, which has its own state
+    pub fn new(storage: &StatsStorage) -> Result<(State, Writer)> {
+        let store = open_store(storage, true)?;
+        let preferences = read_preferences(&*store).unwrap_or_else(|e| {
+            warn!("Failed to load image preferences: {}", e);
+            Preferences::default()
+        });
+        let preferences = Arc::new(Mutex::new(Arc::new(preferences)));
+        let writer_state = State {
+            store: store.open_writer()?,
+            hashes: RefCell::new(HashMap::new()),
+            preferences: preferences.clone(),
+        };
+
+        Ok((
+            State { store, hashes: RefCell::new(HashMap::new()), preferences, },
+            Writer::start(writer_state)?,
+        ))
+    }
+
+    // opens the statistics without the writer thread, for working with them while the daemon is not running
+    pub fn open(storage: &StatsStorage) -> Result<State> {
+        simple_error!(MemoryStorage, "Statistics kept in memory are only available from the running daemon");
+
+        if let StatsStorage::Memory = *storage {
+            return Err(MemoryStorage.into());
+        }
+        // nothing is selected without the daemon, so preferences are not loaded
+        Ok(State {
+            store: open_store(storage, false)?,
+            hashes: RefCell::new(HashMap::new()),
+            preferences: Arc::default(),
+        })
+    }
+
+    // writes a batch of updates in a single transaction; `rows` maps ids of display events which have
+    // not ended yet to their database ids
+    fn write_batch(&self, batch: Vec<Write>, rows: &mut HashMap<EventId, i64>) {
+        debug!("Writing {} statistics updates", batch.len());
+        let batch_len = batch.len();
+        let mut started = Vec::new();
+        let mut counted = Vec::new();
+
+        let mut batch = batch.into_iter();
+        let result = self.store.transaction(&mut || {
+            for write in &mut batch {
+                // updates run in nested transactions, so a failed one does not take the rest of the batch with it
+                if let Err(e) = self.write(write, rows, &mut started, &mut counted) {
+                    warn!("Failed to update statistics: {}", e);
+                }
+            }
+            Ok(())
+        });
+
+        if let Err(e) = result {
+            warn!("Failed to write {} statistics updates: {}", batch_len, e);
+            for event_id in started {
+                rows.remove(&event_id);
+            }
+            return;
+        }
+
+        // the written counters are added to the cached statistics, so the store is not read again
+        if !counted.is_empty() {
+            let mut preferences = self.preferences.lock();
+            let preferences = Arc::make_mut(&mut *preferences);
+            for c in counted {
+                preferences.add_counters(&c.content_hash, c.path.as_deref(), c.displays, c.skips);
+            }
+        }
+    }
+
+    // `counted` receives the changes of display and skip counters made by the update
+    fn write(&self, write: Write, rows: &mut HashMap<EventId, i64>, started: &mut Vec<EventId>,
+             counted: &mut Vec<CountersChange>) -> Result<()> {
+        match write {
+            Write::Start { event_id, file_name, channel, playlist, reason, at, } => {
+                let context = DisplayContext { channel: &channel, playlist: &playlist, reason, };
+                let (row, path, content_hash) = self.start_display(&file_name, context, at)?;
+                rows.insert(event_id, row);
+                started.push(event_id);
+                counted.push(CountersChange { content_hash, path: Some(PathBuf::from(path)), displays: 1, skips: 0, });
+            }
+            // events whose start was dropped are not recorded at all
+            Write::Extend { event_id, until, time_sec, } => {
+                if let Some(&row) = rows.get(&event_id) {
+                    self.extend_display(row, until, time_sec)?;
+                }
+            }
+            Write::End { event_id, skipped, } => {
+                if let Some(row) = rows.remove(&event_id) {
+                    if let Some(content_hash) = self.end_display(row, skipped)? {
+                        counted.push(CountersChange { content_hash, path: None, displays: 0, skips: 1, });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // returns the canonical path and the content hash of the file, and records the path as the last known
+    // path of this content
+    fn identify(&self, file_name: &str) -> Result<(String, String)> {
+        let path = util::canonical_path(Path::new(file_name));
+        let path_str = path.to_string_lossy().into_owned();
+        let content_hash = match self.content_hash(&path) {
+            Ok(content_hash) => content_hash,
+            Err(e) => {
+                warn!("Failed to compute the content hash of {}, using its path instead: {}", path.display(), e);
+                path_hash(&path_str)
+            }
+        };
+
+        self.store.record_path(
+            &model::ImagePath { path: path_str.clone(), hash: content_hash.clone(), last_seen: Utc::now().timestamp(), }
+        )?;
+
+        Ok((path_str, content_hash))
+    }
+
+    fn content_hash(&self, path: &Path) -> io::Result<String> {
+        let metadata = fs::metadata(path)?;
+        let (size, modified) = (metadata.len(), metadata.modified()?);
+
+        if let Some(&(cached_size, cached_modified, ref content_hash)) = self.hashes.borrow().get(path) {
+            if cached_size == size && cached_modified == modified {
+                return Ok(content_hash.clone());
+            }
+        }
+
+        let mut hasher = Sha256::new();
+        io::copy(&mut File::open(path)?, &mut hasher)?;
+        let content_hash = format!("{:x}", hasher.finalize());
+
+        self.hashes.borrow_mut().insert(path.to_owned(), (size, modified, content_hash.clone()));
+        Ok(content_hash)
+    }
+
+    // returns the id of the display event along with the canonical path and the content hash of the image
+    fn start_display(&self, file_name: &str, context: DisplayContext, at: DateTime<Utc>) -> Result<(i64, String, String)> {
+        debug!("Starting display of {} in channel {}, playlist {}", file_name, context.channel, context.playlist);
+        let mut event_id = 0;
+        let mut identity = (String::new(), String::new());
+        self.store.transaction(&mut || {
+            let (path, content_hash) = self.identify(file_name)?;
+
+            event_id = self.store.insert_event(&model::NewDisplayEvent {
+                filename: &path,
+                hash: &content_hash,
+                channel: context.channel,
+                playlist: context.playlist,
+                started_at: at.timestamp(),
+                ended_at: at.timestamp(),
+                reason: context.reason.as_str(),
+                skipped: false,
+            })?;
+
+            self.store.add_counters(&content_hash, 1, 0, 0)?;
+            identity = (path, content_hash);
+            Ok(())
+        })?;
+        Ok((event_id, identity.0, identity.1))
+    }
+
+    fn extend_display(&self, event_id: i64, until: DateTime<Utc>, time_sec: i64) -> Result<()> {
+        self.store.transaction(&mut || {
+            let content_hash = self.store.extend_event(event_id, until.timestamp())?;
+            self.store.add_counters(&content_hash, 0, 0, time_sec)
+        })
+    }
+
+    // returns the content hash of the image if the display was skipped
+    fn end_display(&self, event_id: i64, is_skipped: bool) -> Result<Option<String>> {
+        debug!("Ending display event {}", event_id);
+        if !is_skipped {
+            return Ok(None);
+        }
+
+        let mut skipped = None;
+        self.store.transaction(&mut || {
+            let content_hash = self.store.skip_event(event_id)?;
+            self.store.add_counters(&content_hash, 0, 1, 0)?;
+            skipped = Some(content_hash);
+            Ok(())
+        })?;
+        Ok(skipped)
+    }
+
+    fn rebuild_aggregates(&self) -> Result<()> {
+        info!("Rebuilding image statistics from display events");
+        self.store.rebuild_aggregates()?;
+        self.reload_preferences();
+        Ok(())
+    }
+
+    fn merge(&self, from_hash: &str, to_hash: &str) -> Result<()> {
+        debug!("Merging statistics of {} into {}", from_hash, to_hash);
+        self.store.merge(from_hash, to_hash)
+    }
+
+    // reattaches statistics identified by paths and statistics without existing files to the given images
+    fn relink(&self, images: &[PathBuf]) -> Result<RelinkResult> {
+        info!("Relinking image statistics");
+        let mut result = RelinkResult::default();
+
+        let image_paths = self.store.load_paths()?;
+        let mut paths_by_hash: HashMap<&str, Vec<&str>> = HashMap::new();
+        for p in &image_paths {
+            paths_by_hash.entry(&p.hash).or_default().push(&p.path);
+        }
+
+        // statistics recorded before content hashes were introduced get content hashes if their files still exist
+        for content_hash in self.store.load_statistics()?.into_iter().map(|s| s.hash) {
+            if let Some(path) = content_hash.strip_prefix(PATH_HASH_PREFIX) {
+                if Path::new(path).exists() {
+                    let (_, real_hash) = self.identify(path)?;
+                    if real_hash != content_hash {
+                        self.merge(&content_hash, &real_hash)?;
+                        result.merged += 1;
+                    }
+                }
+            }
+        }
+
+        // statistics none of whose paths exist are orphaned
+        let orphaned: HashSet<&str> = paths_by_hash.iter()
+            .filter(|&(h, paths)| !h.starts_with(PATH_HASH_PREFIX) && !paths.iter().any(|p| Path::new(p).exists()))
+            .map(|(h, _)| *h)
+            .collect();
+
+        if !orphaned.is_empty() {
+            let known_paths: HashSet<&str> = image_paths.iter().map(|p| &*p.path).collect();
+            for image in images {
+                let image = util::canonical_path(image);
+                if known_paths.contains(&*image.to_string_lossy()) {
+                    continue;
+                }
+                match self.content_hash(&image) {
+                    Ok(ref content_hash) if orphaned.contains(&**content_hash) => {
+                        info!("Relinking statistics of {} to {}", content_hash, image.display());
+                        let (path, _) = self.identify(&image.to_string_lossy())?;
+                        self.move_preferences(&paths_by_hash[&**content_hash], &path)?;
+                        result.relinked += 1;
+                    }
+                    Ok(_) => {}
+                    Err(e) => warn!("Failed to compute the content hash of {}: {}", image.display(), e),
+                }
+            }
+        }
+
+        self.reload_preferences();
+        Ok(result)
+    }
+
+    // moves preferences and bans of old paths of an image to its new path
+    fn move_preferences(&self, old_paths: &[&str], new_path: &str) -> Result<()> {
+        for old_path in old_paths {
+            self.store.move_preferences(old_path, new_path)?;
+        }
+        Ok(())
+    }
+
+    // removes statistics of images none of whose paths exist, along with missing paths and preferences
+    // and bans of missing files
+    fn gc(&self, dry_run: bool) -> Result<GcResult> {
+        info!("Collecting garbage in image statistics{}", if dry_run { " (dry run)" } else { "" });
+        let mut result = GcResult { dry_run, ..GcResult::default() };
+
+        // paths are ordered by the time they were last seen, so the last one is the last known path
+        let mut last_paths: HashMap<String, String> = HashMap::new();
+        let mut existing_hashes = HashSet::new();
+        let mut missing_paths = Vec::new();
+        for p in self.store.load_paths()? {
+            if Path::new(&p.path).exists() {
+                existing_hashes.insert(p.hash.clone());
+            } else {
+                missing_paths.push(p.path.clone());
+            }
+            last_paths.insert(p.hash, p.path);
+        }
+
+        let removed_hashes: Vec<String> = self.store.load_statistics()?.into_iter()
+            .map(|s| s.hash)
+            .filter(|h| !existing_hashes.contains(h))
+            .collect();
+        for h in &removed_hashes {
+            result.images.push(
+                last_paths.get(h).cloned().unwrap_or_else(|| h.trim_start_matches(PATH_HASH_PREFIX).to_owned())
+            );
+        }
+        result.images.sort();
+        result.paths = missing_paths.len() as u32;
+
+        let missing_preferences: Vec<String> = self.store.load_preferences()?.into_iter()
+            .map(|p| p.filename)
+            .chain(self.load_bans()?.into_iter().map(|b| b.filename))
+            .filter(|f| !Path::new(f).exists())
+            .collect();
+        result.preferences = missing_preferences.len() as u32;
+
+        if dry_run {
+            return Ok(result);
+        }
+
+        self.store.remove(&removed_hashes, &missing_paths, &missing_preferences)?;
+        self.reload_preferences();
+
+        Ok(result)
+    }
+
+    // rewrites all recorded paths under `old_prefix` to be under `new_prefix`; records which already exist
+    // under `new_prefix` are merged with the renamed ones
+    fn rename_prefix(&self, old_prefix: &str, new_prefix: &str) -> Result<RenameResult> {
+        // the new location exists, so it is recorded the same way as paths of displayed images
+        let new_prefix = util::canonical_path(Path::new(new_prefix)).to_string_lossy().into_owned();
+        let old_prefix = old_prefix.trim_end_matches('/');
+        let new_prefix = new_prefix.trim_end_matches('/');
+        // renamed records would be renamed again
+        if renamed_path(new_prefix, old_prefix, new_prefix).is_some() {
+            return Err(RequestError::InvalidArgument("The new prefix must not lie under the old one".into()).into());
+        }
+        info!("Renaming recorded paths from {} to {}", old_prefix, new_prefix);
+
+        let mut result = RenameResult::default();
+        self.store.transaction(&mut || {
+            result = RenameResult::default();
+
+            // statistics recorded before content hashes were introduced are identified by their paths
+            let (old_hash, new_hash) = (path_hash(old_prefix), path_hash(new_prefix));
+            let hashes: HashSet<_> = self.store.load_statistics()?.into_iter().map(|s| s.hash).collect();
+            for hash in &hashes {
+                match renamed_path(hash, &old_hash, &new_hash) {
+                    Some(ref renamed) if hashes.contains(renamed) => {
+                        self.merge(hash, renamed)?;
+                        result.merged += 1;
+                    }
+                    _ => {}
+                }
+            }
+
+            // when the new path is already known, it is more recent than the renamed one
+            let paths: HashSet<_> = self.store.load_paths()?.into_iter().map(|p| p.path).collect();
+            let stale_paths: Vec<_> = paths.iter()
+                .filter(|p| renamed_path(p, old_prefix, new_prefix).map(|r| paths.contains(&r)).unwrap_or(false))
+                .cloned()
+                .collect();
+
+            // ratings of the new path take precedence, and an image stays banned or a favorite if either path is
+            let preferences: HashMap<_, _> = self.store.load_preferences()?.into_iter()
+                .map(|p| (p.filename.clone(), p))
+                .collect();
+            let bans: HashMap<_, _> = self.store.load_bans()?.into_iter()
+                .map(|b| (b.filename, b.banned_at))
+                .collect();
+            let mut merged_files = Vec::new();
+            for filename in preferences.keys().chain(bans.keys()).collect::<HashSet<_>>() {
+                let renamed = match renamed_path(filename, old_prefix, new_prefix) {
+                    Some(renamed) => renamed,
+                    None => continue,
+                };
+                if !preferences.contains_key(&renamed) && !bans.contains_key(&renamed) {
+                    continue;
+                }
+
+                if let Some(old) = preferences.get(filename) {
+                    let new = preferences.get(&renamed);
+                    self.store.set_rating(&renamed, new.and_then(|p| p.rating).or(old.rating))?;
+                    self.store.set_favorite(&renamed, new.map(|p| p.favorite).unwrap_or(false) || old.favorite)?;
+                }
+                if let Some(&banned_at) = bans.get(filename) {
+                    self.store.ban(&model::BannedImage { filename: renamed, banned_at, })?;
+                }
+                merged_files.push(filename.clone());
+            }
+
+            self.store.remove(&[], &stale_paths, &merged_files)?;
+            result.merged += (stale_paths.len() + merged_files.len()) as u32;
+
+            // nothing is left to conflict with the renamed records
+            let renamed = self.store.rename_prefix(old_prefix, new_prefix)?;
+            result.paths = renamed.paths + stale_paths.len() as u32;
+            result.preferences = renamed.preferences + merged_files.len() as u32;
+            Ok(())
+        })?;
+        self.hashes.borrow_mut().clear();
+        self.reload_preferences();
+
+        Ok(result)
+    }
+
+    fn export(&self) -> Result<String> {
+        info!("Exporting image statistics");
+        let export = Export {
+            version: EXPORT_VERSION,
+            images: self.store.load_statistics()?,
+            paths: self.store.load_paths()?,
+            events: self.store.load_events()?,
+            preferences: self.store.load_preferences()?,
+            bans: self.store.load_bans()?,
+        };
+        Ok(serde_json::to_string(&export)?)
+    }
+
+    // adds exported statistics to the store; counters of images are summed, and display events which
+    // are already present are skipped along with their contribution to the counters
+    fn import(&self, data: &str, merge: bool) -> Result<ImportResult> {
+        let mut export: Export = serde_json::from_str(data)?;
+        if export.version != EXPORT_VERSION {
+            return Err(RequestError::InvalidArgument("Unsupported version of exported statistics".into()).into());
+        }
+        info!("Importing statistics of {} images", export.images.len());
+
+        let mut result = ImportResult { images: export.images.len() as u32, ..ImportResult::default() };
+        self.store.transaction(&mut || {
+            if !merge {
+                let known: HashSet<_> = self.store.load_statistics()?.into_iter().map(|s| s.hash).collect();
+                if export.images.iter().any(|s| known.contains(&s.hash)) {
+                    return Err(RequestError::FailedPrecondition(
+                        "Some of the imported images already have statistics, use merging to combine them".into()
+                    ).into());
+                }
+            }
+
+            // whatever the exported display events do not account for are legacy counters
+            let mut legacy: HashMap<String, (i64, i64, i64)> = export.images.drain(..)
+                .map(|s| (s.hash, (s.total_displays, s.total_skips, s.total_display_time)))
+                .collect();
+            let mut imported: HashMap<String, (i64, i64, i64)> = HashMap::new();
+
+            for event in export.events.drain(..) {
+                let contribution = (1, event.skipped as i64, event.ended_at - event.started_at);
+                if let Some(c) = legacy.get_mut(&event.hash) {
+                    *c = (c.0 - contribution.0, c.1 - contribution.1, c.2 - contribution.2);
+                }
+
+                if self.store.has_event(&event.hash, &event.channel, event.started_at)? {
+                    result.duplicate_events += 1;
+                    continue;
+                }
+                self.store.insert_event(&model::NewDisplayEvent {
+                    filename: &event.filename,
+                    hash: &event.hash,
+                    channel: &event.channel,
+                    playlist: &event.playlist,
+                    started_at: event.started_at,
+                    ended_at: event.ended_at,
+                    reason: &event.reason,
+                    skipped: event.skipped,
+                })?;
+                result.events += 1;
+
+                let c = imported.entry(event.hash).or_default();
+                *c = (c.0 + contribution.0, c.1 + contribution.1, c.2 + contribution.2);
+            }
+
+            for (content_hash, (displays, skips, time_sec)) in imported {
+                self.store.add_counters(&content_hash, displays, skips, time_sec)?;
+            }
+            // legacy counters cannot be told apart from known ones, so they are always added
+            for (content_hash, (displays, skips, time_sec)) in legacy {
+                self.store.add_legacy_counters(&content_hash, displays.max(0), skips.max(0), time_sec.max(0))?;
+            }
+
+            // the most recently seen association of a path wins
+            let known_paths: HashMap<_, _> = self.store.load_paths()?.into_iter()
+                .map(|p| (p.path, p.last_seen))
+                .collect();
+            for p in export.paths.drain(..) {
+                if known_paths.get(&p.path).map(|&last_seen| last_seen < p.last_seen).unwrap_or(true) {
+                    self.store.record_path(&p)?;
+                }
+            }
+
+            // local ratings take precedence, an image is a favorite if it is a favorite anywhere
+            let known_preferences: HashMap<_, _> = self.store.load_preferences()?.into_iter()
+                .map(|p| (p.filename.clone(), p))
+                .collect();
+            for p in export.preferences.drain(..) {
+                let known = known_preferences.get(&p.filename);
+                self.store.set_rating(&p.filename, known.and_then(|k| k.rating).or(p.rating))?;
+                self.store.set_favorite(&p.filename, known.map(|k| k.favorite).unwrap_or(false) || p.favorite)?;
+            }
+
+            for b in export.bans.drain(..) {
+                self.store.ban(&b)?;
+            }
+
+            Ok(())
+        })?;
+        self.reload_preferences();
+
+        Ok(result)
+    }
+
+    // creates empty statistics for the image, if they do not exist yet; returns the canonical path of the image
+    fn ensure_statistics(&self, file_name: &str) -> Result<String> {
+        let (path, content_hash) = self.identify(file_name)?;
+        self.store.add_counters(&content_hash, 0, 0, 0)?;
+        Ok(path)
+    }
+
+    pub fn set_rating(&self, file_name: &str, rating: Option<u8>) -> Result<()> {
+        debug!("Setting rating of {} to {:?}", file_name, rating);
+        let mut path = String::new();
+        self.store.transaction(&mut || {
+            path = self.ensure_statistics(file_name)?;
+            self.store.set_rating(&path, rating.map(i32::from))
+        })?;
+
+        self.update_preferences(|p| match rating {
+            Some(rating) => { p.ratings.insert(PathBuf::from(path), rating); }
+            None => { p.ratings.remove(Path::new(&path)); }
+        });
+        Ok(())
+    }
+
+    pub fn set_favorite(&self, file_name: &str, favorite: bool) -> Result<()> {
+        debug!("Setting favorite flag of {} to {}", file_name, favorite);
+        let mut path = String::new();
+        self.store.transaction(&mut || {
+            path = self.ensure_statistics(file_name)?;
+            self.store.set_favorite(&path, favorite)
+        })?;
+
+        self.update_preferences(|p| if favorite {
+            p.favorites.insert(PathBuf::from(path));
+        } else {
+            p.favorites.remove(Path::new(&path));
+        });
+        Ok(())
+    }
+
+    pub fn ban(&self, file_name: &str, at: DateTime<Utc>) -> Result<()> {
+        let path = util::canonical_path(Path::new(file_name)).to_string_lossy().into_owned();
+        info!("Banning image {}", path);
+        self.store.ban(&model::BannedImage { filename: path.clone(), banned_at: at.timestamp(), })?;
+
+        self.update_preferences(|p| { p.banned.insert(PathBuf::from(path)); });
+        Ok(())
+    }
+
+    fn unban(&self, file_name: &str) -> Result<bool> {
+        let path = util::canonical_path(Path::new(file_name)).to_string_lossy().into_owned();
+        info!("Unbanning image {}", path);
+        let unbanned = self.store.unban(&path)?;
+
+        self.update_preferences(|p| { p.banned.remove(Path::new(&path)); });
+        Ok(unbanned)
+    }
+
+    fn load_bans(&self) -> Result<Vec<model::BannedImage>> {
+        self.store.load_bans()
+    }
+
+    // changes the cached preferences after they are changed in the store
+    fn update_preferences(&self, f: impl FnOnce(&mut Preferences)) {
+        f(Arc::make_mut(&mut *self.preferences.lock()));
+    }
+
+    // reads the cached preferences from the store again after changes of many records
+    fn reload_preferences(&self) {
+        match read_preferences(&*self.store) {
+            Ok(preferences) => *self.preferences.lock() = Arc::new(preferences),
+            Err(e) => warn!("Failed to load image preferences: {}", e),
+        }
+    }
+
+    // aggregates display events which started since the given time; days are ordered chronologically,
+    // other groups by their display time
+    fn report(&self, query: &ReportQuery) -> Result<Vec<ReportGroup>> {
+        let mut groups: HashMap<String, ReportGroup> = HashMap::new();
+        for event in self.store.load_events()? {
+            if query.since.map(|since| event.started_at < since).unwrap_or(false) {
+                continue;
+            }
+
+            let name = match query.group_by {
+                ReportGrouping::Playlist => event.playlist,
+                ReportGrouping::Day => Local.timestamp_opt(event.started_at, 0).earliest()
+                    .map(|dt| dt.format("%F").to_string())
+                    .unwrap_or_default(),
+                ReportGrouping::Directory => Path::new(&event.filename).parent()
+                    .map(|dir| dir.to_string_lossy().into_owned())
+                    .unwrap_or_default(),
+            };
+
+            let group = groups.entry(name.clone()).or_insert_with(|| ReportGroup { name, ..ReportGroup::default() });
+            group.display_time += (event.ended_at - event.started_at).max(0);
+            group.displays += 1;
+            if event.skipped {
+                group.skips += 1;
+            }
+        }
+
+        let mut result: Vec<_> = groups.into_values().collect();
+        result.sort_by(|a, b| a.name.cmp(&b.name));
+        if query.group_by != ReportGrouping::Day {
+            // sorting is stable, so ties stay ordered by name
+            result.sort_by_key(|g| Reverse(g.display_time));
+        }
+
+        Ok(result)
+    }
+
+    pub fn load(&self) -> Result<Vec<model::ImageInfo>> {
+        let image_stats = self.store.load_statistics()?;
+
+        // paths are ordered by the time they were last seen, so the most recent existing path wins
+        let mut last_paths: HashMap<String, (String, bool)> = HashMap::new();
+        for p in self.store.load_paths()? {
+            let exists = Path::new(&p.path).exists();
+            let replace = match last_paths.get(&p.hash) {
+                Some(&(_, current_exists)) => exists || !current_exists,
+                None => true,
+            };
+            if replace {
+                last_paths.insert(p.hash, (p.path, exists));
+            }
+        }
+
+        let mut preferences: HashMap<_, _> = self.store.load_preferences()?.into_iter()
+            .map(|p| (p.filename.clone(), p))
+            .collect();
+
+        let mut result: Vec<_> = image_stats.into_iter()
+            .map(|stats| {
+                let filename = last_paths.get(&stats.hash).map(|p| p.0.clone())
+                    .unwrap_or_else(|| stats.hash.trim_start_matches(PATH_HASH_PREFIX).to_owned());
+                let p = preferences.remove(&filename);
+                model::ImageInfo {
+                    filename,
+                    rating: p.as_ref().and_then(|p| p.rating),
+                    favorite: p.map(|p| p.favorite).unwrap_or(false),
+                    stats,
+                }
+            })
+            .collect();
+        result.sort_by(|a, b| a.filename.cmp(&b.filename));
+
+        Ok(result)
+    }
+}
+
+// a change of display and skip counters, made by a batch of updates
+struct CountersChange {
+    content_hash: String,
+    // the path the image was displayed from, which may not be known yet
+    path: Option<PathBuf>,
+    displays: i64,
+    skips: i64,
+}
+
+// queues display events for the writer thread; it is kept in the daemon state apart from the statistics,
+// so changing images never waits for other operations on them
+pub struct Writer {
+    // the queue and the thread, absent after the thread is stopped
+    thread: Option<(SyncSender<Write>, JoinHandle<()>)>,
+    next_event_id: Cell<EventId>,
+    dropped: Cell<u64>,
+    // shared with the statistics and the writer thread
+    preferences: Arc<Mutex<Arc<Preferences>>>,
+}
+
+impl Writer {
+    // `state` is used exclusively by the writer thread
+    fn start(state: State) -> Result<Writer> {
+        let preferences = state.preferences.clone();
+        let (sender, receiver) = mpsc::sync_channel(WRITE_QUEUE_SIZE);
+        let handle = thread::Builder::new()
+            .name("stats-writer".into())
+            .spawn(move || Writer::run(state, receiver))?;
+
+        Ok(Writer {
+            thread: Some((sender, handle)),
+            next_event_id: Cell::new(1),
+            dropped: Cell::new(0),
+            preferences,
+        })
+    }
+
+    fn new_event_id(&self) -> EventId {
+        let event_id = self.next_event_id.get();
+        self.next_event_id.set(event_id + 1);
+        event_id
+    }
+
+    fn send(&self, write: Write) {
+        let sender = match self.thread {
+            Some((ref sender, _)) => sender,
+            None => return,
+        };
+
+        match sender.try_send(write) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) | Err(TrySendError::Disconnected(_)) => {
+                self.dropped.set(self.dropped.get() + 1);
+                warn!("Statistics database is too slow, dropped {} updates so far", self.dropped.get());
+            }
+        }
+    }
+
+    fn dropped_writes(&self) -> u64 {
+        self.dropped.get()
+    }
+
+    fn stop(&mut self) {
+        if let Some((sender, handle)) = self.thread.take() {
+            info!("Waiting for queued statistics updates to be written");
+            // the writer thread stops once the queue is closed and empty
+            drop(sender);
+            let _ = handle.join();
+        }
+    }
+
+    pub fn preferences(&self) -> Arc<Preferences> {
+        self.preferences.lock().clone()
+    }
+
+    fn run(state: State, receiver: Receiver<Write>) {
+        info!("Starting statistics writer thread");
+
+        let mut rows = HashMap::new();
+        while let Ok(write) = receiver.recv() {
+            let batch = iter::once(write).chain(receiver.try_iter().take(MAX_WRITE_BATCH - 1)).collect();
+            state.write_batch(batch, &mut rows);
+        }
+
+        info!("Statistics writer thread stopped");
+    }
+}
+
+fn read_preferences(store: &dyn StatsStore) -> Result<Preferences> {
+    let mut preferences = Preferences::default();
+    for p in store.load_preferences()? {
+        let path = PathBuf::from(p.filename);
+        if let Some(rating) = p.rating {
+            preferences.ratings.insert(path.clone(), rating as u8);
+        }
+        if p.favorite {
+            preferences.favorites.insert(path);
+        }
+    }
+    for b in store.load_bans()? {
+        preferences.banned.insert(PathBuf::from(b.filename));
+    }
+
+    // every known path of an image shares its statistics
+    let counters: HashMap<_, _> = store.load_statistics()?.into_iter()
+        .map(|s| (s.hash, ImageCounters { displays: s.total_displays, skips: s.total_skips, }))
+        .collect();
+    for p in store.load_paths()? {
+        let path = PathBuf::from(p.path);
+        if let Some(c) = counters.get(&p.hash) {
+            preferences.statistics.insert(path.clone(), *c);
+        }
+        preferences.paths.entry(p.hash).or_default().insert(path);
+    }
+
+    Ok(preferences)
+}
+
+// creates the directory of a statistics file; `daemon` is false for other processes, which must not
+// rewrite the storage
+fn open_store(storage: &StatsStorage, daemon: bool) -> Result<Box<dyn StatsStore>> {
+    Ok(match *storage {
+        StatsStorage::Sqlite(ref path) => {
+            prepare_dir(path)?;
+            Box::new(sqlite::SqliteStore::open(path)?)
+        }
+        StatsStorage::JsonLines(ref path) => {
+            prepare_dir(path)?;
+            Box::new(memory::MemoryStore::open_log(path, daemon)?)
+        }
+        StatsStorage::Memory => Box::new(memory::MemoryStore::new()),
+    })
+}
+
+fn prepare_dir(path: &Path) -> Result<()> {
+    simple_error!(InvalidStatsDbDir, "Path to the statistics database is invalid");
+
+    let db_dir = match path.parent() {
+        Some(dir) => dir,
+        None => return Err(InvalidStatsDbDir.into())
+    };
+
+    if !db_dir.exists() {
+        fs::create_dir_all(db_dir)?;
+        info!("Created directory: {}", db_dir.display());
+    }
+    Ok(())
+}
+
+// identities of images whose content could not be read
+fn path_hash(path: &str) -> String {
+    format!("{}{}", PATH_HASH_PREFIX, path)
+}
+
+// returns the path with `from` replaced by `to`, if the path is equal to `from` or lies under it
+fn renamed_path(path: &str, from: &str, to: &str) -> Option<String> {
+    match path.strip_prefix(from) {
+        Some(rest) if rest.is_empty() || rest.starts_with('/') => Some(format!("{}{}", to, rest)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::daemon::test_util::{display_event, memory_stats, with_states};
+
+    fn counters(state: &State, hash: &str) -> Option<(i64, i64, i64)> {
+        state.store.load_statistics().unwrap().into_iter()
+            .find(|s| s.hash == hash)
+            .map(|s| (s.total_displays, s.total_skips, s.total_display_time))
+    }
+
+    fn record_event(state: &State, hash: &str, started_at: i64, duration: i64, skipped: bool) {
+        state.store.insert_event(&display_event(hash, started_at, duration, skipped)).unwrap();
+        state.store.add_counters(hash, 1, skipped as i64, duration).unwrap();
+    }
+
+    #[test]
+    fn rebuild_keeps_legacy_counters() {
+        with_states(|state| {
+            state.store.add_legacy_counters("path:/images/a.jpg", 10, 2, 600).unwrap();
+            record_event(&state, "path:/images/a.jpg", 1000, 30, true);
+            record_event(&state, "path:/images/b.jpg", 2000, 40, false);
+            state.store.add_legacy_counters("path:/images/c.jpg", 3, 0, 90).unwrap();
+            // counters which drifted from the events are corrected
+            state.store.add_counters("path:/images/b.jpg", 5, 5, 5).unwrap();
+
+            state.rebuild_aggregates().unwrap();
+
+            assert_eq!(counters(&state, "path:/images/a.jpg"), Some((11, 3, 630)));
+            assert_eq!(counters(&state, "path:/images/b.jpg"), Some((1, 0, 40)));
+            assert_eq!(counters(&state, "path:/images/c.jpg"), Some((3, 0, 90)));
+        });
+    }
+
+    #[test]
+    fn merge_moves_legacy_counters() {
+        with_states(|state| {
+            state.store.add_legacy_counters("path:/images/a.jpg", 10, 2, 600).unwrap();
+            record_event(&state, "abc", 1000, 30, false);
+
+            state.merge("path:/images/a.jpg", "abc").unwrap();
+            state.rebuild_aggregates().unwrap();
+
+            assert_eq!(counters(&state, "path:/images/a.jpg"), None);
+            assert_eq!(counters(&state, "abc"), Some((11, 2, 630)));
+        });
+    }
+
+    fn record_path(state: &State, path: &str, hash: &str, last_seen: i64) {
+        state.store.record_path(&model::ImagePath { path: path.into(), hash: hash.into(), last_seen, }).unwrap();
+    }
+
+    #[test]
+    fn rename_prefix_merges_conflicting_records() {
+        with_states(|state| {
+            let new_dir = tempfile::tempdir().unwrap();
+            let new_prefix = new_dir.path().to_string_lossy().into_owned();
+            let new_path = |name: &str| format!("{}/{}", new_prefix, name);
+
+            // the same image is known under both prefixes
+            state.store.add_legacy_counters("path:/old/a.jpg", 2, 1, 20).unwrap();
+            state.store.add_legacy_counters(&path_hash(&new_path("a.jpg")), 3, 0, 30).unwrap();
+            record_path(&state, "/old/a.jpg", "path:/old/a.jpg", 100);
+            record_path(&state, &new_path("a.jpg"), &path_hash(&new_path("a.jpg")), 200);
+            state.store.set_rating("/old/a.jpg", Some(4)).unwrap();
+            state.store.set_favorite("/old/a.jpg", true).unwrap();
+            state.store.set_rating(&new_path("a.jpg"), Some(2)).unwrap();
+            // only known under the old prefix
+            state.store.add_legacy_counters("path:/old/b.jpg", 1, 0, 10).unwrap();
+            record_path(&state, "/old/b.jpg", "path:/old/b.jpg", 100);
+            state.store.ban(&model::BannedImage { filename: "/old/b.jpg".into(), banned_at: 100, }).unwrap();
+
+            let result = state.rename_prefix("/old", &new_prefix).unwrap();
+            assert_eq!((result.paths, result.merged), (2, 3));
+
+            let hashes: Vec<_> = state.store.load_statistics().unwrap().into_iter().map(|s| s.hash).collect();
+            assert!(hashes.iter().all(|h| !h.starts_with("path:/old/")));
+            assert_eq!(counters(&state, &path_hash(&new_path("a.jpg"))), Some((5, 1, 50)));
+            assert_eq!(counters(&state, &path_hash(&new_path("b.jpg"))), Some((1, 0, 10)));
+
+            let paths: Vec<_> = state.store.load_paths().unwrap().into_iter().map(|p| (p.path, p.hash)).collect();
+            assert_eq!(paths, vec![
+                (new_path("b.jpg"), path_hash(&new_path("b.jpg"))),
+                (new_path("a.jpg"), path_hash(&new_path("a.jpg"))),
+            ]);
+
+            let preferences = state.store.load_preferences().unwrap();
+            assert_eq!(preferences.len(), 1);
+            assert_eq!(preferences[0].filename, new_path("a.jpg"));
+            assert_eq!((preferences[0].rating, preferences[0].favorite), (Some(2), true));
+            let bans: Vec<_> = state.store.load_bans().unwrap().into_iter().map(|b| b.filename).collect();
+            assert_eq!(bans, vec![new_path("b.jpg")]);
+        });
+    }
+
+    #[test]
+    fn rename_prefix_rejects_nested_prefixes() {
+        with_states(|state| {
+            let dir = tempfile::tempdir().unwrap();
+            let old_prefix = dir.path().to_string_lossy().into_owned();
+            assert!(state.rename_prefix(&old_prefix, &format!("{}/nested", old_prefix)).is_err());
+            assert!(state.rename_prefix(&old_prefix, &old_prefix).is_err());
+        });
+    }
+
+    #[test]
+    fn cached_preferences_follow_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let image = dir.path().join("a.jpg");
+        fs::write(&image, "a").unwrap();
+        let image_name = image.to_string_lossy().into_owned();
+        let canonical = util::canonical_path(&image);
+
+        let (state, mut writer) = memory_stats();
+        let context = DisplayContext { channel: "default", playlist: "default", reason: DisplayReason::Manual, };
+        for _ in 0..2 {
+            let event_id = writer.new_event_id();
+            writer.send(Write::Start {
+                event_id,
+                file_name: image_name.clone(),
+                channel: context.channel.into(),
+                playlist: context.playlist.into(),
+                reason: context.reason,
+                at: Utc::now(),
+            });
+            writer.send(Write::End { event_id, skipped: true, });
+        }
+        // the writer thread updates the statistics after each batch
+        writer.stop();
+        let c = writer.preferences().statistics[&canonical];
+        assert_eq!((c.displays, c.skips), (2, 2));
+        assert_eq!(read_preferences(&*state.store).unwrap().statistics[&canonical].displays, 2);
+
+        state.set_rating(&image_name, Some(4)).unwrap();
+        state.set_favorite(&image_name, true).unwrap();
+        state.ban(&image_name, Utc::now()).unwrap();
+        let preferences = writer.preferences();
+        assert_eq!(preferences.ratings.get(&canonical), Some(&4));
+        assert!(preferences.favorites.contains(&canonical) && preferences.banned.contains(&canonical));
+
+        state.set_rating(&image_name, None).unwrap();
+        state.unban(&image_name).unwrap();
+        let preferences = writer.preferences();
+        assert!(preferences.ratings.is_empty() && preferences.banned.is_empty());
+    }
+
+    #[test]
+    fn images_are_identified_by_content() {
+        with_states(|state| {
+            let dir = tempfile::tempdir().unwrap();
+            let file = |name: &str, content: &str| {
+                let path = dir.path().join(name);
+                fs::write(&path, content).unwrap();
+                util::canonical_path(&path).to_string_lossy().into_owned()
+            };
+            let (a, copy, other) = (file("a.jpg", "a"), file("copy.jpg", "a"), file("b.jpg", "b"));
+
+            let (a_path, a_hash) = state.identify(&a).unwrap();
+            assert_eq!(a_path, a);
+            assert!(!a_hash.starts_with(PATH_HASH_PREFIX));
+            assert_eq!(state.identify(&copy).unwrap().1, a_hash);
+            assert_ne!(state.identify(&other).unwrap().1, a_hash);
+
+            // cached hashes are recomputed when files change
+            file("a.jpg", "changed");
+            assert_ne!(state.identify(&a).unwrap().1, a_hash);
+
+            // files which cannot be read are identified by their paths
+            let missing = dir.path().join("missing.jpg").to_string_lossy().into_owned();
+            assert_eq!(state.identify(&missing).unwrap().1, path_hash(&missing));
+
+            // every identified path is recorded along with its content hash
+            let paths = state.store.load_paths().unwrap();
+            assert!(paths.iter().any(|p| p.path == copy && p.hash == a_hash));
+            assert_eq!(paths.len(), 4);
+        });
+    }
+
+    #[test]
+    fn relink_gives_path_statistics_content_hashes() {
+        with_states(|state| {
+            let dir = tempfile::tempdir().unwrap();
+            let image = dir.path().join("a.jpg");
+            fs::write(&image, "a").unwrap();
+            let image = util::canonical_path(&image).to_string_lossy().into_owned();
+
+            // statistics recorded before content hashes existed
+            state.store.add_legacy_counters(&path_hash(&image), 2, 1, 20).unwrap();
+
+            assert_eq!(state.relink(&[]).unwrap().merged, 1);
+            assert_eq!(counters(&state, &path_hash(&image)), None);
+            let content_hash = state.identify(&image).unwrap().1;
+            assert_eq!(counters(&state, &content_hash), Some((2, 1, 20)));
+
+            // a moved file gets the statistics of its old path
+            let moved = util::canonical_path(dir.path()).join("moved.jpg");
+            fs::rename(&image, &moved).unwrap();
+            let result = state.relink(std::slice::from_ref(&moved)).unwrap();
+            assert_eq!(result.relinked, 1);
+            let paths = state.store.load_paths().unwrap();
+            assert!(paths.iter().any(|p| Path::new(&p.path) == moved && p.hash == content_hash));
+        });
+    }
+
+    #[test]
+    fn import_skips_known_events() {
+        let (source, _source_writer) = memory_stats();
+        record_event(&source, "abc", 1000, 30, true);
+        record_event(&source, "abc", 2000, 40, false);
+        source.store.add_legacy_counters("abc", 5, 0, 100).unwrap();
+        record_path(&source, "/images/a.jpg", "abc", 100);
+        source.store.set_rating("/images/a.jpg", Some(3)).unwrap();
+        let data = source.export().unwrap();
+
+        // an empty store gets the same statistics
+        let (copy, _copy_writer) = memory_stats();
+        let result = copy.import(&data, false).unwrap();
+        assert_eq!((result.images, result.events, result.duplicate_events), (1, 2, 0));
+        assert_eq!(counters(&copy, "abc"), Some((7, 1, 170)));
+
+        // the first event is already known to this store
+        let (target, _target_writer) = memory_stats();
+        record_event(&target, "abc", 1000, 30, true);
+        assert!(target.import(&data, false).is_err());
+        let result = target.import(&data, true).unwrap();
+        assert_eq!((result.images, result.events, result.duplicate_events), (1, 1, 1));
+        assert_eq!(counters(&target, "abc"), Some((7, 1, 170)));
+        assert_eq!(target.store.load_events().unwrap().len(), 2);
+        assert_eq!(target.store.load_preferences().unwrap()[0].rating, Some(3));
+
+        // counters rebuilt from the events stay the same
+        target.rebuild_aggregates().unwrap();
+        assert_eq!(counters(&target, "abc"), Some((7, 1, 170)));
+    }
+
+    #[test]
+    fn import_rejects_unknown_versions() {
+        let (state, _writer) = memory_stats();
+        let data = state.export().unwrap();
+        assert!(state.import(&data, true).is_ok());
+        let data = data.replacen(&format!("\"version\":{}", EXPORT_VERSION), "\"version\":0", 1);
+        assert!(state.import(&data, true).is_err());
+        assert!(state.import("not json", true).is_err());
+    }
+}
+
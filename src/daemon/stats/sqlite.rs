@@ -0,0 +1,408 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+use diesel;
+use diesel::*;
+use diesel::connection::SimpleConnection;
+use diesel::sqlite::SqliteConnection;
+
+use super::{model, path_hash, RenameResult, Result, StatsStore};
+
+embed_migrations!();
+
+no_arg_sql_function!(last_insert_rowid, diesel::sql_types::BigInt);
+
+// how long connections wait for the database to be unlocked by other connections, in milliseconds
+const BUSY_TIMEOUT_MS: u32 = 5000;
+
+pub mod schema {
+    table! {
+        image_statistics (hash) {
+            hash -> Text,
+            total_displays -> BigInt,
+            total_skips -> BigInt,
+            total_display_seconds -> BigInt,
+            legacy_displays -> BigInt,
+            legacy_skips -> BigInt,
+            legacy_display_seconds -> BigInt,
+        }
+    }
+
+    table! {
+        display_events (id) {
+            id -> BigInt,
+            filename -> Text,
+            channel -> Text,
+            playlist -> Text,
+            started_at -> BigInt,
+            ended_at -> BigInt,
+            reason -> Text,
+            skipped -> Bool,
+            hash -> Text,
+        }
+    }
+
+    table! {
+        image_paths (path) {
+            path -> Text,
+            hash -> Text,
+            last_seen -> BigInt,
+        }
+    }
+
+    table! {
+        image_preferences (filename) {
+            filename -> Text,
+            rating -> Nullable<Integer>,
+            favorite -> Bool,
+        }
+    }
+
+    table! {
+        banned_images (filename) {
+            filename -> Text,
+            banned_at -> BigInt,
+        }
+    }
+}
+
+// statistics stored in an SQLite database
+pub struct SqliteStore {
+    path: PathBuf,
+    conn: SqliteConnection,
+}
+
+impl SqliteStore {
+    pub fn open(path: &Path) -> Result<SqliteStore> {
+        info!("Establishing connection to an SQLite database: {}", path.display());
+        let conn = connect(path)?;
+
+        info!("Running migrations");
+        embedded_migrations::run(&conn)?;
+
+        Ok(SqliteStore { path: path.to_owned(), conn, })
+    }
+
+    // replaces `old_prefix` with `new_prefix` in values of the column which are equal to `old_prefix`
+    // or lie under it; returns the number of updated rows
+    fn rewrite_prefix(&self, conflict: &str, table: &str, column: &str, old_prefix: &str, new_prefix: &str) -> Result<usize> {
+        Ok(diesel::sql_query(format!(
+            "UPDATE {conflict} {table} SET {column} = ?2 || substr({column}, length(?1) + 1) \
+             WHERE {column} = ?1 OR substr({column}, 1, length(?1) + 1) = ?1 || '/'",
+            conflict = conflict, table = table, column = column,
+        ))
+            .bind::<sql_types::Text, _>(old_prefix)
+            .bind::<sql_types::Text, _>(new_prefix)
+            .execute(&self.conn)?)
+    }
+
+    fn event_hash(&self, event_id: i64) -> Result<String> {
+        use self::schema::display_events::dsl::*;
+
+        Ok(display_events.find(event_id).select(hash).first::<String>(&self.conn)?)
+    }
+}
+
+impl StatsStore for SqliteStore {
+    // the writer thread uses its own connection, so it does not block the daemon
+    fn open_writer(&self) -> Result<Box<dyn StatsStore>> {
+        Ok(Box::new(SqliteStore { path: self.path.clone(), conn: connect(&self.path)?, }))
+    }
+
+    fn transaction(&self, f: &mut dyn FnMut() -> Result<()>) -> Result<()> {
+        self.conn.transaction::<_, Box<dyn Error>, _>(f)
+    }
+
+    fn load_statistics(&self) -> Result<Vec<model::ImageStatistics>> {
+        use self::schema::image_statistics::dsl::*;
+
+        Ok(image_statistics
+            .select((hash, total_displays, total_skips, total_display_seconds))
+            .load::<model::ImageStatistics>(&self.conn)?)
+    }
+
+    fn load_paths(&self) -> Result<Vec<model::ImagePath>> {
+        use self::schema::image_paths::dsl::*;
+
+        Ok(image_paths
+            .order((last_seen.asc(), path.asc()))
+            .load::<model::ImagePath>(&self.conn)?)
+    }
+
+    fn load_events(&self) -> Result<Vec<model::DisplayEvent>> {
+        use self::schema::display_events::dsl::*;
+
+        Ok(display_events
+            .select((filename, channel, playlist, started_at, ended_at, reason, skipped, hash))
+            .order(id.asc())
+            .load::<model::DisplayEvent>(&self.conn)?)
+    }
+
+    fn load_preferences(&self) -> Result<Vec<model::ImagePreferences>> {
+        use self::schema::image_preferences::dsl::*;
+
+        Ok(image_preferences.load::<model::ImagePreferences>(&self.conn)?)
+    }
+
+    fn load_bans(&self) -> Result<Vec<model::BannedImage>> {
+        use self::schema::banned_images::dsl::*;
+
+        Ok(banned_images
+            .order(banned_at.asc())
+            .load::<model::BannedImage>(&self.conn)?)
+    }
+
+    fn has_event(&self, content_hash: &str, channel_name: &str, at: i64) -> Result<bool> {
+        use self::schema::display_events::dsl::*;
+
+        Ok(diesel::select(diesel::dsl::exists(
+            display_events
+                .filter(hash.eq(content_hash))
+                .filter(channel.eq(channel_name))
+                .filter(started_at.eq(at))
+        )).get_result::<bool>(&self.conn)?)
+    }
+
+    fn record_path(&self, image_path: &model::ImagePath) -> Result<()> {
+        diesel::replace_into(self::schema::image_paths::table)
+            .values(image_path)
+            .execute(&self.conn)?;
+        Ok(())
+    }
+
+    fn add_counters(&self, content_hash: &str, displays: i64, skips: i64, time_sec: i64) -> Result<()> {
+        self.conn.transaction(|| {
+            use self::schema::image_statistics::dsl::*;
+
+            diesel::insert_or_ignore_into(image_statistics)
+                .values(&model::NewImageStatistics { hash: content_hash, })
+                .execute(&self.conn)?;
+
+            diesel::update(image_statistics.filter(hash.eq(content_hash)))
+                .set((
+                    total_displays.eq(total_displays + displays),
+                    total_skips.eq(total_skips + skips),
+                    total_display_seconds.eq(total_display_seconds + time_sec),
+                ))
+                .execute(&self.conn)?;
+
+            Ok(())
+        })
+    }
+
+    fn insert_event(&self, event: &model::NewDisplayEvent) -> Result<i64> {
+        diesel::insert_into(self::schema::display_events::table)
+            .values(event)
+            .execute(&self.conn)?;
+        Ok(diesel::select(last_insert_rowid).get_result::<i64>(&self.conn)?)
+    }
+
+    fn extend_event(&self, event_id: i64, until: i64) -> Result<String> {
+        use self::schema::display_events::dsl::*;
+
+        diesel::update(display_events.find(event_id))
+            .set(ended_at.eq(until))
+            .execute(&self.conn)?;
+        self.event_hash(event_id)
+    }
+
+    fn skip_event(&self, event_id: i64) -> Result<String> {
+        use self::schema::display_events::dsl::*;
+
+        diesel::update(display_events.find(event_id))
+            .set(skipped.eq(true))
+            .execute(&self.conn)?;
+        self.event_hash(event_id)
+    }
+
+    fn add_legacy_counters(&self, content_hash: &str, displays: i64, skips: i64, time_sec: i64) -> Result<()> {
+        self.conn.transaction(|| {
+            use self::schema::image_statistics::dsl::*;
+
+            self.add_counters(content_hash, displays, skips, time_sec)?;
+            diesel::update(image_statistics.filter(hash.eq(content_hash)))
+                .set((
+                    legacy_displays.eq(legacy_displays + displays),
+                    legacy_skips.eq(legacy_skips + skips),
+                    legacy_display_seconds.eq(legacy_display_seconds + time_sec),
+                ))
+                .execute(&self.conn)?;
+
+            Ok(())
+        })
+    }
+
+    fn rebuild_aggregates(&self) -> Result<()> {
+        self.conn.transaction(|| {
+            diesel::sql_query("INSERT OR IGNORE INTO image_statistics (hash) SELECT DISTINCT hash FROM display_events")
+                .execute(&self.conn)?;
+            diesel::sql_query(
+                "UPDATE image_statistics SET \
+                 total_displays = legacy_displays + \
+                   (SELECT COUNT(*) FROM display_events e WHERE e.hash = image_statistics.hash), \
+                 total_skips = legacy_skips + \
+                   (SELECT COALESCE(SUM(e.skipped), 0) FROM display_events e WHERE e.hash = image_statistics.hash), \
+                 total_display_seconds = legacy_display_seconds + \
+                   (SELECT COALESCE(SUM(e.ended_at - e.started_at), 0) FROM display_events e \
+                    WHERE e.hash = image_statistics.hash)"
+            ).execute(&self.conn)?;
+            Ok(())
+        })
+    }
+
+    fn merge(&self, from_hash: &str, to_hash: &str) -> Result<()> {
+        self.conn.transaction(|| {
+            diesel::insert_or_ignore_into(self::schema::image_statistics::table)
+                .values(&model::NewImageStatistics { hash: to_hash, })
+                .execute(&self.conn)?;
+
+            diesel::sql_query(
+                "UPDATE image_statistics SET \
+                 total_displays = total_displays + \
+                   (SELECT total_displays FROM image_statistics WHERE hash = ?1), \
+                 total_skips = total_skips + \
+                   (SELECT total_skips FROM image_statistics WHERE hash = ?1), \
+                 total_display_seconds = total_display_seconds + \
+                   (SELECT total_display_seconds FROM image_statistics WHERE hash = ?1), \
+                 legacy_displays = legacy_displays + \
+                   (SELECT legacy_displays FROM image_statistics WHERE hash = ?1), \
+                 legacy_skips = legacy_skips + \
+                   (SELECT legacy_skips FROM image_statistics WHERE hash = ?1), \
+                 legacy_display_seconds = legacy_display_seconds + \
+                   (SELECT legacy_display_seconds FROM image_statistics WHERE hash = ?1) \
+                 WHERE hash = ?2 AND EXISTS (SELECT 1 FROM image_statistics WHERE hash = ?1)"
+            )
+                .bind::<sql_types::Text, _>(from_hash)
+                .bind::<sql_types::Text, _>(to_hash)
+                .execute(&self.conn)?;
+
+            {
+                use self::schema::image_statistics::dsl::*;
+                diesel::delete(image_statistics.filter(hash.eq(from_hash))).execute(&self.conn)?;
+            }
+            {
+                use self::schema::display_events::dsl::*;
+                diesel::update(display_events.filter(hash.eq(from_hash))).set(hash.eq(to_hash)).execute(&self.conn)?;
+            }
+            {
+                use self::schema::image_paths::dsl::*;
+                diesel::update(image_paths.filter(hash.eq(from_hash))).set(hash.eq(to_hash)).execute(&self.conn)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    fn set_rating(&self, file_name: &str, new_rating: Option<i32>) -> Result<()> {
+        self.conn.transaction(|| {
+            use self::schema::image_preferences::dsl::*;
+
+            diesel::insert_or_ignore_into(image_preferences)
+                .values(&model::NewImagePreferences { filename: file_name, })
+                .execute(&self.conn)?;
+
+            diesel::update(image_preferences.filter(filename.eq(file_name)))
+                .set(rating.eq(new_rating))
+                .execute(&self.conn)?;
+
+            Ok(())
+        })
+    }
+
+    fn set_favorite(&self, file_name: &str, is_favorite: bool) -> Result<()> {
+        self.conn.transaction(|| {
+            use self::schema::image_preferences::dsl::*;
+
+            diesel::insert_or_ignore_into(image_preferences)
+                .values(&model::NewImagePreferences { filename: file_name, })
+                .execute(&self.conn)?;
+
+            diesel::update(image_preferences.filter(filename.eq(file_name)))
+                .set(favorite.eq(is_favorite))
+                .execute(&self.conn)?;
+
+            Ok(())
+        })
+    }
+
+    fn move_preferences(&self, old_path: &str, new_path: &str) -> Result<()> {
+        diesel::sql_query("UPDATE OR IGNORE image_preferences SET filename = ?1 WHERE filename = ?2")
+            .bind::<sql_types::Text, _>(new_path)
+            .bind::<sql_types::Text, _>(old_path)
+            .execute(&self.conn)?;
+        diesel::sql_query("UPDATE OR IGNORE banned_images SET filename = ?1 WHERE filename = ?2")
+            .bind::<sql_types::Text, _>(new_path)
+            .bind::<sql_types::Text, _>(old_path)
+            .execute(&self.conn)?;
+        Ok(())
+    }
+
+    fn ban(&self, banned_image: &model::BannedImage) -> Result<()> {
+        diesel::insert_or_ignore_into(self::schema::banned_images::table)
+            .values(banned_image)
+            .execute(&self.conn)?;
+        Ok(())
+    }
+
+    fn unban(&self, file_name: &str) -> Result<bool> {
+        use self::schema::banned_images::dsl::*;
+
+        let removed = diesel::delete(banned_images.filter(filename.eq(file_name)))
+            .execute(&self.conn)?;
+        Ok(removed > 0)
+    }
+
+    fn remove(&self, hashes: &[String], paths: &[String], file_names: &[String]) -> Result<()> {
+        self.conn.transaction(|| {
+            {
+                use self::schema::image_statistics::dsl::*;
+                diesel::delete(image_statistics.filter(hash.eq_any(hashes))).execute(&self.conn)?;
+            }
+            {
+                use self::schema::display_events::dsl::*;
+                diesel::delete(display_events.filter(hash.eq_any(hashes))).execute(&self.conn)?;
+            }
+            {
+                use self::schema::image_paths::dsl::*;
+                diesel::delete(image_paths.filter(path.eq_any(paths))).execute(&self.conn)?;
+            }
+            {
+                use self::schema::image_preferences::dsl::*;
+                diesel::delete(image_preferences.filter(filename.eq_any(file_names))).execute(&self.conn)?;
+            }
+            {
+                use self::schema::banned_images::dsl::*;
+                diesel::delete(banned_images.filter(filename.eq_any(file_names))).execute(&self.conn)?;
+            }
+            Ok(())
+        })
+    }
+
+    fn rename_prefix(&self, old_prefix: &str, new_prefix: &str) -> Result<RenameResult> {
+        let old_hash = path_hash(old_prefix);
+        let new_hash = path_hash(new_prefix);
+
+        self.conn.transaction::<_, Box<dyn Error>, _>(|| {
+            // when the new path is already known, it is more recent than the renamed one
+            let paths = self.rewrite_prefix("OR IGNORE", "image_paths", "path", old_prefix, new_prefix)?;
+            let preferences =
+                self.rewrite_prefix("OR IGNORE", "image_preferences", "filename", old_prefix, new_prefix)? +
+                self.rewrite_prefix("OR IGNORE", "banned_images", "filename", old_prefix, new_prefix)?;
+            self.rewrite_prefix("", "display_events", "filename", old_prefix, new_prefix)?;
+
+            // statistics recorded before content hashes were introduced are identified by their paths
+            self.rewrite_prefix("OR IGNORE", "image_statistics", "hash", &old_hash, &new_hash)?;
+            self.rewrite_prefix("", "display_events", "hash", &old_hash, &new_hash)?;
+            self.rewrite_prefix("", "image_paths", "hash", &old_hash, &new_hash)?;
+
+            Ok(RenameResult { paths: paths as u32, preferences: preferences as u32, ..RenameResult::default() })
+        })
+    }
+}
+
+fn connect(path: &Path) -> Result<SqliteConnection> {
+    let conn = SqliteConnection::establish(&path.as_os_str().to_string_lossy())?;
+    // the daemon and the writer thread use separate connections
+    conn.batch_execute(&format!("PRAGMA busy_timeout = {};", BUSY_TIMEOUT_MS))?;
+    Ok(conn)
+}
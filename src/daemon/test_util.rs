@@ -0,0 +1,82 @@
+// fixtures shared by tests of the daemon's modules
+use std::fs;
+
+use chrono::Duration;
+
+use crate::common::config::{self, ChangeMode, CommandArg, Outputs, StatsStorage, ValidatedPlaylist, WeightFormula};
+use crate::daemon::Daemon;
+use crate::daemon::stats::{self, model};
+
+// a daemon keeping statistics in memory with a playlist of two images and a playlist of favorites
+pub fn daemon(dir: &tempfile::TempDir) -> Daemon {
+    for name in &["a.jpg", "b.jpg"] {
+        fs::write(dir.path().join(name), name).unwrap();
+    }
+    let config = config::parse(&format!(r#"
+        [common]
+        endpoint = "127.0.0.1:0"
+
+        [server]
+        default_playlist = "default"
+        stats_backend = "memory"
+
+        [server.defaults]
+        mode = "sequential"
+        command = ["true", "{{}}"]
+        change_every = "1 hour"
+
+        [server.playlists.default]
+        directories = ["{}"]
+
+        [server.playlists.favorites]
+        favorites_only = true
+    "#, dir.path().display())).unwrap();
+    Daemon::new(config.server)
+}
+
+// a sequential playlist without images, which are added by the test
+pub fn playlist_config(outputs: usize) -> ValidatedPlaylist {
+    ValidatedPlaylist {
+        files: Vec::new(),
+        directories: Vec::new(),
+        command: "true".into(),
+        command_args: vec![CommandArg::Path],
+        mode: ChangeMode::Sequential,
+        change_every: Duration::hours(1),
+        trigger_on_select: true,
+        use_last_on_select: true,
+        outputs: Outputs::Count(outputs),
+        fit: None,
+        overrides: Vec::new(),
+        min_rating: None,
+        favorites_only: false,
+        weights: WeightFormula::default(),
+    }
+}
+
+pub fn display_event(hash: &str, started_at: i64, duration: i64, skipped: bool) -> model::NewDisplayEvent<'_> {
+    model::NewDisplayEvent {
+        filename: "/images/a.jpg",
+        hash,
+        channel: "default",
+        playlist: "default",
+        started_at,
+        ended_at: started_at + duration,
+        reason: "scheduled",
+        skipped,
+    }
+}
+
+// statistics kept in memory; their writer thread stops when the writer is dropped
+pub fn memory_stats() -> (stats::State, stats::Writer) {
+    stats::State::new(&StatsStorage::Memory).unwrap()
+}
+
+// runs the test against every kind of store
+pub fn with_states(test: impl Fn(stats::State)) {
+    let (state, _writer) = memory_stats();
+    test(state);
+    let dir = tempfile::tempdir().unwrap();
+    let (state, _writer) = stats::State::new(&StatsStorage::Sqlite(dir.path().join("stats.db"))).unwrap();
+    test(state);
+}
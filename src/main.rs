@@ -2,14 +2,6 @@
 #[macro_use] extern crate quick_error;
 #[macro_use] extern crate diesel;
 #[macro_use] extern crate diesel_migrations;
-#[cfg(feature = "stats-analyzer")]
-extern crate gtk;
-#[cfg(feature = "stats-analyzer")]
-extern crate gdk;
-#[cfg(feature = "stats-analyzer")]
-extern crate gdk_pixbuf;
-#[cfg(feature = "stats-analyzer")]
-extern crate cairo;
 
 use structopt::StructOpt;
 use structopt::clap::AppSettings::{ColoredHelp, SubcommandRequiredElseHelp, VersionlessSubcommands};
@@ -21,8 +13,6 @@ mod macros;
 mod common;
 mod cli;
 mod daemon;
-#[cfg(feature = "stats-analyzer")]
-mod stats_analyzer;
 
 #[derive(Debug, StructOpt)]
 #[structopt(name = "wcd", about = "A wallpaper change daemon and its control utility.")]
@@ -36,10 +26,24 @@ struct Options {
     #[structopt(short, long, parse(from_occurrences))]
     verbose: u32,
 
+    #[structopt(flatten)]
+    output: OutputOptions,
+
     #[structopt(subcommand)]
     cmd: Command,
 }
 
+#[derive(Debug, StructOpt)]
+struct OutputOptions {
+    /// Output format of client commands; `csv` is only supported by statistics commands.
+    #[structopt(long, global = true, possible_values = &["plain", "json", "yaml", "csv", "table"])]
+    format: Option<String>,
+
+    /// Print the status as a line built from the template, e.g. `{playlist}: {image_name}`.
+    #[structopt(long, global = true, conflicts_with = "format")]
+    template: Option<String>,
+}
+
 #[derive(Debug, StructOpt)]
 enum Command {
     /// Starts the wallpaper change daemon.
@@ -49,13 +53,24 @@ enum Command {
         /// Refresh the currently selected wallpaper.
         #[structopt(short, long)]
         keep: bool,
+
+        /// The channel to trigger the change in (the default channel if not specified).
+        #[structopt(long)]
+        channel: Option<String>,
     },
     /// Makes the wallpaper change daemon rescan all directories in all playlists, potentially loading new files.
     Refresh,
     /// Shuts the wallpaper change daemon down.
     Terminate,
     /// Displays the current status information (available playlists, current items in them, timestamps, etc).
-    Status,
+    Status {
+        /// Only show the given playlist (all playlists if not specified).
+        playlist: Option<String>,
+
+        /// The channel to display the status of (the default channel if not specified).
+        #[structopt(long)]
+        channel: Option<String>,
+    },
     /// Sets the given playlist as the current one (may cause immediate wallpaper switch, depending on the
     /// selected playlist configuration).
     SetPlaylist {
@@ -63,18 +78,221 @@ enum Command {
 
         #[structopt(long)]
         or_trigger: bool,
+
+        /// The channel to set the playlist in (the default channel if not specified).
+        #[structopt(long)]
+        channel: Option<String>,
+    },
+    /// Switches to the next playlist in alphabetical order, wrapping around after the last one.
+    Cycle {
+        /// Switch to the previous playlist instead.
+        #[structopt(long)]
+        reverse: bool,
+
+        /// The channel to switch the playlist in (the default channel if not specified).
+        #[structopt(long)]
+        channel: Option<String>,
+    },
+    /// Stops scheduled wallpaper changes until `wcd resume` is called; manual changes are still possible.
+    Pause {
+        /// The channel to pause (the default channel if not specified).
+        #[structopt(long)]
+        channel: Option<String>,
+    },
+    /// Resumes scheduled wallpaper changes, starting a new interval with the current wallpaper.
+    Resume {
+        /// The channel to resume (the default channel if not specified).
+        #[structopt(long)]
+        channel: Option<String>,
+    },
+    /// Prints changes in the daemon as they happen, until the daemon shuts down.
+    Watch {
+        /// Same as `--format json`.
+        #[structopt(long, hidden = true)]
+        json: bool,
+    },
+    /// Prints a line describing the current image whenever it changes, for status bars; `--format json`
+    /// prints objects for custom modules of Waybar.
+    Bar {
+        /// The channel to follow (the default channel if not specified).
+        #[structopt(long)]
+        channel: Option<String>,
+    },
+    /// Changes the wallpaper to the next image, like `wcd trigger`.
+    Next {
+        /// The channel to change the image in (the default channel if not specified).
+        #[structopt(long)]
+        channel: Option<String>,
+    },
+    /// Returns to the images displayed before the current ones in the current playlist.
+    Prev {
+        /// The channel to change the image in (the default channel if not specified).
+        #[structopt(long)]
+        channel: Option<String>,
+    },
+    /// Displays the given image of the current playlist right away.
+    Show {
+        image: String,
+
+        /// The channel to display the image in (the default channel if not specified).
+        #[structopt(long)]
+        channel: Option<String>,
+    },
+    /// Rates the current image or the given image from 1 to 5, with 0 removing the rating.
+    Rate {
+        rating: u8,
+
+        /// The image to rate (the images currently displayed in the channel if not specified).
+        image: Option<String>,
+
+        /// The channel to take the current images from (the default channel if not specified).
+        #[structopt(long)]
+        channel: Option<String>,
+    },
+    /// Marks the current image or the given image as a favorite.
+    Fav {
+        /// The image to mark (the images currently displayed in the channel if not specified).
+        image: Option<String>,
+
+        /// The channel to take the current images from (the default channel if not specified).
+        #[structopt(long)]
+        channel: Option<String>,
+    },
+    /// Removes the current image or the given image from favorites.
+    Unfav {
+        /// The image to unmark (the images currently displayed in the channel if not specified).
+        image: Option<String>,
+
+        /// The channel to take the current images from (the default channel if not specified).
+        #[structopt(long)]
+        channel: Option<String>,
+    },
+    /// Lists images of the current playlist, with their selection weights in the weighted mode.
+    List {
+        /// List only the images which were not used in the current cycle, in the order of their use.
+        #[structopt(long)]
+        upcoming: bool,
+
+        /// The playlist to list images of (the current playlist if not specified).
+        #[structopt(long)]
+        playlist: Option<String>,
+
+        /// The channel to list images in (the default channel if not specified).
+        #[structopt(long)]
+        channel: Option<String>,
+    },
+    /// Bans the current image or the given image, permanently removing it from rotation.
+    Ban {
+        /// The image to ban (the images currently displayed in the channel if not specified).
+        image: Option<String>,
+
+        /// The channel to take the current images from (the default channel if not specified).
+        #[structopt(long)]
+        channel: Option<String>,
+    },
+    /// Manages banned images.
+    Bans {
+        #[structopt(subcommand)]
+        action: BansCommand,
+    },
+    /// Displays the collected image statistics.
+    Stats {
+        #[structopt(flatten)]
+        options: StatsOptions,
+
+        #[structopt(subcommand)]
+        action: Option<StatsCommand>,
+    },
+    /// Opens the interactive terminal analyzer of the collected image statistics.
+    Analyze {
+        /// The channel to show images in (the default channel if not specified).
+        #[structopt(long)]
+        channel: Option<String>,
+    },
+    /// Checks whether the daemon is healthy, exiting with a non-zero code if it is not serving.
+    Ping,
+    /// Prints the version of wcd and of its control protocol.
+    Version {
+        /// Also print the version and capabilities of the running daemon.
+        #[structopt(long)]
+        daemon: bool,
+    },
+}
+
+#[derive(Debug, StructOpt)]
+struct StatsOptions {
+    /// Only show images from the given playlist.
+    #[structopt(long)]
+    playlist: Option<String>,
+
+    /// Sort images by the given statistics in descending order, or by file name.
+    #[structopt(long, default_value = "displays", possible_values = &["displays", "skips", "time", "name"])]
+    sort: String,
+
+    /// Only show the given number of images.
+    #[structopt(long)]
+    top: Option<u32>,
+
+    /// Only show images whose files no longer exist.
+    #[structopt(long)]
+    missing_only: bool,
+}
+
+#[derive(Debug, StructOpt)]
+enum BansCommand {
+    /// Lists banned images.
+    List,
+    /// Removes the ban from the given image, returning it to rotation.
+    Remove {
+        image: String,
+    },
+}
+
+#[derive(Debug, StructOpt)]
+enum StatsCommand {
+    /// Rebuilds the aggregated image statistics from the recorded display events.
+    Rebuild,
+    /// Reattaches statistics of moved or renamed images to the files with the same content.
+    Relink,
+    /// Removes statistics, preferences and bans of images whose files no longer exist.
+    Gc {
+        /// Only print what would be removed.
+        #[structopt(long)]
+        dry_run: bool,
+    },
+    /// Rewrites recorded paths of images after moving a directory.
+    RenamePrefix {
+        old_prefix: String,
+        new_prefix: String,
+    },
+    /// Exports statistics, preferences and bans as JSON.
+    Export {
+        /// Write the export to the given file instead of the standard output.
+        #[structopt(long, short)]
+        output: Option<String>,
+    },
+    /// Imports statistics exported by `wcd stats export`.
+    Import {
+        file: String,
+        /// Combine the imported statistics with the existing ones.
+        #[structopt(long)]
+        merge: bool,
+    },
+    /// Summarizes display time, displays and skips per playlist, day or directory.
+    Report {
+        /// Only include displays started within the given period, e.g. `30d` or `12 hours`.
+        #[structopt(long)]
+        since: Option<String>,
+
+        /// Group displays by their playlist, the day they started on or the directory of the image.
+        #[structopt(long, default_value = "playlist", possible_values = &["playlist", "day", "directory"])]
+        by: String,
     },
-    #[cfg(target_feature = "stats-analyzer")]
-    StatsAnalyzer,
 }
 
 fn main() {
     let options: Options = Options::from_args();
 
-    #[cfg(feature = "stats-analyzer")]
-    let app = app
-        .subcommand(stats_analyzer::subcommand());
-
     let log_level = match options.verbose {
         0 => LogLevel::Normal,
         1 => LogLevel::Debug,
@@ -85,8 +303,6 @@ fn main() {
     let config_path = common::util::str_to_path(&options.config);
     match options.cmd {
         Command::Daemon => daemon::main(&config_path),
-        #[cfg(target_feature = "stats-analyzer")]
-        Command::StatsAnalyzer => stats_analyzer::main(&options.config),
-        subcommand => cli::main(&config_path, subcommand),
+        subcommand => cli::main(&config_path, subcommand, options.output),
     }
 }
@@ -0,0 +1,108 @@
+// runs the daemon and the command line client against each other over the control server
+#![cfg(unix)]
+
+use std::fs;
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+// exit codes of the client, see src/cli/mod.rs
+const EXIT_CONNECTION_FAILED: i32 = 2;
+const EXIT_PERMISSION_DENIED: i32 = 9;
+
+// kills the process when the test ends, even if it fails
+struct Process(Child);
+
+impl Drop for Process {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+// the daemon does not report the port it listens on, so a free one is chosen in advance
+fn free_endpoint() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    listener.local_addr().unwrap().to_string()
+}
+
+// `common` and `server` are added to the respective sections of the configuration
+fn write_config(dir: &Path, name: &str, endpoint: &str, common: &str, server: &str) -> PathBuf {
+    let images = dir.join("images");
+    if !images.exists() {
+        fs::create_dir(&images).unwrap();
+        for name in &["a.jpg", "b.jpg"] {
+            fs::write(images.join(name), name).unwrap();
+        }
+    }
+    let config = dir.join(name);
+    fs::write(&config, format!(r#"
+        [common]
+        endpoint = "{}"
+        {}
+
+        [server]
+        default_playlist = "default"
+        stats_backend = "memory"
+        {}
+
+        [server.playlists.default]
+        directories = ["{}"]
+        mode = "sequential"
+        command = ["true", "{{}}"]
+        change_every = "1 hour"
+    "#, endpoint, common, server, images.display())).unwrap();
+    config
+}
+
+fn wcd(config: &Path, args: &[&str]) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_wcd"));
+    command.arg("-c").arg(config).args(args).stdout(Stdio::null()).stderr(Stdio::null());
+    command
+}
+
+fn exit_code(config: &Path, args: &[&str]) -> i32 {
+    wcd(config, args).status().unwrap().code().unwrap()
+}
+
+fn wait_for_daemon(config: &Path) {
+    let started = Instant::now();
+    while exit_code(config, &["status"]) == EXIT_CONNECTION_FAILED {
+        assert!(started.elapsed() < TIMEOUT, "the daemon did not start listening");
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+#[test]
+fn statistics_export_requires_control_scope() {
+    let dir = tempfile::tempdir().unwrap();
+    let endpoint = free_endpoint();
+    let token_file = |name: &str, token: &str| {
+        let path = dir.path().join(name);
+        fs::write(&path, token).unwrap();
+        path.display().to_string()
+    };
+    let (read_token, control_token) = (token_file("read-token", "reader"), token_file("control-token", "controller"));
+
+    let server = format!(
+        "tokens = [{{ file = \"{}\", scope = \"read\" }}, {{ file = \"{}\", scope = \"control\" }}]",
+        read_token, control_token
+    );
+    let daemon_config = write_config(dir.path(), "daemon.toml", &endpoint, "", &server);
+    let _daemon = Process(wcd(&daemon_config, &["daemon"]).spawn().unwrap());
+
+    // clients use the scoped tokens as their common ones
+    let client_config = |name: &str, token: &str| {
+        write_config(dir.path(), name, &endpoint, &format!("auth_token_file = \"{}\"", token), "")
+    };
+    let reader = client_config("reader.toml", &read_token);
+    let controller = client_config("controller.toml", &control_token);
+    wait_for_daemon(&reader);
+
+    assert_eq!(exit_code(&reader, &["stats", "export"]), EXIT_PERMISSION_DENIED);
+    assert_eq!(exit_code(&controller, &["stats", "export"]), 0);
+}
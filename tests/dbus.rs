@@ -0,0 +1,239 @@
+// runs the daemon against a private bus; skipped if `dbus-daemon` is not available
+#![cfg(all(unix, feature = "dbus"))]
+
+use std::collections::HashMap;
+use std::convert::{TryFrom, TryInto};
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use zbus::blocking::{Connection, Proxy};
+use zbus::blocking::fdo::DBusProxy;
+use zbus::zvariant::OwnedValue;
+
+const NAME: &str = "io.github.netvl.Wcd";
+const OBJECT_PATH: &str = "/io/github/netvl/Wcd";
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+const BUS_CONFIG: &str = r#"<!DOCTYPE busconfig PUBLIC "-//freedesktop//DTD D-Bus Bus Configuration 1.0//EN"
+ "http://www.freedesktop.org/standards/dbus/1.0/busconfig.dtd">
+<busconfig>
+  <type>session</type>
+  <listen>unix:dir={dir}</listen>
+  <auth>EXTERNAL</auth>
+  <policy context="default">
+    <allow send_destination="*" eavesdrop="true"/>
+    <allow eavesdrop="true"/>
+    <allow own="*"/>
+  </policy>
+</busconfig>
+"#;
+
+// kills the process when the test ends, even if it fails
+struct Process(Child);
+
+impl Drop for Process {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+// returns the bus process and its address
+fn start_bus(dir: &Path) -> Option<(Process, String)> {
+    let config = dir.join("bus.conf");
+    fs::write(&config, BUS_CONFIG.replace("{dir}", &dir.display().to_string())).unwrap();
+
+    let child = Command::new("dbus-daemon")
+        .arg(format!("--config-file={}", config.display()))
+        .args(["--nofork", "--print-address=1"])
+        .stdout(Stdio::piped())
+        .spawn();
+    let mut bus = match child {
+        Ok(child) => Process(child),
+        Err(e) => {
+            eprintln!("Skipping the D-Bus test, dbus-daemon cannot be started: {}", e);
+            return None;
+        }
+    };
+
+    let mut address = String::new();
+    BufReader::new(bus.0.stdout.as_mut().unwrap()).read_line(&mut address).unwrap();
+    Some((bus, address.trim().to_owned()))
+}
+
+// `common` and `server` are added to the respective sections of the configuration
+fn start_daemon(dir: &Path, address: &str, common: &str, server: &str) -> Process {
+    let images = dir.join("images");
+    fs::create_dir(&images).unwrap();
+    for name in &["a.jpg", "b.jpg"] {
+        fs::write(images.join(name), name).unwrap();
+    }
+    let config = dir.join("wcd.toml");
+    fs::write(&config, format!(r#"
+        [common]
+        endpoint = "127.0.0.1:0"
+        {}
+
+        [server]
+        default_playlist = "default"
+        stats_backend = "memory"
+        {}
+
+        [server.playlists.default]
+        directories = ["{}"]
+        mode = "sequential"
+        command = ["true", "{{}}"]
+        change_every = "1 hour"
+
+        [server.dbus]
+        address = "{}"
+    "#, common, server, images.display(), address)).unwrap();
+
+    let child = Command::new(env!("CARGO_BIN_EXE_wcd"))
+        .arg("-c").arg(&config).arg("daemon")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    Process(child)
+}
+
+fn wait_for_name(connection: &Connection) {
+    let dbus = DBusProxy::new(connection).unwrap();
+    let started = Instant::now();
+    while !dbus.name_has_owner(NAME.try_into().unwrap()).unwrap() {
+        assert!(started.elapsed() < TIMEOUT, "the daemon did not appear on the bus");
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+fn proxy(connection: &Connection) -> Proxy<'_> {
+    // properties are read from the daemon every time instead of being cached from PropertiesChanged signals
+    zbus::blocking::proxy::Builder::new(connection)
+        .destination(NAME).unwrap()
+        .path(OBJECT_PATH).unwrap()
+        .interface(NAME).unwrap()
+        .cache_properties(zbus::proxy::CacheProperties::No)
+        .build()
+        .unwrap()
+}
+
+fn error_name(e: zbus::Error) -> String {
+    match e {
+        zbus::Error::MethodError(name, _, _) => name.to_string(),
+        zbus::Error::FDO(e) => zbus::DBusError::name(&*e).to_string(),
+        other => panic!("unexpected error {}", other),
+    }
+}
+
+#[test]
+fn daemon_is_controlled_over_dbus() {
+    let dir = tempfile::tempdir().unwrap();
+    let (_bus, address) = match start_bus(dir.path()) {
+        Some(bus) => bus,
+        None => return,
+    };
+    // callers of the daemon's user have the common token, which allows everything
+    let token_file = dir.path().join("token");
+    fs::write(&token_file, "secret").unwrap();
+    let common = format!("auth_token_file = \"{}\"", token_file.display());
+    let mut daemon = start_daemon(dir.path(), &address, &common, "");
+
+    let connection = zbus::blocking::connection::Builder::address(&*address).unwrap().build().unwrap();
+    wait_for_name(&connection);
+    let wcd = proxy(&connection);
+
+    let introspection: String = Proxy::new(&connection, NAME, OBJECT_PATH, "org.freedesktop.DBus.Introspectable")
+        .unwrap()
+        .call("Introspect", &())
+        .unwrap();
+    for method in &["TriggerChange", "GetStatus", "GetStatistics", "GetReport", "GetVersion", "Terminate"] {
+        assert!(introspection.contains(&format!("<method name=\"{}\">", method)), "{} is not introspectable", method);
+    }
+
+    let version: HashMap<String, OwnedValue> = wcd.call("GetVersion", &()).unwrap();
+    assert_eq!(String::try_from(version["version"].clone()).unwrap(), env!("CARGO_PKG_VERSION"));
+
+    // signals are received in another thread, so that a missing signal fails the test instead of blocking it
+    let (sender, receiver) = mpsc::channel();
+    let signals = wcd.receive_signal("WallpaperChanged").unwrap();
+    thread::spawn(move || {
+        for signal in signals {
+            let body: (String, String, Vec<String>, String) = signal.body().deserialize().unwrap();
+            let (channel, _, images, reason) = body;
+            let _ = sender.send((channel, images, reason));
+        }
+    });
+
+    let _: () = wcd.call("TriggerChange", &("",)).unwrap();
+    let (channel, images, reason) = receiver.recv_timeout(TIMEOUT).expect("no WallpaperChanged signal");
+    assert_eq!((&*channel, images.len(), &*reason), ("default", 1, "manual"));
+    let current_image: String = wcd.get_property("CurrentImage").unwrap();
+    assert_eq!(current_image, images[0]);
+
+    let _: () = wcd.call("Pause", &("",)).unwrap();
+    assert!(wcd.get_property::<bool>("Paused").unwrap());
+
+    let statistics: Vec<(String, i64, i64, i64, u32, bool)> = wcd.call("GetStatistics", &("", "", 0u32)).unwrap();
+    assert_eq!(statistics.len(), 1);
+    assert_eq!((&statistics[0].0, statistics[0].1), (&images[0], 1));
+    let report: Vec<(String, i64, i64, i64)> = wcd.call("GetReport", &("playlist", 0i64)).unwrap();
+    assert_eq!(report.len(), 1);
+
+    let e = wcd.call::<_, _, ()>("GetReport", &("nope", 0i64)).unwrap_err();
+    assert_eq!(error_name(e), "io.github.netvl.Wcd.Error.InvalidArgument");
+    let e = wcd.call::<_, _, ()>("TriggerChange", &("nope",)).unwrap_err();
+    assert_eq!(error_name(e), "io.github.netvl.Wcd.Error.NotFound");
+
+    let _: () = wcd.call("Terminate", &()).unwrap();
+    let started = Instant::now();
+    loop {
+        if let Some(status) = daemon.0.try_wait().unwrap() {
+            assert!(status.success());
+            break;
+        }
+        assert!(started.elapsed() < TIMEOUT, "the daemon did not terminate");
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+#[test]
+fn callers_are_authorized_with_tokens() {
+    let dir = tempfile::tempdir().unwrap();
+    let (_bus, address) = match start_bus(dir.path()) {
+        Some(bus) => bus,
+        None => return,
+    };
+    // without the common token, callers of the daemon's user have no token either
+    let token_file = dir.path().join("read-token");
+    fs::write(&token_file, "reader").unwrap();
+    let server = format!("tokens = [{{ file = \"{}\", scope = \"read\" }}]", token_file.display());
+    let _daemon = start_daemon(dir.path(), &address, "", &server);
+
+    let connection = zbus::blocking::connection::Builder::address(&*address).unwrap().build().unwrap();
+    wait_for_name(&connection);
+    let wcd = proxy(&connection);
+
+    let e = wcd.call::<_, _, ()>("GetVersion", &()).unwrap_err();
+    assert_eq!(error_name(e), "io.github.netvl.Wcd.Error.Unauthenticated");
+    let e = wcd.get_property::<bool>("Paused").unwrap_err();
+    assert_eq!(error_name(e), "org.freedesktop.DBus.Error.AuthFailed");
+    let e = wcd.call::<_, _, ()>("Authenticate", &("writer",)).unwrap_err();
+    assert_eq!(error_name(e), "io.github.netvl.Wcd.Error.Unauthenticated");
+
+    let _: () = wcd.call("Authenticate", &("reader",)).unwrap();
+    let _: HashMap<String, OwnedValue> = wcd.call("GetVersion", &()).unwrap();
+    assert!(!wcd.get_property::<bool>("Paused").unwrap());
+    let e = wcd.call::<_, _, ()>("TriggerChange", &("",)).unwrap_err();
+    assert_eq!(error_name(e), "io.github.netvl.Wcd.Error.PermissionDenied");
+
+    // the token only applies to the connection which gave it
+    let other = zbus::blocking::connection::Builder::address(&*address).unwrap().build().unwrap();
+    let e = proxy(&other).call::<_, _, ()>("GetVersion", &()).unwrap_err();
+    assert_eq!(error_name(e), "io.github.netvl.Wcd.Error.Unauthenticated");
+}